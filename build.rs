@@ -0,0 +1,134 @@
+//! With the `layout-tests` feature, generates bindings from the headers of a tmux checkout
+//! and keeps bindgen's layout checks from them, to check the crate's own bindings against.
+//!
+//! The checks are compile time assertions of the size and alignment of each struct and the
+//! offset of each of its fields, so bindings which don't match the tmux they'll be loaded
+//! into fail to build, rather than reading the wrong memory once loaded.
+
+fn main() {
+    #[cfg(feature = "layout-tests")]
+    layout_tests::generate();
+}
+
+#[cfg(feature = "layout-tests")]
+mod layout_tests {
+    use std::collections::HashSet;
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    /// The environment variable naming the tmux checkout to check against.
+    const SOURCE_DIR: &str = "TMUX_SOURCE_DIR";
+
+    pub fn generate() {
+        println!("cargo:rerun-if-env-changed={}", SOURCE_DIR);
+        let source_dir = match env::var_os(SOURCE_DIR) {
+            Some(dir) => PathBuf::from(dir),
+            None => panic!(
+                "{} must be set to the tmux checkout to check the bindings against",
+                SOURCE_DIR
+            ),
+        };
+        let header = source_dir.join("tmux.h");
+        println!("cargo:rerun-if-changed={}", header.display());
+
+        // Extra flags, such as -D for whatever ./configure defined, are taken from
+        // BINDGEN_EXTRA_CLANG_ARGS by bindgen itself.
+        let bindings = bindgen::Builder::default()
+            .header(header.to_string_lossy())
+            .clang_arg(format!("-I{}", source_dir.display()))
+            .layout_tests(true)
+            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+            .generate()
+            .expect("Couldn't generate bindings from tmux's headers")
+            .to_string();
+
+        let ours = fs::read_to_string(bindings_file()).expect("Couldn't read the bindings");
+        let known = defined_types(&ours);
+        let checks: Vec<&str> = layout_checks(&bindings)
+            .into_iter()
+            .filter(|(name, _)| known.contains(name) && !name.contains("_bindgen_ty_"))
+            .map(|(_, check)| check)
+            .collect();
+
+        let out = PathBuf::from(env::var_os("OUT_DIR").expect("Cargo sets OUT_DIR"));
+        fs::write(out.join("layout_tests.rs"), checks.join("\n"))
+            .expect("Couldn't write the layout checks");
+    }
+
+    /// The crate's bindings for the version of tmux picked with a feature.
+    fn bindings_file() -> &'static str {
+        let file = if env::var_os("CARGO_FEATURE_TMUX_2_9").is_some() {
+            "src/tmux_bindings/v2_9.rs"
+        } else {
+            panic!("No version of tmux was picked to check the bindings of")
+        };
+        println!("cargo:rerun-if-changed={}", file);
+        file
+    }
+
+    /// The names of the structs and unions in a file of bindings.
+    fn defined_types(bindings: &str) -> HashSet<String> {
+        bindings
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim_start();
+                line.strip_prefix("pub struct ")
+                    .or_else(|| line.strip_prefix("pub union "))
+            })
+            .filter_map(|rest| {
+                rest.split(|c: char| !c.is_alphanumeric() && c != '_')
+                    .next()
+            })
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Each `const _: () = { ... };` block of layout assertions in generated bindings, along
+    /// with the name of the type it checks.
+    fn layout_checks(bindings: &str) -> Vec<(String, &str)> {
+        let mut checks = Vec::new();
+        let mut rest = bindings;
+        while let Some(start) = rest.find("const _") {
+            let block = &rest[start..];
+            let end = match block_end(block) {
+                Some(end) => end,
+                None => break,
+            };
+            let check = &block[..end];
+            if let Some(name) = checked_type(check) {
+                checks.push((name, check));
+            }
+            rest = &block[end..];
+        }
+        checks
+    }
+
+    /// The length of a block up to and including its closing `};`, by matching braces.
+    fn block_end(block: &str) -> Option<usize> {
+        let open = block.find('{')?;
+        let mut depth = 0;
+        for (i, c) in block[open..].char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let after = open + i + 1;
+                        let semicolon = block[after..].find(';')?;
+                        return Some(after + semicolon + 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// The type a block of layout assertions is about, from its `"Size of ..."` message.
+    fn checked_type(check: &str) -> Option<String> {
+        let start = check.find("\"Size of ")? + "\"Size of ".len();
+        let end = check[start..].find('"')?;
+        Some(check[start..start + end].to_owned())
+    }
+}
@@ -0,0 +1,166 @@
+//! Creates new tmux plugin crates, run as `cargo tmux-plugin new <path>`.
+//!
+//! The new crate builds a `cdylib` for tmux to load, defines a format variable with
+//! `format_plugin!`, and has a test which runs without tmux, using `tmux_plugin`'s `stub`
+//! feature.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// The version of `tmux_plugin` new crates depend on, which this is released alongside.
+const TMUX_PLUGIN_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+const USAGE: &str = "\
+Creates a new tmux plugin crate.
+
+Usage: cargo tmux-plugin new <path> [--name <name>]
+
+Options:
+    --name <name>  The crate's name, instead of the last part of <path>";
+
+/// The files of a new crate, by path, with placeholders for its names.
+const TEMPLATES: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../templates/Cargo.toml.template"),
+    ),
+    (
+        ".gitignore",
+        include_str!("../templates/gitignore.template"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.template")),
+    (
+        "tests/plugin.rs",
+        include_str!("../templates/test.rs.template"),
+    ),
+];
+
+#[derive(Debug)]
+enum Error {
+    Usage(String),
+    InvalidName(String),
+    Exists(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Usage(message) => write!(f, "{}\n\n{}", message, USAGE),
+            Error::InvalidName(name) => write!(
+                f,
+                "`{}` isn't a valid crate name: use letters, digits, `-` and `_`, \
+                 starting with a letter",
+                name
+            ),
+            Error::Exists(path) => write!(f, "`{}` already exists", path.display()),
+            Error::Io(path, err) => write!(f, "couldn't write `{}`: {}", path.display(), err),
+        }
+    }
+}
+
+/// What to create.
+#[derive(Debug)]
+struct New {
+    path: PathBuf,
+    name: String,
+}
+
+fn main() {
+    let result = parse_args(env::args().skip(1)).and_then(|new| {
+        create(&new)?;
+        Ok(new)
+    });
+    match result {
+        Ok(new) => println!(
+            "Created tmux plugin `{}` in `{}`",
+            new.name,
+            new.path.display()
+        ),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_args<I: Iterator<Item = String>>(args: I) -> Result<New, Error> {
+    // Run by cargo as `cargo-tmux-plugin tmux-plugin new ...`, or directly without it.
+    let mut args = args.peekable();
+    if args.peek().map(String::as_str) == Some("tmux-plugin") {
+        args.next();
+    }
+    match args.next().as_deref() {
+        Some("new") => {}
+        Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            process::exit(0);
+        }
+        Some(command) => return Err(Error::Usage(format!("unknown command `{}`", command))),
+        None => return Err(Error::Usage("no command given".to_owned())),
+    }
+
+    let mut path = None;
+    let mut name = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => match args.next() {
+                Some(value) => name = Some(value),
+                None => return Err(Error::Usage("`--name` needs a value".to_owned())),
+            },
+            _ if arg.starts_with('-') => {
+                return Err(Error::Usage(format!("unknown option `{}`", arg)))
+            }
+            _ if path.is_none() => path = Some(PathBuf::from(arg)),
+            _ => return Err(Error::Usage(format!("unexpected argument `{}`", arg))),
+        }
+    }
+
+    let path = path.ok_or_else(|| Error::Usage("no path given".to_owned()))?;
+    let name = match name {
+        Some(name) => name,
+        None => path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| Error::Usage("the path has no name to use".to_owned()))?,
+    };
+    if !is_valid_name(&name) {
+        return Err(Error::InvalidName(name));
+    }
+    Ok(New { path, name })
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn create(new: &New) -> Result<(), Error> {
+    if new.path.exists() {
+        return Err(Error::Exists(new.path.clone()));
+    }
+    // The crate's name as Rust code refers to it, which is also the format variable's name.
+    let ident = new.name.replace('-', "_");
+    for (file, template) in TEMPLATES {
+        let contents = template
+            .replace("{{name}}", &new.name)
+            .replace("{{crate}}", &ident)
+            .replace("{{variable}}", &ident)
+            .replace("{{version}}", TMUX_PLUGIN_VERSION);
+        write(&new.path.join(file), &contents)?;
+    }
+    Ok(())
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), Error> {
+    let result = match path.parent() {
+        Some(dir) => fs::create_dir_all(dir).and_then(|()| fs::write(path, contents)),
+        None => fs::write(path, contents),
+    };
+    result.map_err(|err| Error::Io(path.to_owned(), err))
+}
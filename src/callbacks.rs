@@ -0,0 +1,66 @@
+//! Boxing the closures passed to the plugin macros, so that they can be kept until tmux calls
+//! them.
+//!
+//! Each function takes a closure of the shape its macro documents and returns it boxed, with
+//! its return value converted to what tmux expects. Taking the closure as an argument of a
+//! known shape also lets Rust infer the types of its arguments.
+
+use crate::tmux::strings::{sanitize_value, to_tmux_string_lossy, CStrExt, OwnedTmuxString};
+use crate::tmux::{cmd, cmd_retval, format_tree, notify_entry, CmdqItem, IntoCmdRetval};
+use std::ffi::{CStr, CString};
+
+pub type Format = Box<dyn FnMut(*mut format_tree) -> OwnedTmuxString>;
+pub type Function = Box<dyn FnMut(&CStr) -> OwnedTmuxString>;
+pub type Notification = Box<dyn FnMut(*mut notify_entry)>;
+pub type Filter = Box<dyn Fn(&notify_entry) -> bool>;
+pub type Cmd = Box<dyn FnMut(*mut cmd, &mut dyn Iterator<Item = &CStr>, CmdqItem) -> cmd_retval>;
+
+pub fn format<F, R>(mut f: F) -> Format
+where
+    F: FnMut(*mut format_tree) -> R + 'static,
+    R: AsRef<CStr>,
+{
+    Box::new(move |ft| OwnedTmuxString::new(f(ft).as_ref()))
+}
+
+/// Wraps a format callback so that its values are passed through
+/// [`sanitize_value`](../tmux/strings/fn.sanitize_value.html).
+pub fn sanitized_format(mut f: Format) -> Format {
+    Box::new(move |ft| OwnedTmuxString::new(&sanitized(f(ft).as_c_str())))
+}
+
+/// A format variable's value, passed through
+/// [`sanitize_value`](../tmux/strings/fn.sanitize_value.html).
+pub fn sanitized(value: &CStr) -> CString {
+    to_tmux_string_lossy(sanitize_value(&value.to_string_sanitized()))
+}
+
+pub fn function<F, R>(mut f: F) -> Function
+where
+    F: FnMut(&CStr) -> R + 'static,
+    R: AsRef<CStr>,
+{
+    Box::new(move |arg| OwnedTmuxString::new(f(arg).as_ref()))
+}
+
+pub fn notification<F>(f: F) -> Notification
+where
+    F: FnMut(*mut notify_entry) + 'static,
+{
+    Box::new(f)
+}
+
+pub fn filter<F>(f: F) -> Filter
+where
+    F: Fn(&notify_entry) -> bool + 'static,
+{
+    Box::new(f)
+}
+
+pub fn cmd<F, R>(mut f: F) -> Cmd
+where
+    F: FnMut(*mut cmd, &mut dyn Iterator<Item = &CStr>, CmdqItem) -> R + 'static,
+    R: IntoCmdRetval,
+{
+    Box::new(move |cmd, args, item| f(cmd, args, item).into_cmd_retval(item))
+}
@@ -0,0 +1,263 @@
+//! Configuration files, for plugins whose configuration is too rich for tmux options.
+//!
+//! A plugin's configuration file is `$XDG_CONFIG_HOME/tmux-plugins/<name>.toml`, falling back
+//! to `~/.config/tmux-plugins/<name>.toml` when `XDG_CONFIG_HOME` isn't set. It is read into
+//! any type implementing serde's `Deserialize`.
+//!
+//! [`Config`](./struct.Config.html) holds a plugin's configuration for as long as the plugin is
+//! loaded, reading the file when it is first used and again whenever it is reloaded, usually
+//! from a command. Each of its top-level fields can also be overridden with a user option or
+//! a variable in the server's environment, as described in
+//! [`Config::resolve`](./struct.Config.html#method.resolve).
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::config::Config;
+//! use tmux_plugin::{cmd_plugin, tmux};
+//! use serde::Deserialize;
+//!
+//! #[derive(Default, Deserialize)]
+//! #[serde(default)]
+//! struct Settings {
+//!     greeting: String,
+//!     names: Vec<String>,
+//! }
+//!
+//! thread_local! {
+//!     // Read from ~/.config/tmux-plugins/greeter.toml.
+//!     static SETTINGS: Config<Settings> = Config::new("greeter");
+//! }
+//!
+//! // Reloads the configuration file, reporting any errors in it.
+//! cmd_plugin!(b"greeter-reload\0", b"\0", b"\0", 0, 0, |_cmd, _args, _item| {
+//!     match SETTINGS.with(Config::reload) {
+//!         Ok(()) => tmux::CmdResult::Normal,
+//!         Err(error) => tmux::CmdResult::Error(error.to_string()),
+//!     }
+//! });
+//! # fn main() {}
+//! ```
+
+use crate::tmux::server;
+use serde::de::{self, DeserializeOwned};
+use std::cell::{Ref, RefCell};
+use std::env;
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The path of a plugin's configuration file.
+///
+/// Returns `None` if neither `XDG_CONFIG_HOME` nor `HOME` is set.
+pub fn path(name: &str) -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(
+        config_home
+            .join("tmux-plugins")
+            .join(format!("{}.toml", name)),
+    )
+}
+
+/// Reads a plugin's configuration file.
+///
+/// Returns `Ok(None)` if the file doesn't exist.
+pub fn read<T: DeserializeOwned>(name: &str) -> Result<Option<T>, ConfigError> {
+    let path = path(name).ok_or(ConfigError::NoConfigDir)?;
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(ConfigError::Io(path, error)),
+    };
+    toml::from_str(&contents)
+        .map(Some)
+        .map_err(|error| ConfigError::Parse(path, error))
+}
+
+/// An error reading a configuration file.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// Neither `XDG_CONFIG_HOME` nor `HOME` is set, so there is nowhere to look for the file.
+    NoConfigDir,
+    /// The file couldn't be read.
+    Io(PathBuf, io::Error),
+    /// The file isn't valid TOML.
+    Parse(PathBuf, toml::de::Error),
+    /// The configuration doesn't match its type, once the options and environment are
+    /// applied to it.
+    Invalid(toml::de::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::NoConfigDir => f.write_str("no configuration directory"),
+            ConfigError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+            ConfigError::Parse(path, error) => write!(f, "{}: {}", path.display(), error),
+            ConfigError::Invalid(error) => write!(f, "invalid configuration: {}", error),
+        }
+    }
+}
+
+impl error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ConfigError::NoConfigDir => None,
+            ConfigError::Io(_, error) => Some(error),
+            ConfigError::Parse(_, error) => Some(error),
+            ConfigError::Invalid(error) => Some(error),
+        }
+    }
+}
+
+/// A plugin's configuration, read from its configuration file.
+///
+/// This is meant to be kept in a `thread_local!`, since tmux runs plugins on its one thread.
+/// The configuration is [resolved](#method.resolve) the first time it is used. If that fails,
+/// the configuration's `Default` is used instead, until it is resolved successfully.
+#[derive(Debug)]
+pub struct Config<T> {
+    name: &'static str,
+    value: RefCell<Option<T>>,
+}
+
+impl<T: DeserializeOwned + Default> Config<T> {
+    /// The configuration of the plugin with this name, read from `<name>.toml`.
+    pub const fn new(name: &'static str) -> Config<T> {
+        Config {
+            name,
+            value: RefCell::new(None),
+        }
+    }
+
+    /// The current configuration, resolving it if it hasn't been resolved yet.
+    pub fn get(&self) -> Ref<'_, T> {
+        if self.value.borrow().is_none() {
+            let value = self.read_layers().unwrap_or_default();
+            *self.value.borrow_mut() = Some(value);
+        }
+        Ref::map(self.value.borrow(), |value| {
+            value.as_ref().expect("The configuration was just resolved")
+        })
+    }
+
+    /// Works out the configuration again, from each of the places it can be set.
+    ///
+    /// Each top-level field is taken from the first of these which sets it:
+    ///
+    /// 1. The variable in the server's global environment named after the plugin and the
+    ///    field, in upper case with underscores, such as `GREETER_NAMES`. This lets scripts
+    ///    which start tmux configure plugins with `tmux set-environment -g` or by exporting
+    ///    the variable before starting the server.
+    /// 2. The user option named after the plugin and the field, with dashes, such as
+    ///    `@greeter-names`.
+    /// 3. The configuration file.
+    ///
+    /// Values from the environment and options are read as TOML values, so `10` is a number,
+    /// `true` a boolean and `["a", "b"]` an array. Anything which isn't a TOML value, such as
+    /// a bare word, is read as a string.
+    ///
+    /// If anything is invalid, the error is returned and the configuration is left as it was.
+    pub fn resolve(&self) -> Result<Ref<'_, T>, ConfigError> {
+        let value = self.read_layers()?;
+        *self.value.borrow_mut() = Some(value);
+        Ok(self.get())
+    }
+
+    /// Resolves the configuration again, after its file has changed.
+    ///
+    /// This is the same as [`resolve`](#method.resolve), for use in a reload command.
+    pub fn reload(&self) -> Result<(), ConfigError> {
+        self.resolve().map(drop)
+    }
+
+    fn read_layers(&self) -> Result<T, ConfigError> {
+        let mut table = read::<toml::Table>(self.name)?.unwrap_or_default();
+        let prefix = self.name.replace('_', "-");
+        let env_prefix = self.name.to_ascii_uppercase().replace('-', "_");
+        for field in field_names::<T>() {
+            let overridden = server::environment(&format!(
+                "{}_{}",
+                env_prefix,
+                field.to_ascii_uppercase().replace('-', "_")
+            ))
+            .or_else(|| {
+                server::global_session_options().get(&format!(
+                    "@{}-{}",
+                    prefix,
+                    field.replace('_', "-")
+                ))
+            });
+            if let Some(value) = overridden {
+                table.insert(field.to_string(), parse_value(&value));
+            }
+        }
+        T::deserialize(toml::Value::Table(table)).map_err(ConfigError::Invalid)
+    }
+}
+
+/// Reads a value from an option or the environment as a TOML value, or else as a string.
+fn parse_value(value: &str) -> toml::Value {
+    toml::from_str::<toml::Table>(&format!("value = {}", value))
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or_else(|| toml::Value::String(value.to_owned()))
+}
+
+/// The names of a struct's fields, as serde sees them.
+///
+/// serde passes a struct's field names to the deserializer, so they are picked up by a
+/// deserializer which gives up as soon as it has them.
+fn field_names<T: DeserializeOwned>() -> &'static [&'static str] {
+    struct FieldNames(Option<&'static [&'static str]>);
+
+    #[derive(Debug)]
+    struct Stop;
+
+    impl fmt::Display for Stop {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("stop")
+        }
+    }
+
+    impl error::Error for Stop {}
+
+    impl de::Error for Stop {
+        fn custom<M: fmt::Display>(_message: M) -> Stop {
+            Stop
+        }
+    }
+
+    impl<'de> de::Deserializer<'de> for &mut FieldNames {
+        type Error = Stop;
+
+        fn deserialize_any<V: de::Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Stop> {
+            Err(Stop)
+        }
+
+        fn deserialize_struct<V: de::Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Stop> {
+            self.0 = Some(fields);
+            Err(Stop)
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+            byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map enum
+            identifier ignored_any
+        }
+    }
+
+    let mut names = FieldNames(None);
+    let _ = T::deserialize(&mut names);
+    names.0.unwrap_or_default()
+}
@@ -0,0 +1,193 @@
+//! The crate's error type, for plugin code which uses `?` on many kinds of error at once.
+
+use crate::tmux::status::InvalidStyle;
+use crate::tmux::target::InvalidTarget;
+use crate::tmux::{CommandError, LayoutError, RenameError, SpawnError};
+use std::error;
+use std::ffi::{IntoStringError, NulError};
+use std::fmt;
+use std::io;
+use std::str::Utf8Error;
+use std::string::FromUtf8Error;
+
+/// Any error a plugin might run into.
+///
+/// The errors returned by the rest of this crate, as well as I/O errors and the errors from
+/// converting between Rust strings and C strings, convert into it, so functions returning
+/// [`Result`](./type.Result.html) can use `?` on all of them.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux, Error, Result};
+/// use tmux_plugin::tmux::CmdqItem;
+/// use std::ffi::CString;
+///
+/// fn show_hostname(item: &CmdqItem) -> Result<()> {
+///     let hostname = std::fs::read_to_string("/etc/hostname")?;
+///     let client = item.client().ok_or(Error::MissingTarget("client".to_owned()))?;
+///     item.print(&CString::new(format!("{}: {}", client.name().to_str()?, hostname.trim()))?);
+///     Ok(())
+/// }
+///
+/// cmd_plugin!(b"show-hostname\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+///     match show_hostname(&item) {
+///         Ok(()) => tmux::CmdResult::Normal,
+///         Err(error) => tmux::CmdResult::Error(error.to_string()),
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// A string passed to tmux contains a null byte.
+    Nul(NulError),
+    /// A string from tmux isn't valid UTF-8.
+    Utf8(Utf8Error),
+    /// The client, session, window or pane to act on couldn't be found. This holds a
+    /// description of what was looked for, such as `pane %3`.
+    MissingTarget(String),
+    /// An option is set to a value which can't be parsed.
+    InvalidOption {
+        /// The option's name.
+        name: String,
+        /// The option's value.
+        value: String,
+    },
+    /// A command was given a value for a flag which can't be parsed.
+    InvalidArgument {
+        /// The flag.
+        flag: char,
+        /// The value given with it.
+        value: String,
+        /// Why it can't be parsed.
+        reason: String,
+    },
+    /// tmux returned a null pointer. This holds a description of what was asked for.
+    NullPointer(&'static str),
+    /// An I/O error.
+    Io(io::Error),
+    /// One of the other errors returned by this crate, such as a
+    /// [`SpawnError`](./tmux/struct.SpawnError.html).
+    Other(Box<dyn error::Error + Send + Sync>),
+}
+
+/// A `Result` with this crate's [`Error`](./enum.Error.html) as its default error.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Nul(error) => error.fmt(f),
+            Error::Utf8(error) => error.fmt(f),
+            Error::MissingTarget(target) => write!(f, "can't find {}", target),
+            Error::InvalidOption { name, value } => {
+                write!(f, "invalid value for {}: {}", name, value)
+            }
+            Error::InvalidArgument {
+                flag,
+                value,
+                reason,
+            } => write!(f, "invalid value for -{}: {} ({})", flag, value, reason),
+            Error::NullPointer(what) => write!(f, "tmux returned no {}", what),
+            Error::Io(error) => error.fmt(f),
+            Error::Other(error) => error.fmt(f),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Nul(error) => Some(error),
+            Error::Utf8(error) => Some(error),
+            Error::Io(error) => Some(error),
+            Error::Other(error) => Some(&**error),
+            Error::MissingTarget(_)
+            | Error::InvalidOption { .. }
+            | Error::InvalidArgument { .. }
+            | Error::NullPointer(_) => None,
+        }
+    }
+}
+
+impl From<NulError> for Error {
+    fn from(error: NulError) -> Error {
+        Error::Nul(error)
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(error: Utf8Error) -> Error {
+        Error::Utf8(error)
+    }
+}
+
+impl From<FromUtf8Error> for Error {
+    fn from(error: FromUtf8Error) -> Error {
+        Error::Utf8(error.utf8_error())
+    }
+}
+
+impl From<IntoStringError> for Error {
+    fn from(error: IntoStringError) -> Error {
+        Error::Utf8(error.utf8_error())
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
+
+impl From<SpawnError> for Error {
+    fn from(error: SpawnError) -> Error {
+        Error::Other(Box::new(error))
+    }
+}
+
+impl From<RenameError> for Error {
+    fn from(error: RenameError) -> Error {
+        match error {
+            RenameError::Nul(error) => Error::Nul(error),
+            error => Error::Other(Box::new(error)),
+        }
+    }
+}
+
+impl From<CommandError> for Error {
+    fn from(error: CommandError) -> Error {
+        match error {
+            CommandError::Nul(error) => Error::Nul(error),
+            error => Error::Other(Box::new(error)),
+        }
+    }
+}
+
+impl From<InvalidStyle> for Error {
+    fn from(error: InvalidStyle) -> Error {
+        Error::Other(Box::new(error))
+    }
+}
+
+impl From<InvalidTarget> for Error {
+    fn from(error: InvalidTarget) -> Error {
+        Error::Other(Box::new(error))
+    }
+}
+
+impl From<LayoutError> for Error {
+    fn from(error: LayoutError) -> Error {
+        // Layout errors can hold panes, which can't leave tmux's thread, so only the message
+        // is kept.
+        Error::Other(error.to_string().into())
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<crate::config::ConfigError> for Error {
+    fn from(error: crate::config::ConfigError) -> Error {
+        Error::Other(Box::new(error))
+    }
+}
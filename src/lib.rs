@@ -11,7 +11,7 @@
 //! use tmux_plugin::format_plugin;
 //! use std::ffi::CString;
 //!
-//! format_plugin!(b"my_window_width\0", |format_tree| {
+//! format_plugin!("my_window_width", |format_tree| {
 //!     CString::new(
 //!         format!("{}", unsafe { *(*format_tree).w }.sx)
 //!     ).unwrap()
@@ -25,16 +25,126 @@
 //! name = "..." # Your plugin's name
 //! crate-type = ["cdylib"]
 //! ```
+//!
+//! # Names
+//!
+//! The plugin macros take the names of variables, functions, commands and hooks as string
+//! literals, such as `"my_window_width"`, and add the null terminator tmux needs at compile
+//! time. Byte strings, with or without a terminator, and `&CStr`s such as `c"name"` work too,
+//! as do constants of any of those types.
+//!
+//! Names are checked at compile time too. One with whitespace, `#`, `{` or `}` in it could
+//! never be used from tmux, so it is rejected:
+//!
+//! ```rust,compile_fail
+//! use tmux_plugin::format_plugin;
+//! use std::ffi::CString;
+//!
+//! format_plugin!("window width", |_format_tree| { CString::new("").unwrap() });
+//! # fn main() {}
+//! ```
+//!
+//! # One plugin per library
+//!
+//! tmux loads a single plugin from each library, so each of the plugin macros may only be
+//! used once in a crate. Using a second one fails to compile with an error about the symbol
+//! `ONLY_ONE_PLUGIN_MACRO_PER_LIBRARY__USE_FORMAT_PLUGINS_TO_DEFINE_SEVERAL` being defined
+//! twice. Several format variables can be defined together with
+//! [`format_plugins!`](./macro.format_plugins.html) or
+//! [`format_group_plugin!`](./macro.format_group_plugin.html).
+//!
+//! # tmux versions
+//!
+//! The bindings to tmux are for one version of the fork, picked with a feature. The default,
+//! `tmux-2-9`, is the fork as of tmux 2.9, and is the only version so far. Bindings for newer
+//! versions will be added alongside it with features of their own, so that a plugin keeps
+//! building against the version it was written for until it picks another:
+//!
+//! ```toml
+//! [dependencies]
+//! tmux_plugin = { version = "0.0.1", default-features = false, features = ["tmux-2-9"] }
+//! ```
+//!
+//! # Testing
+//!
+//! A plugin's unit tests run on the host, outside tmux, so the tmux functions the crate calls
+//! aren't there to link against. The `stub` feature defines them all, doing nothing: there are
+//! no sessions, windows or clients, formats expand to themselves and timers never fire. It is
+//! only for tests, since a plugin built with it would call the stubs rather than tmux, so
+//! enable it for dev-dependencies alone, with version 2 of the feature resolver:
+//!
+//! ```toml
+//! [package]
+//! resolver = "2"
+//!
+//! [dependencies]
+//! tmux_plugin = "0.0.1"
+//!
+//! [dev-dependencies]
+//! tmux_plugin = { version = "0.0.1", features = ["stub"] }
+//! ```
+//!
+//! The `tracked-alloc` feature, which turns on `stub` too, also keeps track of the strings
+//! passed between the plugin and the stubs. They are allocated by Rust rather than by C's
+//! `strdup`, so Miri and AddressSanitizer can follow them, and
+//! [`tmux::strings::free`](./tmux/strings/fn.free.html) panics if a string is freed twice.
+//!
+//! The crate's bindings were generated from one version of the tmux fork's headers, and a
+//! struct which has changed since is silently misread. The `layout-tests` feature generates
+//! bindings from the headers of another checkout, named by the `TMUX_SOURCE_DIR` environment
+//! variable, and checks the size, alignment and field offsets of each struct the crate knows
+//! against them, failing the build if any differ. It needs libclang, as bindgen does, and any
+//! flags `./configure` passed to the compiler can be given in `BINDGEN_EXTRA_CLANG_ARGS`:
+//!
+//! ```sh
+//! TMUX_SOURCE_DIR=~/src/tmux cargo build --features layout-tests
+//! ```
 
+#[doc(hidden)]
+pub mod callbacks;
+#[cfg(feature = "config")]
+pub mod config;
+mod error;
+mod metadata;
+#[doc(hidden)]
+pub mod names;
+pub mod prelude;
+#[cfg(feature = "stub")]
+mod stub;
 pub mod tmux;
 mod tmux_bindings;
 
+/// The layout checks generated from the tmux checkout in `TMUX_SOURCE_DIR` by the build script,
+/// checked against the crate's own bindings.
+#[cfg(feature = "layout-tests")]
+#[allow(
+    clippy::unnecessary_operation,
+    clippy::identity_op,
+    non_camel_case_types
+)]
+mod layout_tests {
+    use crate::tmux_bindings::*;
+
+    include!(concat!(env!("OUT_DIR"), "/layout_tests.rs"));
+}
+
+pub use error::{Error, Result};
+#[doc(hidden)]
+pub use metadata::check_plugin_api as __check_plugin_api;
+pub use metadata::{Capabilities, PluginMetadata, Priority, PLUGIN_API_VERSION};
+
 #[doc(hidden)]
 pub use libc;
 
+/// Derives [`PluginOptions`](./tmux/trait.PluginOptions.html), reading a struct's fields
+/// from `@`-prefixed user options.
+#[cfg(feature = "derive")]
+pub use tmux_plugin_derive::PluginOptions;
+
 /// Defines a new format variable.
 ///
-/// This macro takes two arguments: The name of the variable (as a null-terminated byte string),
+/// This macro takes two arguments: The name of the variable (as a string literal, such as
+/// `"my_window_width"`; see [the crate documentation](./index.html#names) for what else it may be),
 /// and a function to calculate that variable's value.
 /// This function will be passed a tmux [`format_tree`](./tmux/struct.format_tree.html) object,
 /// and should return a type that implements [`AsRef`](https://doc.rust-lang.org/std/convert/trait.AsRef.html)`<`[`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html)`>`,
@@ -48,16 +158,82 @@ pub use libc;
 /// use tmux_plugin::format_plugin;
 /// use std::ffi::CString;
 ///
-/// format_plugin!(b"my_window_width\0", |format_tree| {
+/// format_plugin!("my_window_width", |format_tree| {
 ///     CString::new(
 ///         format!("{}", unsafe { *(*format_tree).w }.sx)
 ///     ).unwrap()
 /// });
 /// # fn main() {}
 /// ```
+///
+/// Variables which are expensive to calculate can be marked `lazy`, by passing `lazy` between
+/// the name and the function. The function is then only called if the variable is used in
+/// some option, such as `status-right`, so that users who never use it don't pay for it.
+/// Otherwise, as when listing every variable with `display-message -a`, the variable is empty.
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use std::ffi::CString;
+///
+/// format_plugin!("load_average", lazy, |_format_tree| {
+///     let load = std::fs::read_to_string("/proc/loadavg").unwrap_or_default();
+///     CString::new(load.split(' ').next().unwrap_or_default()).unwrap()
+/// });
+/// # fn main() {}
+/// ```
+///
+/// Variables which show outside data, such as a command's output or a file's contents, can be
+/// marked `sanitized`, after `lazy` if both are used. Their values are then passed through
+/// [`sanitize_value`](./tmux/strings/fn.sanitize_value.html), so that any `#` or control
+/// characters in the data are shown as they are, rather than restyling or garbling the status
+/// line.
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use std::ffi::CString;
+///
+/// format_plugin!("now_playing", sanitized, |_format_tree| {
+///     let title = std::fs::read_to_string("/tmp/now-playing").unwrap_or_default();
+///     CString::new(title.replace('\0', "")).unwrap()
+/// });
+/// # fn main() {}
+/// ```
+///
+/// Instead of a body, the macro can be passed an expression which evaluates to a closure, such
+/// as a `move` closure or a block ending in one. The expression is evaluated the first time the
+/// variable is expanded, and the closure is kept and called from then on, so it can capture
+/// configuration or keep state between calls. The closure can't expand the variable itself.
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use std::ffi::CString;
+///
+/// // The number of times the variable has been expanded, as a prefix read from the environment.
+/// format_plugin!("expansions", {
+///     let prefix = std::env::var("EXPANSIONS_PREFIX").unwrap_or_default();
+///     let mut count = 0;
+///     move |_format_tree| {
+///         count += 1;
+///         CString::new(format!("{}{}", prefix, count)).unwrap_or_default()
+///     }
+/// });
+/// # fn main() {}
+/// ```
 #[macro_export]
 macro_rules! format_plugin {
     ($name:expr, |$ft:ident| $body:block) => {
+        $crate::format_plugin!(@define $name, false, false, |$ft| $body);
+    };
+    ($name:expr, lazy, sanitized, |$ft:ident| $body:block) => {
+        $crate::format_plugin!(@define $name, true, true, |$ft| $body);
+    };
+    ($name:expr, lazy, |$ft:ident| $body:block) => {
+        $crate::format_plugin!(@define $name, true, false, |$ft| $body);
+    };
+    ($name:expr, sanitized, |$ft:ident| $body:block) => {
+        $crate::format_plugin!(@define $name, false, true, |$ft| $body);
+    };
+    (@define $name:expr, $lazy:expr, $sanitized:expr, |$ft:ident| $body:block) => {
         mod tmux_format_plugin {
             use super::*;
             use $crate::tmux;
@@ -65,7 +241,7 @@ macro_rules! format_plugin {
             $crate::__plugin!(
                 format,
                 tmux::format_plugin {
-                    name: $name as *const u8 as *const ::libc::c_char,
+                    name: $crate::__name!($name),
                     cb: Some(plugin_format_cb),
                 }
             );
@@ -81,9 +257,79 @@ macro_rules! format_plugin {
                 $ft: *mut tmux::format_tree,
                 fe: *mut tmux::format_entry,
             ) {
+                if $lazy {
+                    let name = ::std::ffi::CStr::from_ptr($crate::__name!($name));
+                    if !tmux::format::is_referenced(&name.to_string_lossy()) {
+                        tmux::strings::OwnedTmuxString::new(Default::default())
+                            .hand_off(&mut (*fe).value);
+                        return;
+                    }
+                }
                 let return_str = format_plugin_body($ft, fe);
-                let dup = $crate::libc::strdup(return_str.as_ref().as_ptr());
-                (*fe).value = dup;
+                let value = if $sanitized {
+                    let sanitized = $crate::callbacks::sanitized(return_str.as_ref());
+                    tmux::strings::OwnedTmuxString::new(&sanitized)
+                } else {
+                    tmux::strings::OwnedTmuxString::new(return_str.as_ref())
+                };
+                value.hand_off(&mut (*fe).value);
+            }
+        }
+    };
+    ($name:expr, lazy, sanitized, $callback:expr) => {
+        $crate::format_plugin!(@closure $name, true, true, $callback);
+    };
+    ($name:expr, lazy, $callback:expr) => {
+        $crate::format_plugin!(@closure $name, true, false, $callback);
+    };
+    ($name:expr, sanitized, $callback:expr) => {
+        $crate::format_plugin!(@closure $name, false, true, $callback);
+    };
+    ($name:expr, $callback:expr) => {
+        $crate::format_plugin!(@closure $name, false, false, $callback);
+    };
+    (@closure $name:expr, $lazy:expr, $sanitized:expr, $callback:expr) => {
+        mod tmux_format_plugin {
+            use super::*;
+            use $crate::tmux;
+
+            $crate::__plugin!(
+                format,
+                tmux::format_plugin {
+                    name: $crate::__name!($name),
+                    cb: Some(plugin_format_cb),
+                }
+            );
+
+            thread_local! {
+                static CALLBACK: ::std::cell::RefCell<Option<$crate::callbacks::Format>> =
+                    ::std::cell::RefCell::new(None);
+            }
+
+            pub unsafe extern "C" fn plugin_format_cb(
+                ft: *mut tmux::format_tree,
+                fe: *mut tmux::format_entry,
+            ) {
+                if $lazy {
+                    let name = ::std::ffi::CStr::from_ptr($crate::__name!($name));
+                    if !tmux::format::is_referenced(&name.to_string_lossy()) {
+                        tmux::strings::OwnedTmuxString::new(Default::default())
+                            .hand_off(&mut (*fe).value);
+                        return;
+                    }
+                }
+                let value = CALLBACK.with(|callback| {
+                    let mut callback = callback.borrow_mut();
+                    callback.get_or_insert_with(|| {
+                        let callback = $crate::callbacks::format($callback);
+                        if $sanitized {
+                            $crate::callbacks::sanitized_format(callback)
+                        } else {
+                            callback
+                        }
+                    })(ft)
+                });
+                value.hand_off(&mut (*fe).value);
             }
         }
     };
@@ -91,7 +337,7 @@ macro_rules! format_plugin {
 
 /// Defines a new format function.
 ///
-/// This macro takes two arguments: The name of the function (as a null-terminated byte string),
+/// This macro takes two arguments: The name of the function (as a string literal),
 /// and the function body itself. The function recives a `&`[`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html).
 /// as an argument, and should return a type that implements [`AsRef`](https://doc.rust-lang.org/std/convert/trait.AsRef.html)`<`[`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html)`>`,
 /// such as [`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html)
@@ -100,13 +346,18 @@ macro_rules! format_plugin {
 /// This function will then be usable in tmux format strings by surrounding it with a pair of colons.
 /// For instance, if you define a format function that reverses it's input named `reverse`, you can use it as `#{:reverse:session_name}` to display the builtin tmux `session_name` variable backwards.
 ///
+/// tmux looks functions up by their whole name and passes them nothing but their input, so a
+/// function can't take inline arguments the way some builtin modifiers do, as in
+/// `#{=10:window_name}`. Writing `#{:pad=10,left:window_name}` looks for a function named
+/// `pad=10,left`, rather than passing `10,left` to `pad`.
+///
 /// For example:
 ///
 /// ```rust
 /// use tmux_plugin::format_function_plugin;
 /// use std::ffi::CString;
 ///
-/// format_function_plugin!(b"trim\0", |arg| {
+/// format_function_plugin!("trim", |arg| {
 ///     match arg.to_str() {
 ///       Ok(string) => {
 ///         CString::new(string.trim().to_owned())
@@ -117,6 +368,9 @@ macro_rules! format_plugin {
 /// });
 /// # fn main() {}
 /// ```
+///
+/// As with [`format_plugin!`](./macro.format_plugin.html), an expression which evaluates to a
+/// closure can be passed instead of a body.
 #[macro_export]
 macro_rules! format_function_plugin {
     ($name:expr, |$arg:ident| $body:block) => {
@@ -127,7 +381,7 @@ macro_rules! format_function_plugin {
             $crate::__plugin!(
                 function,
                 tmux::function_plugin {
-                    name: $name as *const u8 as *const $crate::libc::c_char,
+                    name: $crate::__name!($name),
                     cb: Some(plugin_format_function_cb),
                 }
             );
@@ -144,7 +398,40 @@ macro_rules! format_function_plugin {
             ) -> *mut $crate::libc::c_char {
                 let argument = ::std::ffi::CStr::from_ptr($arg);
                 let return_str = format_function_plugin_body(argument);
-                $crate::libc::strdup(return_str.as_ref().as_ptr())
+                tmux::strings::OwnedTmuxString::new(return_str.as_ref()).into_raw()
+            }
+        }
+    };
+    ($name:expr, $callback:expr) => {
+        mod tmux_format_function_plugin {
+            use super::*;
+            use $crate::tmux;
+
+            $crate::__plugin!(
+                function,
+                tmux::function_plugin {
+                    name: $crate::__name!($name),
+                    cb: Some(plugin_format_function_cb),
+                }
+            );
+
+            thread_local! {
+                static CALLBACK: ::std::cell::RefCell<Option<$crate::callbacks::Function>> =
+                    ::std::cell::RefCell::new(None);
+            }
+
+            pub unsafe extern "C" fn plugin_format_function_cb(
+                arg: *const $crate::libc::c_char,
+            ) -> *mut $crate::libc::c_char {
+                let argument = ::std::ffi::CStr::from_ptr(arg);
+                CALLBACK
+                    .with(|callback| {
+                        let mut callback = callback.borrow_mut();
+                        callback.get_or_insert_with(|| $crate::callbacks::function($callback))(
+                            argument,
+                        )
+                    })
+                    .into_raw()
             }
         }
     };
@@ -153,7 +440,7 @@ macro_rules! format_function_plugin {
 /// Defines a new notification callback.
 ///
 /// This macro has two variants: If passed just a callback function, that function
-/// is registered as a callback for all hook events. If passed a null-terminated hook name
+/// is registered as a callback for all hook events. If passed a hook name
 /// and a callback function, that function will be registered as a callback for that specific
 /// hook event. The callback function itself will in either case recive an argument of type
 /// [`*mut`](https://doc.rust-lang.org/std/primitive.pointer.html)` `[`notify_entry`](./tmux/struct.notify_entry.html).
@@ -163,33 +450,107 @@ macro_rules! format_function_plugin {
 ///
 /// ```rust
 /// use tmux_plugin::notification_plugin;
-/// use std::ffi::{CStr, CString};
+/// use tmux_plugin::tmux::Window;
 ///
 /// // Enforce that window names are lower case.
-/// notification_plugin!(b"window-renamed\0", |notify_entry| {
-///     let window = unsafe { (*notify_entry).window };
-///     let window_name = unsafe { CStr::from_ptr((*window).name) };
-///     let lowercase_name = window_name
-///         .to_string_lossy()
-///         .into_owned()
-///         .to_lowercase();
-///     let c_string = CString::new(lowercase_name)
-///         .expect("Does not contain null bytes, as the source was a valid C str");
-///     unsafe {
-///         // Free the old name, and duplicate the new name so
-///         // that tmux can later free it safely.
-///         libc::free((*window).name as *mut _);
-///         (*window).name = libc::strdup(c_string.as_ptr())
+/// notification_plugin!("window-renamed", |notify_entry| {
+///     if let Some(window) = unsafe { Window::from_raw((*notify_entry).window) } {
+///         let lowercase_name = window.name().to_string_lossy().to_lowercase();
+///         window
+///             .rename(&lowercase_name)
+///             .expect("Does not contain null bytes, as the source was a valid C str");
+///     }
+/// });
+/// # fn main() {}
+/// ```
+///
+/// As with [`format_plugin!`](./macro.format_plugin.html), an expression which evaluates to a
+/// closure can be passed instead of a body.
+///
+/// To run the same body for several events, pass a list of their names. The body is then
+/// also passed the name of the event which happened:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::Window;
+///
+/// // Logs windows moving between sessions.
+/// notification_plugin!(["window-linked", "window-unlinked"], |event, notify_entry| {
+///     if let Some(window) = unsafe { Window::from_raw((*notify_entry).window) } {
+///         eprintln!("{}: {}", event.to_string_lossy(), window.name().to_string_lossy());
+///     }
+/// });
+/// # fn main() {}
+/// ```
+///
+/// When several plugins handle the same event, a plugin can say whether it should run before
+/// or after the others by starting with a [`Priority`](./struct.Priority.html):
+///
+/// ```rust
+/// use tmux_plugin::{notification_plugin, Priority};
+/// use tmux_plugin::tmux::events::{Outcome, SessionRename};
+///
+/// // Replaces spaces in session names, before other plugins see the names.
+/// notification_plugin!(priority(Priority::TRANSFORM), "session-renamed", |notify_entry| {
+///     if let Some(rename) = unsafe { SessionRename::from_notify(notify_entry) } {
+///         let name = rename.new_name.to_string_lossy().replace(' ', "-");
+///         let _ = rename.resolve(Outcome::Replace(name));
+///     }
+/// });
+/// # fn main() {}
+/// ```
+///
+/// A filter can be given before the body, after `if`, to only run the body for some
+/// notifications. A filter is any `Fn(&notify_entry) -> bool`, and the
+/// [`filters`](./tmux/filters/index.html) module has some ready-made ones:
+///
+/// ```rust
+/// use tmux_plugin::{cstr, notification_plugin};
+/// use tmux_plugin::tmux::{display_message, filters, Client};
+///
+/// // Tells whoever is looking at a window when a pane in it exits.
+/// notification_plugin!("pane-exited", if filters::visible, |notify_entry| {
+///     let client = unsafe { Client::from_raw((*notify_entry).client) };
+///     if let Some(client) = client {
+///         display_message(client, cstr!("A pane exited"));
 ///     }
 /// });
 /// # fn main() {}
 /// ```
 #[macro_export]
 macro_rules! notification_plugin {
+    (priority($priority:expr), $($rest:tt)+) => {
+        #[allow(non_upper_case_globals)]
+        #[no_mangle]
+        pub static plugin_priority: $crate::Priority = $priority;
+
+        $crate::notification_plugin!($($rest)+);
+    };
+    ([$($name:expr),+ $(,)?], |$event:ident, $arg:ident| $body:block) => {
+        $crate::notification_plugin!(@many [$($name),+], [], |$event, $arg| $body);
+    };
+    ([$($name:expr),+ $(,)?], if $filter:expr, |$event:ident, $arg:ident| $body:block) => {
+        $crate::notification_plugin!(@many [$($name),+], [$filter], |$event, $arg| $body);
+    };
     (|$arg:ident| $body:block) => {
-        notification_plugin!(::std::ptr::null(), |$arg| $body);
+        $crate::notification_plugin!(@define ::std::ptr::null(), [], |$arg| $body);
+    };
+    (if $filter:expr, |$arg:ident| $body:block) => {
+        $crate::notification_plugin!(@define ::std::ptr::null(), [$filter], |$arg| $body);
+    };
+    (if $filter:expr, $callback:expr) => {
+        $crate::notification_plugin!(@closure ::std::ptr::null(), [$filter], $callback);
     };
     ($name:expr, |$arg:ident| $body:block) => {
+        $crate::notification_plugin!(@define $crate::__name!($name), [], |$arg| $body);
+    };
+    ($name:expr, if $filter:expr, |$arg:ident| $body:block) => {
+        $crate::notification_plugin!(@define $crate::__name!($name), [$filter], |$arg| $body);
+    };
+    ($name:expr, if $filter:expr, $callback:expr) => {
+        $crate::notification_plugin!(@closure $crate::__name!($name), [$filter], $callback);
+    };
+    (@define $event:expr, [$($filter:expr)?], |$arg:ident| $body:block) => {
         mod tmux_notification_plugin {
             use super::*;
             use $crate::tmux;
@@ -197,7 +558,7 @@ macro_rules! notification_plugin {
             $crate::__plugin!(
                 notify,
                 tmux::notification_plugin {
-                    event: $name as *const u8 as *const $crate::libc::c_char,
+                    event: $event,
                     cb: Some(notify_cb),
                 }
             );
@@ -207,48 +568,455 @@ macro_rules! notification_plugin {
             }
 
             pub unsafe extern "C" fn notify_cb($arg: *mut tmux::notify_entry) {
+                $($crate::notification_plugin!(@filter $filter, $arg);)?
                 notify_plugin_body($arg)
             }
         }
     };
+    (@closure $event:expr, [$($filter:expr)?], $callback:expr) => {
+        mod tmux_notification_plugin {
+            use super::*;
+            use $crate::tmux;
+
+            $crate::__plugin!(
+                notify,
+                tmux::notification_plugin {
+                    event: $event,
+                    cb: Some(notify_cb),
+                }
+            );
+
+            thread_local! {
+                static CALLBACK: ::std::cell::RefCell<Option<$crate::callbacks::Notification>> =
+                    ::std::cell::RefCell::new(None);
+            }
+
+            pub unsafe extern "C" fn notify_cb(ne: *mut tmux::notify_entry) {
+                $($crate::notification_plugin!(@filter $filter, ne);)?
+                CALLBACK.with(|callback| {
+                    let mut callback = callback.borrow_mut();
+                    callback.get_or_insert_with(|| $crate::callbacks::notification($callback))(ne)
+                })
+            }
+        }
+    };
+    (@many [$($name:expr),+], [$($filter:expr)?], |$event:ident, $arg:ident| $body:block) => {
+        mod tmux_notification_plugin {
+            use super::*;
+            use $crate::tmux;
+
+            const fn entry(event: *const $crate::libc::c_char) -> Plugin {
+                Plugin(tmux::plugin {
+                    type_: tmux::NOTIFICATION_PLUGIN as $crate::libc::c_int,
+                    __bindgen_anon_1: tmux::plugin_inner {
+                        notify: tmux::notification_plugin {
+                            event,
+                            cb: Some(notify_cb),
+                        },
+                    },
+                })
+            }
+
+            static ENTRIES: [Plugin; [$(stringify!($name)),+].len()] =
+                [$(entry($crate::__name!($name))),+];
+
+            $crate::__plugin!(
+                raw,
+                Plugin(tmux::plugin {
+                    type_: tmux::MULTI_PLUGIN as $crate::libc::c_int,
+                    __bindgen_anon_1: tmux::plugin_inner {
+                        multi: tmux::multi_plugin {
+                            length: ENTRIES.len() as $crate::libc::c_uint,
+                            plugins: ENTRIES.as_ptr() as *mut tmux::plugin,
+                        },
+                    },
+                })
+            );
+
+            fn notify_plugin_body($event: &::std::ffi::CStr, $arg: *mut tmux::notify_entry) {
+                $body
+            }
+
+            pub unsafe extern "C" fn notify_cb($arg: *mut tmux::notify_entry) {
+                $($crate::notification_plugin!(@filter $filter, $arg);)?
+                notify_plugin_body(::std::ffi::CStr::from_ptr((*$arg).name), $arg)
+            }
+        }
+    };
+    (@filter $filter:expr, $ne:ident) => {
+        thread_local! {
+            static FILTER: ::std::cell::RefCell<Option<$crate::callbacks::Filter>> =
+                ::std::cell::RefCell::new(None);
+        }
+        let passed = FILTER.with(|filter| {
+            let mut filter = filter.borrow_mut();
+            filter.get_or_insert_with(|| $crate::callbacks::filter($filter))(&*$ne)
+        });
+        if !passed {
+            return;
+        }
+    };
+    ($name:expr, $callback:expr) => {
+        $crate::notification_plugin!(@closure $crate::__name!($name), [], $callback);
+    };
+    ($callback:expr) => {
+        $crate::notification_plugin!(@closure ::std::ptr::null(), [], $callback);
+    };
 }
 
+/// Defines a new command.
+///
+/// This macro takes the command's name and alias (both as string literals, with `""` for no alias),
+/// its usage string, the minimum and maximum number of arguments, and the command body.
+/// The body may bind up to three arguments: the raw [`cmd`](./tmux/struct.cmd.html) being
+/// executed, an iterator over the command's arguments as `&`[`CStr`](https://doc.rust-lang.org/std/ffi/struct.CStr.html)s,
+/// and the [`CmdqItem`](./tmux/struct.CmdqItem.html) the command is running as.
+/// The item can be used to print output, report errors, or find the invoking client.
+/// The body returns how the command finished, as a [`CmdResult`](./tmux/enum.CmdResult.html).
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::cmd_plugin;
+/// use tmux_plugin::tmux;
+/// use std::ffi::CString;
+///
+/// // Prints the number of arguments it was called with.
+/// cmd_plugin!("count-args", "", "[argument ...]", 0, -1, |_cmd, args, item| {
+///     let message = CString::new(format!("{} arguments", args.count())).unwrap();
+///     item.print(&message);
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+///
+/// The body can instead declare another return type after its arguments, such as
+/// [`tmux_plugin::Result`](./type.Result.html), or `anyhow::Result` with the `anyhow`
+/// feature, so that it can use `?`. Errors are reported to the user along with their causes,
+/// and the command fails. See [`IntoCmdRetval`](./tmux/trait.IntoCmdRetval.html) for the types
+/// which can be returned.
+///
+/// ```rust
+/// use tmux_plugin::cmd_plugin;
+/// use std::ffi::CString;
+///
+/// // Prints the first line of a file.
+/// cmd_plugin!("head", "", "file", 1, 1, |_cmd, args, item| -> tmux_plugin::Result<()> {
+///     let path = args.last().expect("There is exactly one argument").to_str()?;
+///     let contents = std::fs::read_to_string(path)?;
+///     item.print(&CString::new(contents.lines().next().unwrap_or_default())?);
+///     Ok(())
+/// });
+/// # fn main() {}
+/// ```
+///
+/// A command can have several aliases, given as a list in place of the single alias, such as
+/// `["ls", "l"]`. tmux only looks up one alias for each command, so the command is also added
+/// under each alias after the first, as if it were named after it.
+///
+/// Instead of a usage string, the command's arguments can be described in braces, with the
+/// letters of any flags it takes, the flags which take a value and what that value is, and its
+/// other arguments. tmux then parses the flags, leaving only the other arguments to the body,
+/// and the usage string is generated from the same description. The flags can be read from
+/// the command's [`Args`](./tmux/struct.Args.html).
+///
+/// ```rust
+/// use tmux_plugin::cmd_plugin;
+/// use tmux_plugin::tmux;
+/// use std::ffi::{CStr, CString};
+///
+/// // Usage: shout [-n] [-p prefix] message
+/// cmd_plugin!("shout", "", {
+///     flags: "n",
+///     options: { p: "prefix" },
+///     arguments: "message",
+/// }, 1, 1, |cmd, args, item| {
+///     let flags = unsafe { tmux::Args::from_cmd(cmd) }.expect("tmux parsed the arguments");
+///     let newline = flags.flag('n');
+///     let prefix = flags.value('p').map(CStr::to_string_lossy).unwrap_or_default();
+///     let message = args.last().unwrap().to_string_lossy().to_uppercase();
+///     let end = if newline { "\n" } else { "" };
+///     let output = format!("{}{}{}", prefix, message, end);
+///     item.print(&CString::new(output).unwrap_or_default());
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+///
+/// As with [`format_plugin!`](./macro.format_plugin.html), an expression which evaluates to a
+/// closure can be passed instead of a body. The closure is passed the arguments as a
+/// `&mut dyn Iterator`, and may return anything a body can.
+///
+/// ```rust
+/// use tmux_plugin::cmd_plugin;
+/// use std::ffi::CString;
+///
+/// // Prints how many times it has been run.
+/// cmd_plugin!("count-runs", ["runs", "cr"], "", 0, 0, {
+///     let mut runs = 0;
+///     move |_cmd, _args, item| {
+///         runs += 1;
+///         item.print(&CString::new(format!("run {} times", runs)).unwrap());
+///     }
+/// });
+/// # fn main() {}
+/// ```
 #[macro_export]
 macro_rules! cmd_plugin {
-    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, |$self:ident| $body:block) => {
-        cmd_plugin!(
+    ($name:expr, [$($alias:expr),+ $(,)?], $($rest:tt)*) => {
+        $crate::cmd_plugin!(
+            $name,
+            $crate::names::AliasList::Many(&[$($crate::names::Name($alias).bytes()),+]),
+            $($rest)*
+        );
+    };
+    ($name:expr, $alias:expr, {
+        $(flags: $flags:literal,)?
+        $(options: { $($option:ident : $value:literal),* $(,)? },)?
+        $(arguments: $arguments:literal $(,)?)?
+    }, $($rest:tt)*) => {
+        $crate::cmd_plugin!(
+            $name,
+            $alias,
+            $crate::names::ArgsSpec {
+                template: concat!($($flags,)? $($(stringify!($option), ":",)*)?),
+                usage: concat!(
+                    $("[-", $flags, "] ",)?
+                    $($("[-", stringify!($option), " ", $value, "] ",)*)?
+                    $($arguments)?
+                ),
+            },
+            $($rest)*
+        );
+    };
+    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, |$self:ident| $(-> $ret:ty)? $body:block) => {
+        $crate::cmd_plugin!(
             $name,
             $alias,
             $usage,
             $argsmin,
             $argsmax,
-            |$self: ident, _args| $body
+            |$self, _args, _item| $(-> $ret)? $body
         );
     };
-    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, |$self:ident, $args:ident| $body:block) => {
+    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, |$self:ident, $args:ident| $(-> $ret:ty)? $body:block) => {
+        $crate::cmd_plugin!(
+            $name,
+            $alias,
+            $usage,
+            $argsmin,
+            $argsmax,
+            |$self, $args, _item| $(-> $ret)? $body
+        );
+    };
+    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, |$self:ident, $args:ident, $item:ident| $body:block) => {
+        $crate::cmd_plugin!(
+            $name,
+            $alias,
+            $usage,
+            $argsmin,
+            $argsmax,
+            |$self, $args, $item| -> impl $crate::tmux::IntoCmdRetval $body
+        );
+    };
+    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, |$self:ident, $args:ident, $item:ident| -> $ret:ty $body:block) => {
         mod tmux_cmd_plugin {
             use super::*;
             use $crate::tmux;
 
+            $crate::cmd_plugin!(@entry $name, $alias, $usage, $argsmin, $argsmax);
+
+            #[allow(unused_variables)]
+            fn cmd_plugin_body<'a>(
+                $self: *mut tmux::cmd,
+                $args: impl Iterator<Item = &'a ::std::ffi::CStr>,
+                $item: tmux::CmdqItem,
+            ) -> $ret {
+                $body
+            }
+
+            pub unsafe extern "C" fn cmd_exec(
+                $self: *mut tmux::cmd,
+                item: *mut tmux::cmdq_item,
+            ) -> tmux::cmd_retval {
+                let args = tmux::Args::from_cmd($self).expect("tmux always parses the arguments");
+                let argv = args.positionals();
+                let item = tmux::CmdqItem::from_raw(item)
+                    .expect("tmux always executes commands with a queue item");
+                tmux::IntoCmdRetval::into_cmd_retval(cmd_plugin_body($self, argv, item), item)
+            }
+        }
+    };
+    ($name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr, $callback:expr) => {
+        mod tmux_cmd_plugin {
+            use super::*;
+            use $crate::tmux;
+
+            $crate::cmd_plugin!(@entry $name, $alias, $usage, $argsmin, $argsmax);
+
+            thread_local! {
+                static CALLBACK: ::std::cell::RefCell<Option<$crate::callbacks::Cmd>> =
+                    ::std::cell::RefCell::new(None);
+            }
+
+            pub unsafe extern "C" fn cmd_exec(
+                cmd: *mut tmux::cmd,
+                item: *mut tmux::cmdq_item,
+            ) -> tmux::cmd_retval {
+                let args = tmux::Args::from_cmd(cmd).expect("tmux always parses the arguments");
+                let mut argv = args.positionals();
+                let item = tmux::CmdqItem::from_raw(item)
+                    .expect("tmux always executes commands with a queue item");
+                CALLBACK.with(|callback| {
+                    let mut callback = callback.borrow_mut();
+                    callback.get_or_insert_with(|| $crate::callbacks::cmd($callback))(
+                        cmd, &mut argv, item,
+                    )
+                })
+            }
+        }
+    };
+    (@entry $name:expr, $alias:expr, $usage:expr, $argsmin:expr, $argsmax:expr) => {
+        const ALIASES: $crate::names::AliasList = $crate::names::Aliases($alias).list();
+
+        /// Each alias, null-terminated, one after the other.
+        static ALIAS_NAMES: [u8; ALIASES.joined_len()] = ALIASES.joined();
+
+        const fn entry(
+            name: *const $crate::libc::c_char,
+            alias: *const $crate::libc::c_char,
+            template: *const $crate::libc::c_char,
+            usage: *const $crate::libc::c_char,
+        ) -> Plugin {
+            Plugin(tmux::plugin {
+                type_: tmux::CMD_PLUGIN as $crate::libc::c_int,
+                __bindgen_anon_1: tmux::plugin_inner {
+                    cmd: tmux::cmd_entry {
+                        name,
+                        alias,
+                        args: tmux::cmd_entry__bindgen_ty_1 {
+                            template,
+                            lower: $argsmin,
+                            upper: $argsmax,
+                        },
+                        usage,
+                        source: tmux::cmd_entry_flag {
+                            flag: 0,
+                            type_: 0 as tmux::cmd_find_type,
+                            flags: 0,
+                        },
+                        target: tmux::cmd_entry_flag {
+                            flag: 0,
+                            type_: 0,
+                            flags: 0,
+                        },
+                        flags: 0,
+                        exec: Some(cmd_exec),
+                    },
+                },
+            })
+        }
+
+        /// The command under its first alias, followed by a command for each other alias,
+        /// named after it.
+        static ENTRIES: [Plugin; ALIASES.count()] = {
+            let name = $crate::__name!($name);
+            let template = $crate::__name!(@usage $crate::names::Template($usage).bytes());
+            let usage = $crate::__name!(@usage $usage);
+            let aliases = ALIAS_NAMES.as_ptr() as *const $crate::libc::c_char;
+            let mut entries = [entry(name, aliases, template, usage); ALIASES.count()];
+            let mut i = 1;
+            while i < ALIASES.count() {
+                let alias = unsafe { aliases.add(ALIASES.offset(i)) };
+                entries[i] = entry(alias, alias, template, usage);
+                i += 1;
+            }
+            entries
+        };
+
+        $crate::__plugin!(
+            raw,
+            if ALIASES.count() == 1 {
+                entry(
+                    $crate::__name!($name),
+                    ALIAS_NAMES.as_ptr() as *const $crate::libc::c_char,
+                    $crate::__name!(@usage $crate::names::Template($usage).bytes()),
+                    $crate::__name!(@usage $usage),
+                )
+            } else {
+                Plugin(tmux::plugin {
+                    type_: tmux::MULTI_PLUGIN as $crate::libc::c_int,
+                    __bindgen_anon_1: tmux::plugin_inner {
+                        multi: tmux::multi_plugin {
+                            length: ALIASES.count() as $crate::libc::c_uint,
+                            plugins: ENTRIES.as_ptr() as *mut tmux::plugin,
+                        },
+                    },
+                })
+            }
+        );
+    };
+}
+
+/// Defines a new layout.
+///
+/// tmux's plugin interface has no way to add to the layouts `select-layout` knows about,
+/// so a layout is defined as a command of the same name instead, which arranges the panes of
+/// its target window (`-t`, or the current window). This macro takes the layout's name (as a
+/// string literal) and a function which is passed the
+/// [`Window`](./tmux/struct.Window.html) and a `Vec` of its [`Pane`](./tmux/struct.Pane.html)s,
+/// and returns the [`Layout`](./tmux/enum.Layout.html) to apply. If the layout can't be
+/// applied, the command fails with the [`LayoutError`](./tmux/enum.LayoutError.html).
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::layout_plugin;
+/// use tmux_plugin::tmux::{Layout, Orientation};
+///
+/// // Arranges the panes in columns of equal width.
+/// layout_plugin!("even-columns", |window, panes| {
+///     let (width, height) = window.size();
+///     let count = panes.len() as u32;
+///     let available = width - (count - 1);
+///     let columns = panes
+///         .into_iter()
+///         .enumerate()
+///         .map(|(i, pane)| {
+///             let extra = if (i as u32) < available % count { 1 } else { 0 };
+///             Layout::pane(pane, available / count + extra, height)
+///         })
+///         .collect();
+///     Layout::split(Orientation::LeftRight, columns)
+/// });
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! layout_plugin {
+    ($name:expr, |$window:ident, $panes:ident| $body:block) => {
+        mod tmux_layout_plugin {
+            use super::*;
+            use $crate::tmux;
+
             $crate::__plugin!(
                 cmd,
                 tmux::cmd_entry {
-                    name: $name as *const u8 as *const $crate::libc::c_char,
-                    alias: $alias as *const u8 as *const $crate::libc::c_char,
+                    name: $crate::__name!($name),
+                    alias: $crate::cstr!("").as_ptr(),
                     args: tmux::cmd_entry__bindgen_ty_1 {
-                        template: b"" as *const u8 as *const $crate::libc::c_char,
-                        lower: $argsmin,
-                        upper: $argsmax,
+                        template: $crate::cstr!("t:").as_ptr(),
+                        lower: 0,
+                        upper: 0,
                     },
-                    usage: $usage as *const u8 as *const $crate::libc::c_char,
+                    usage: $crate::cstr!("[-t target-window]").as_ptr(),
                     source: tmux::cmd_entry_flag {
                         flag: 0,
                         type_: 0 as tmux::cmd_find_type,
                         flags: 0,
                     },
                     target: tmux::cmd_entry_flag {
-                        flag: 0,
-                        type_: 0,
+                        flag: b't' as $crate::libc::c_char,
+                        type_: tmux::cmd_find_type_CMD_FIND_WINDOW,
                         flags: 0,
                     },
                     flags: 0,
@@ -256,28 +1024,384 @@ macro_rules! cmd_plugin {
                 }
             );
 
-            fn cmd_plugin_body<'a>(
-                $self: *mut tmux::cmd,
-                $args: impl Iterator<Item = &'a CStr>,
-            ) -> tmux::cmd_retval {
+            fn layout_plugin_body(
+                $window: tmux::Window,
+                $panes: ::std::vec::Vec<tmux::Pane>,
+            ) -> tmux::Layout {
                 $body
             }
 
             pub unsafe extern "C" fn cmd_exec(
-                $self: *mut tmux::cmd,
-                _item: *mut tmux::cmdq_item,
+                _cmd: *mut tmux::cmd,
+                item: *mut tmux::cmdq_item,
             ) -> tmux::cmd_retval {
-                let args = *(*$self).args;
-                let argv: &[*mut i8] = std::slice::from_raw_parts(args.argv, args.argc as usize);
-                let argv = argv
-                    .iter()
-                    .map(|arg| unsafe { ::std::ffi::CStr::from_ptr(*arg) });
-                cmd_plugin_body($self, argv)
+                let window = match tmux::Window::from_raw((*item).target.w) {
+                    Some(window) => window,
+                    None => return tmux::cmd_retval_CMD_RETURN_ERROR,
+                };
+                let item = tmux::CmdqItem::from_raw(item)
+                    .expect("tmux always executes commands with a queue item");
+                let layout = layout_plugin_body(window, window.panes());
+                let result = match window.apply_layout(&layout) {
+                    Ok(()) => tmux::CmdResult::Normal,
+                    Err(err) => tmux::CmdResult::Error(err.to_string()),
+                };
+                tmux::IntoCmdRetval::into_cmd_retval(result, item)
             }
         }
     };
 }
 
+/// Defines several format variables sharing a common prefix.
+///
+/// This macro takes a namespace (as a string literal), and a list of variable names
+/// (also string literals) and functions to calculate their values, in the same form as
+/// [`format_plugin!`](./macro.format_plugin.html). Each variable is registered as the
+/// namespace and its name joined with an underscore, so that variables from different
+/// plugins don't collide. Since a crate can only define one plugin, this is also the way
+/// to define more than one format variable in a crate.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_plugins;
+/// use std::ffi::CString;
+///
+/// // Defines #{size_width} and #{size_height}.
+/// format_plugins!("size", {
+///     "width" => |format_tree| {
+///         CString::new(format!("{}", unsafe { *(*format_tree).w }.sx)).unwrap()
+///     },
+///     "height" => |format_tree| {
+///         CString::new(format!("{}", unsafe { *(*format_tree).w }.sy)).unwrap()
+///     },
+/// });
+/// # fn main() {}
+/// ```
+///
+/// The joined names are checked like any other, so a namespace or name with whitespace, `#`,
+/// `{` or `}` in it fails to compile:
+///
+/// ```rust,compile_fail
+/// use tmux_plugin::format_plugins;
+/// use std::ffi::CString;
+///
+/// format_plugins!("my size", {
+///     "width" => |_format_tree| { CString::new("").unwrap() },
+/// });
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! format_plugins {
+    ($namespace:literal, { $($name:literal => |$ft:ident| $body:block),+ $(,)? }) => {
+        mod tmux_format_plugins {
+            use super::*;
+            use $crate::tmux;
+
+            $crate::__plugin!(
+                multi,
+                tmux::multi_plugin {
+                    length: PLUGINS.len() as $crate::libc::c_uint,
+                    plugins: PLUGINS.as_ptr() as *mut tmux::plugin,
+                }
+            );
+
+            static PLUGINS: [Plugin; [$($name),+].len()] = [$(
+                {
+                    use std::convert::AsRef;
+                    fn format_plugin_body(
+                        $ft: *mut tmux::format_tree,
+                    ) -> impl ::std::convert::AsRef<::std::ffi::CStr> {
+                        $body
+                    }
+
+                    unsafe extern "C" fn plugin_format_cb(
+                        ft: *mut tmux::format_tree,
+                        fe: *mut tmux::format_entry,
+                    ) {
+                        let return_str = format_plugin_body(ft);
+                        tmux::strings::OwnedTmuxString::new(return_str.as_ref())
+                            .hand_off(&mut (*fe).value);
+                    }
+
+                    Plugin(tmux::plugin {
+                        type_: tmux::FORMAT_PLUGIN as $crate::libc::c_int,
+                        __bindgen_anon_1: tmux::plugin_inner {
+                            format: tmux::format_plugin {
+                                name: $crate::__name!(concat!($namespace, "_", $name)),
+                                cb: Some(plugin_format_cb),
+                            },
+                        },
+                    })
+                }
+            ),+];
+        }
+    };
+}
+
+/// Defines several format variables calculated together.
+///
+/// This macro takes a namespace and a list of variable names (as string literals), and a
+/// function which is passed a tmux [`format_tree`](./tmux/struct.format_tree.html) and a
+/// `&mut HashMap<&'static str, String>` to fill with the variables' values, keyed by their
+/// names without the namespace. Each variable is registered as the namespace and its name
+/// joined with an underscore, as with [`format_plugins!`](./macro.format_plugins.html).
+///
+/// The function is only called once each time tmux draws a status line or otherwise
+/// expands formats, however many of the variables are used, so it can do expensive work
+/// shared by all of them. Variables it leaves out of the map are empty.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_group_plugin;
+/// use std::process::Command;
+///
+/// // Defines #{git_branch} and #{git_dirty} from a single run of `git status`.
+/// format_group_plugin!("git", ["branch", "dirty"], |_format_tree, values| {
+///     let output = match Command::new("git").args(&["status", "--porcelain", "-b"]).output() {
+///         Ok(output) => String::from_utf8_lossy(&output.stdout).into_owned(),
+///         Err(_) => return,
+///     };
+///     let mut lines = output.lines();
+///     if let Some(branch) = lines.next().and_then(|line| line.strip_prefix("## ")) {
+///         let branch = branch.split("...").next().unwrap_or(branch);
+///         values.insert("branch", branch.to_owned());
+///     }
+///     let dirty = if lines.next().is_some() { "1" } else { "0" };
+///     values.insert("dirty", dirty.to_owned());
+/// });
+/// # fn main() {}
+/// ```
+///
+/// As with [`format_plugins!`](./macro.format_plugins.html), the joined names must be usable
+/// from tmux, so this fails to compile:
+///
+/// ```rust,compile_fail
+/// use tmux_plugin::format_group_plugin;
+///
+/// format_group_plugin!("git", ["#{branch}"], |_format_tree, _values| {});
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! format_group_plugin {
+    ($namespace:literal, [$($name:literal),+ $(,)?], |$ft:ident, $values:ident| $body:block) => {
+        mod tmux_format_group_plugin {
+            use super::*;
+            use $crate::tmux;
+
+            $crate::__plugin!(
+                multi,
+                tmux::multi_plugin {
+                    length: PLUGINS.len() as $crate::libc::c_uint,
+                    plugins: PLUGINS.as_ptr() as *mut tmux::plugin,
+                }
+            );
+
+            ::std::thread_local! {
+                static CACHE: ::std::cell::RefCell<tmux::format::GroupCache> =
+                    ::std::cell::RefCell::new(::std::default::Default::default());
+            }
+
+            #[allow(unused_variables)]
+            fn format_group_plugin_body(
+                $ft: *mut tmux::format_tree,
+                $values: &mut ::std::collections::HashMap<&'static str, ::std::string::String>,
+            ) {
+                $body
+            }
+
+            static PLUGINS: [Plugin; [$($name),+].len()] = [$(
+                {
+                    unsafe extern "C" fn plugin_format_cb(
+                        ft: *mut tmux::format_tree,
+                        fe: *mut tmux::format_entry,
+                    ) {
+                        let value = CACHE.with(|cache| {
+                            cache.borrow_mut().get(ft, $name, format_group_plugin_body)
+                        });
+                        tmux::strings::OwnedTmuxString::new(&value).hand_off(&mut (*fe).value);
+                    }
+
+                    Plugin(tmux::plugin {
+                        type_: tmux::FORMAT_PLUGIN as $crate::libc::c_int,
+                        __bindgen_anon_1: tmux::plugin_inner {
+                            format: tmux::format_plugin {
+                                name: $crate::__name!(concat!($namespace, "_", $name)),
+                                cb: Some(plugin_format_cb),
+                            },
+                        },
+                    })
+                }
+            ),+];
+        }
+    };
+}
+
+/// Describes the plugin, so that tmux and other tools can show what it is.
+///
+/// This exports a [`PluginMetadata`](./struct.PluginMetadata.html) as the `plugin_metadata`
+/// symbol. By default, it describes the plugin using the `name`, `version`, `authors` and
+/// `description` from the crate's `Cargo.toml`. Any of these can be given instead, as string
+/// literals, with the keys `name`, `version`, `author` and `description`.
+///
+/// Like the plugin macros, this may only be used once in a crate.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::plugin_metadata;
+///
+/// plugin_metadata! {
+///     name: "weather",
+///     description: "Shows the weather in the status line",
+/// }
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! plugin_metadata {
+    ($($key:ident : $value:expr),* $(,)?) => {
+        #[allow(non_upper_case_globals)]
+        #[no_mangle]
+        pub static plugin_metadata: $crate::PluginMetadata = {
+            let metadata = unsafe {
+                $crate::PluginMetadata::__new(
+                    $crate::__name!(@usage env!("CARGO_PKG_NAME")),
+                    $crate::__name!(@usage env!("CARGO_PKG_VERSION")),
+                    $crate::__name!(@usage env!("CARGO_PKG_AUTHORS")),
+                    $crate::__name!(@usage env!("CARGO_PKG_DESCRIPTION")),
+                )
+            };
+            $(let metadata = $crate::plugin_metadata!(@with metadata, $key, $value);)*
+            metadata
+        };
+    };
+    (@with $metadata:ident, name, $value:expr) => {
+        unsafe { $metadata.__with_name($crate::__name!($value)) }
+    };
+    (@with $metadata:ident, version, $value:expr) => {
+        unsafe { $metadata.__with_version($crate::__name!(@usage $value)) }
+    };
+    (@with $metadata:ident, author, $value:expr) => {
+        unsafe { $metadata.__with_author($crate::__name!(@usage $value)) }
+    };
+    (@with $metadata:ident, description, $value:expr) => {
+        unsafe { $metadata.__with_description($crate::__name!(@usage $value)) }
+    };
+}
+
+/// Declares the facilities of tmux the plugin needs, so that a build of tmux without them can
+/// refuse to load it with a readable error.
+///
+/// This exports the [`Capabilities`](./struct.Capabilities.html) named, such as `JOBS` or
+/// `MENUS`, as the `plugin_capabilities` symbol. Like the plugin macros, this may only be used
+/// once in a crate.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::plugin_capabilities;
+///
+/// plugin_capabilities!(JOBS, MODES);
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! plugin_capabilities {
+    ($($capability:ident),* $(,)?) => {
+        #[allow(non_upper_case_globals)]
+        #[no_mangle]
+        pub static plugin_capabilities: $crate::Capabilities =
+            $crate::Capabilities::empty()$(.union($crate::Capabilities::$capability))*;
+    };
+}
+
+/// Checks, when the plugin is loaded, that tmux supports the version of the plugin interface
+/// it was built for, [`PLUGIN_API_VERSION`](./constant.PLUGIN_API_VERSION.html).
+///
+/// Every plugin exports the version it was built for as `plugin_api_version`, but builds of
+/// tmux from before it was added don't check it, and misread a plugin built for another
+/// version. This looks up `tmux_plugin_api_1`, for version 1, with
+/// [`has_symbol`](./tmux/fn.has_symbol.html), as a build of tmux can define a symbol named
+/// after each version it supports. When tmux has none of them, as no build so far does, or
+/// only has others, the plugin can't stop itself from loading, so it writes a warning to
+/// standard error instead.
+///
+/// Like the plugin macros, this may only be used once in a crate.
+///
+/// ```rust
+/// use tmux_plugin::check_plugin_api;
+///
+/// check_plugin_api!();
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! check_plugin_api {
+    () => {
+        #[doc(hidden)]
+        pub mod tmux_plugin_api_check {
+            // Run as the library is loaded, before tmux looks at the plugin.
+            #[used]
+            #[cfg_attr(
+                any(target_os = "linux", target_os = "android", target_os = "freebsd"),
+                link_section = ".init_array"
+            )]
+            #[cfg_attr(target_vendor = "apple", link_section = "__DATA,__mod_init_func")]
+            static CHECK: extern "C" fn() = check;
+
+            extern "C" fn check() {
+                $crate::__check_plugin_api();
+            }
+        }
+    };
+}
+
+/// A `&'static CStr` from a string literal, null-terminated at compile time.
+///
+/// Like the names given to the plugin macros, this takes a string literal, a byte string with
+/// or without a null terminator, or a `&CStr`, and fails to compile if the string contains a
+/// null byte anywhere but at its end.
+///
+/// ```rust
+/// use tmux_plugin::cstr;
+/// use std::ffi::CStr;
+///
+/// const GREETING: &CStr = cstr!("hello");
+/// assert_eq!(GREETING.to_bytes_with_nul(), b"hello\0");
+/// assert_eq!(cstr!(b"hello\0"), GREETING);
+/// assert_eq!(cstr!(concat!("hel", "lo")), GREETING);
+/// ```
+///
+/// ```compile_fail
+/// use tmux_plugin::cstr;
+///
+/// let _ = cstr!("hel\0lo");
+/// ```
+#[macro_export]
+macro_rules! cstr {
+    ($string:expr) => {{
+        const BYTES: &[u8] = $crate::names::Name($string).bytes();
+        const _: () = $crate::names::check_nul(BYTES);
+        const STRING: [u8; $crate::names::len_with_nul(BYTES)] = $crate::names::with_nul(BYTES);
+        const CSTR: &::std::ffi::CStr =
+            unsafe { ::std::ffi::CStr::from_bytes_with_nul_unchecked(&STRING) };
+        CSTR
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __name {
+    (@usage $usage:expr) => {
+        $crate::cstr!($usage).as_ptr()
+    };
+    ($name:expr) => {{
+        const BYTES: &[u8] = $crate::names::Name($name).bytes();
+        const _: () = $crate::names::check_name(BYTES);
+        static NAME: [u8; $crate::names::len_with_nul(BYTES)] = $crate::names::with_nul(BYTES);
+        NAME.as_ptr() as *const $crate::libc::c_char
+    }};
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __plugin {
@@ -293,16 +1417,54 @@ macro_rules! __plugin {
     (cmd, $body:expr) => {
         $crate::__plugin!(cmd, tmux::CMD_PLUGIN, $body);
     };
-    ($field:ident, $type:expr, $body:expr) => {
+    (multi, $body:expr) => {
+        $crate::__plugin!(multi, tmux::MULTI_PLUGIN, $body);
+    };
+    (raw, $plugin:expr) => {
         #[repr(transparent)]
+        #[derive(Clone, Copy)]
         pub struct Plugin(tmux::plugin);
         unsafe impl Sync for Plugin {}
 
         #[allow(non_upper_case_globals)]
         #[no_mangle]
-        static plugin: Plugin = Plugin(tmux::plugin {
-            type_: $type as $crate::libc::c_int,
-            __bindgen_anon_1: { tmux::plugin_inner { $field: $body } },
-        });
+        static plugin: Plugin = $plugin;
+
+        // tmux loads a single `plugin` symbol from each library, so defining a second plugin
+        // fails with a duplicate symbol error. This symbol is defined alongside it so that
+        // the error explains what to do instead.
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static ONLY_ONE_PLUGIN_MACRO_PER_LIBRARY__USE_FORMAT_PLUGINS_TO_DEFINE_SEVERAL: u8 = 0;
+
+        /// The version of the plugin interface the plugin was built for, so that tmux can
+        /// refuse to load it if it doesn't support that version.
+        #[allow(non_upper_case_globals)]
+        #[doc(hidden)]
+        #[no_mangle]
+        pub static plugin_api_version: $crate::libc::c_uint = $crate::PLUGIN_API_VERSION;
+
+        /// Describes the plugin's commands as JSON, for generating shell completions.
+        #[doc(hidden)]
+        #[no_mangle]
+        pub extern "C" fn plugin_completions() -> *const $crate::libc::c_char {
+            static COMPLETIONS: ::std::sync::OnceLock<::std::ffi::CString> =
+                ::std::sync::OnceLock::new();
+            COMPLETIONS
+                .get_or_init(|| {
+                    let json = unsafe { $crate::tmux::completion::describe(&plugin.0) };
+                    $crate::tmux::strings::to_tmux_string_lossy(json)
+                })
+                .as_ptr()
+        }
+    };
+    ($field:ident, $type:expr, $body:expr) => {
+        $crate::__plugin!(
+            raw,
+            Plugin(tmux::plugin {
+                type_: $type as $crate::libc::c_int,
+                __bindgen_anon_1: { tmux::plugin_inner { $field: $body } },
+            })
+        );
     };
 }
@@ -0,0 +1,201 @@
+use crate::tmux;
+use bitflags::bitflags;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+/// The version of the plugin interface the crate builds plugins for: the layout of the
+/// `plugin` struct tmux loads from each plugin, and what tmux expects of the callbacks in it.
+///
+/// It goes up whenever the interface changes in a way which plugins built for the old one
+/// can't be loaded with. Each plugin exports it as the `plugin_api_version` symbol, an
+/// `unsigned int`, and [`check_plugin_api!`](./macro.check_plugin_api.html) makes a plugin
+/// refuse to load where tmux doesn't support it.
+pub const PLUGIN_API_VERSION: u32 = 1;
+
+/// Checks that tmux supports the version of the plugin interface the plugin was built for,
+/// when the plugin is loaded, for [`check_plugin_api!`](./macro.check_plugin_api.html).
+#[doc(hidden)]
+pub fn check_plugin_api() {
+    let reports = |version: u32| tmux::has_symbol(&format!("tmux_plugin_api_{}", version));
+    if reports(PLUGIN_API_VERSION) {
+        return;
+    }
+    // Later versions are probed for too, so that a tmux which has moved on can say so.
+    let supported: Vec<String> = (1..=PLUGIN_API_VERSION + 8)
+        .filter(|&version| reports(version))
+        .map(|version| version.to_string())
+        .collect();
+    if supported.is_empty() {
+        eprintln!(
+            "warning: tmux doesn't say which versions of the plugin interface it supports, so \
+             a plugin built for version {} can't be checked against it",
+            PLUGIN_API_VERSION
+        );
+    } else {
+        eprintln!(
+            "warning: a plugin built for version {} of the plugin interface was loaded into \
+             tmux, which supports version {}, and may not work",
+            PLUGIN_API_VERSION,
+            supported.join(", ")
+        );
+    }
+}
+
+/// A description of a plugin, exported by [`plugin_metadata!`](./macro.plugin_metadata.html)
+/// as the `plugin_metadata` symbol.
+///
+/// Its layout is the same as this C struct, so tmux and other tools can read it from the
+/// library:
+///
+/// ```c
+/// struct plugin_metadata {
+///     const char *name;
+///     const char *version;
+///     const char *author;
+///     const char *description;
+/// };
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct PluginMetadata {
+    name: *const c_char,
+    version: *const c_char,
+    author: *const c_char,
+    description: *const c_char,
+}
+
+// The strings are never written to.
+unsafe impl Sync for PluginMetadata {}
+
+impl PluginMetadata {
+    #[doc(hidden)]
+    /// # Safety
+    ///
+    /// Each pointer must point to a null-terminated string which lives for the rest of the
+    /// program, as do those passed to the `__with_` functions.
+    pub const unsafe fn __new(
+        name: *const c_char,
+        version: *const c_char,
+        author: *const c_char,
+        description: *const c_char,
+    ) -> PluginMetadata {
+        PluginMetadata {
+            name,
+            version,
+            author,
+            description,
+        }
+    }
+
+    #[doc(hidden)]
+    pub const unsafe fn __with_name(self, name: *const c_char) -> PluginMetadata {
+        PluginMetadata { name, ..self }
+    }
+
+    #[doc(hidden)]
+    pub const unsafe fn __with_version(self, version: *const c_char) -> PluginMetadata {
+        PluginMetadata { version, ..self }
+    }
+
+    #[doc(hidden)]
+    pub const unsafe fn __with_author(self, author: *const c_char) -> PluginMetadata {
+        PluginMetadata { author, ..self }
+    }
+
+    #[doc(hidden)]
+    pub const unsafe fn __with_description(self, description: *const c_char) -> PluginMetadata {
+        PluginMetadata {
+            description,
+            ..self
+        }
+    }
+
+    /// The plugin's name.
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.name) }
+    }
+
+    /// The plugin's version.
+    pub fn version(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.version) }
+    }
+
+    /// Whoever wrote the plugin.
+    pub fn author(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.author) }
+    }
+
+    /// A short description of what the plugin does.
+    pub fn description(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.description) }
+    }
+}
+
+/// When a plugin's notification callbacks run, relative to those of other plugins for the same
+/// event, given to [`notification_plugin!`](./macro.notification_plugin.html).
+///
+/// Callbacks with a lower priority run first. A plugin's priority is exported as the
+/// `plugin_priority` symbol, an `int`, for tmux to order plugins by. Plugins without one have
+/// the [`NORMAL`](#associatedconstant.NORMAL) priority, and plugins with the same priority run
+/// in the order they were loaded.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Priority(pub c_int);
+
+impl Priority {
+    /// For plugins which change what the event is about, such as renaming a window, so that
+    /// other plugins see the result.
+    pub const TRANSFORM: Priority = Priority(-100);
+    /// The priority of plugins which don't declare one.
+    pub const NORMAL: Priority = Priority(0);
+    /// For plugins which only look at what the event is about, such as to log it, so that
+    /// they see it after other plugins have changed it.
+    pub const OBSERVE: Priority = Priority(100);
+}
+
+impl Default for Priority {
+    fn default() -> Priority {
+        Priority::NORMAL
+    }
+}
+
+bitflags! {
+    /// The facilities of tmux a plugin needs, declared with
+    /// [`plugin_capabilities!`](./macro.plugin_capabilities.html).
+    ///
+    /// They are exported as the `plugin_capabilities` symbol, an `unsigned int`, so that a
+    /// build of tmux without one of them can refuse to load the plugin and say why, rather
+    /// than failing to find a symbol, or loading the plugin only for it to misbehave.
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct Capabilities: u32 {
+        /// Running jobs in the background, as `run-shell -b` does.
+        const JOBS = 1 << 0;
+        /// Putting panes into modes of the plugin's own, as `choose-tree` does.
+        const MODES = 1 << 1;
+        /// Showing menus, as `display-menu` does.
+        const MENUS = 1 << 2;
+    }
+}
+
+/// The functions each capability needs from tmux.
+const CAPABILITY_SYMBOLS: &[(Capabilities, &str)] = &[
+    (Capabilities::JOBS, "job_run"),
+    (Capabilities::MODES, "window_pane_set_mode"),
+    (Capabilities::MENUS, "menu_display"),
+];
+
+impl Capabilities {
+    /// The capabilities the build of tmux the plugin was loaded into has, judging by which
+    /// functions it has.
+    ///
+    /// A plugin can check its capabilities itself with this, where tmux doesn't check them.
+    pub fn available() -> Capabilities {
+        CAPABILITY_SYMBOLS
+            .iter()
+            .filter(|(_, symbol)| tmux::has_symbol(symbol))
+            .fold(Capabilities::empty(), |all, (capability, _)| {
+                all | *capability
+            })
+    }
+}
@@ -0,0 +1,233 @@
+//! Turning the names passed to the plugin macros into null-terminated strings at compile time.
+//!
+//! Names can be string literals, byte strings with or without a null terminator, or `&CStr`s.
+//! `__name!` and `cstr!` add the terminator when it is missing.
+
+use std::ffi::CStr;
+
+/// A name passed to one of the plugin macros, before it is null-terminated.
+pub struct Name<T>(pub T);
+
+impl Name<&'static str> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl<const N: usize> Name<&'static [u8; N]> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.0
+    }
+}
+
+impl Name<&'static [u8]> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.0
+    }
+}
+
+impl Name<&'static CStr> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.0.to_bytes_with_nul()
+    }
+}
+
+/// A command's arguments, as given to `cmd_plugin!` in place of a usage string: the template
+/// tmux parses the arguments with, and the usage string generated from the same description.
+pub struct ArgsSpec {
+    pub template: &'static str,
+    pub usage: &'static str,
+}
+
+impl Name<ArgsSpec> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        trim_end(self.0.usage.as_bytes())
+    }
+}
+
+/// The template for parsing a command's arguments, which is empty unless they were described
+/// with an [`ArgsSpec`](./struct.ArgsSpec.html).
+pub struct Template<T>(pub T);
+
+impl Template<ArgsSpec> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.0.template.as_bytes()
+    }
+}
+
+impl Template<&'static str> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        b""
+    }
+}
+
+impl<const N: usize> Template<&'static [u8; N]> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        b""
+    }
+}
+
+impl Template<&'static [u8]> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        b""
+    }
+}
+
+impl Template<&'static CStr> {
+    pub const fn bytes(&self) -> &'static [u8] {
+        b""
+    }
+}
+
+/// A command's aliases, as given to `cmd_plugin!`: either one alias, or a list of them.
+#[derive(Clone, Copy)]
+pub enum AliasList {
+    One(&'static [u8]),
+    Many(&'static [&'static [u8]]),
+}
+
+/// An alias or list of aliases passed to `cmd_plugin!`, before it is made into an
+/// [`AliasList`](./enum.AliasList.html).
+pub struct Aliases<T>(pub T);
+
+impl Aliases<AliasList> {
+    pub const fn list(&self) -> AliasList {
+        self.0
+    }
+}
+
+impl Aliases<&'static str> {
+    pub const fn list(&self) -> AliasList {
+        AliasList::One(Name(self.0).bytes())
+    }
+}
+
+impl<const N: usize> Aliases<&'static [u8; N]> {
+    pub const fn list(&self) -> AliasList {
+        AliasList::One(Name(self.0).bytes())
+    }
+}
+
+impl Aliases<&'static [u8]> {
+    pub const fn list(&self) -> AliasList {
+        AliasList::One(Name(self.0).bytes())
+    }
+}
+
+impl Aliases<&'static CStr> {
+    pub const fn list(&self) -> AliasList {
+        AliasList::One(Name(self.0).bytes())
+    }
+}
+
+impl AliasList {
+    /// The number of aliases. A single empty alias, for a command with none, counts as one.
+    pub const fn count(&self) -> usize {
+        match self {
+            AliasList::One(_) => 1,
+            AliasList::Many(aliases) => aliases.len(),
+        }
+    }
+
+    const fn get(&self, i: usize) -> &'static [u8] {
+        match self {
+            AliasList::One(alias) => alias,
+            AliasList::Many(aliases) => aliases[i],
+        }
+    }
+
+    /// Where the `i`th alias starts among the [joined](#method.joined) aliases.
+    pub const fn offset(&self, i: usize) -> usize {
+        let mut offset = 0;
+        let mut j = 0;
+        while j < i {
+            offset += len_with_nul(self.get(j));
+            j += 1;
+        }
+        offset
+    }
+
+    /// The length of the [joined](#method.joined) aliases.
+    pub const fn joined_len(&self) -> usize {
+        self.offset(self.count())
+    }
+
+    /// The aliases, each null-terminated, one after the other.
+    pub const fn joined<const N: usize>(&self) -> [u8; N] {
+        let mut joined = [0; N];
+        let mut i = 0;
+        while i < self.count() {
+            let alias = self.get(i);
+            check_name(alias);
+            let offset = self.offset(i);
+            let mut j = 0;
+            while j < alias.len() && offset + j < N {
+                joined[offset + j] = alias[j];
+                j += 1;
+            }
+            i += 1;
+        }
+        joined
+    }
+}
+
+/// The bytes without any trailing spaces.
+const fn trim_end(bytes: &[u8]) -> &[u8] {
+    let mut end = bytes.len();
+    while end > 0 && bytes[end - 1] == b' ' {
+        end -= 1;
+    }
+    bytes.split_at(end).0
+}
+
+const fn is_terminated(bytes: &[u8]) -> bool {
+    !bytes.is_empty() && bytes[bytes.len() - 1] == 0
+}
+
+/// Checks that a string has no null bytes before its end, failing to compile if it does.
+pub const fn check_nul(bytes: &[u8]) {
+    let mut i = 0;
+    while i + 1 < bytes.len() {
+        if bytes[i] == 0 {
+            panic!("plugin names, usage strings and `cstr!` strings can't contain null bytes");
+        }
+        i += 1;
+    }
+}
+
+/// Checks that a name can be used from tmux, failing to compile if it can't.
+///
+/// tmux splits commands on whitespace, and treats `#`, `{` and `}` specially in formats, so a
+/// name containing them can never be referred to.
+pub const fn check_name(bytes: &[u8]) {
+    check_nul(bytes);
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b' ' | b'\t' | b'\n' | b'\r' => panic!("plugin names can't contain whitespace"),
+            b'#' | b'{' | b'}' => panic!("plugin names can't contain `#` or braces"),
+            _ => {}
+        }
+        i += 1;
+    }
+}
+
+/// The length of the name once it is null-terminated.
+pub const fn len_with_nul(bytes: &[u8]) -> usize {
+    if is_terminated(bytes) {
+        bytes.len()
+    } else {
+        bytes.len() + 1
+    }
+}
+
+/// The name, null-terminated.
+pub const fn with_nul<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    let mut name = [0; N];
+    let mut i = 0;
+    while i < bytes.len() && i < N {
+        name[i] = bytes[i];
+        i += 1;
+    }
+    name
+}
@@ -0,0 +1,38 @@
+//! The names most plugins use, to import all at once.
+//!
+//! This has the plugin macros and `cstr!`, the crate's error type, `CStr` and `CString`, the
+//! wrappers for tmux's clients, sessions, windows and panes, and the tmux types and constants
+//! plugin callbacks take and return:
+//!
+//! ```rust
+//! use tmux_plugin::prelude::*;
+//!
+//! // Shows the name of the client's session, in bold.
+//! format_plugin!("session_name_bold", |format_tree| {
+//!     let session = unsafe { Session::from_raw((*format_tree).s) };
+//!     let name = session.map(|session| session.name().to_string_lossy().into_owned());
+//!     StyledText::new()
+//!         .span(Style::new().bold().span(&name.unwrap_or_default()))
+//!         .into_c_string()
+//! });
+//! # fn main() {}
+//! ```
+
+pub use crate::{
+    check_plugin_api, cmd_plugin, cstr, format_function_plugin, format_group_plugin, format_plugin,
+    format_plugins, layout_plugin, notification_plugin, plugin_capabilities, plugin_metadata,
+};
+pub use crate::{Capabilities, Error, Priority, Result};
+
+pub use crate::tmux::{
+    display_message, queue_commands, Client, CmdResult, CmdqItem, Colour, CommandError, Options,
+    Pane, Session, Style, StyledText, Window,
+};
+
+pub use crate::tmux::{
+    cmd_retval, cmd_retval_CMD_RETURN_ERROR, cmd_retval_CMD_RETURN_NORMAL,
+    cmd_retval_CMD_RETURN_STOP, cmd_retval_CMD_RETURN_WAIT, format_entry, format_tree,
+    notify_entry,
+};
+
+pub use std::ffi::{CStr, CString};
@@ -0,0 +1,1034 @@
+//! No-op definitions of every tmux function and variable the crate uses, for the `stub`
+//! feature, described in the crate's documentation under "Testing".
+//!
+//! These are ordinary symbols rather than weak ones, which Rust has no stable way to define, so
+//! in a plugin loaded into tmux they would be called instead of tmux's own. Functions return
+//! null, zero or an empty string, except where the crate relies on a more useful answer, such
+//! as `xstrdup` copying its argument and `format_expand` returning the format it was given.
+//!
+//! With the `tracked-alloc` feature as well, the strings handed out here are allocated by Rust
+//! and recorded, and [`strings::free`](../tmux/strings/fn.free.html) checks each one it is given
+//! against the record before dropping it. Freeing a string twice, or one which never came from
+//! here, panics rather than corrupting the heap, and tools such as Miri and AddressSanitizer,
+//! which can't see into `strdup` or `free` in C, catch any use of a string after it is freed.
+
+#![allow(non_upper_case_globals, clippy::missing_safety_doc)]
+
+use crate::tmux_bindings::*;
+#[cfg(feature = "tracked-alloc")]
+use std::collections::BTreeSet;
+#[cfg(feature = "tracked-alloc")]
+use std::ffi::{CStr, CString};
+use std::mem;
+use std::os::raw::{c_char, c_int, c_longlong, c_short, c_void};
+use std::ptr;
+#[cfg(feature = "tracked-alloc")]
+use std::sync::Mutex;
+
+static EMPTY: [u8; 1] = [0];
+
+// The global options and environment are never null in tmux, so they point at something, even
+// though nothing ever looks inside it.
+static mut GLOBAL_ENVIRON: u8 = 0;
+static mut GLOBAL_OPTIONS: u8 = 0;
+static mut GLOBAL_S_OPTIONS: u8 = 0;
+static mut GLOBAL_W_OPTIONS: u8 = 0;
+
+/// The addresses of the strings allocated by [`copy`](./fn.copy.html) and not yet freed.
+#[cfg(feature = "tracked-alloc")]
+static ALLOCATED: Mutex<BTreeSet<usize>> = Mutex::new(BTreeSet::new());
+
+/// Copies a string, as tmux's `xstrdup` does.
+#[cfg(not(feature = "tracked-alloc"))]
+unsafe fn copy(s: *const c_char) -> *mut c_char {
+    libc::strdup(s)
+}
+
+/// Copies a string into a Rust allocation, recording it for [`free`](./fn.free.html).
+#[cfg(feature = "tracked-alloc")]
+unsafe fn copy(s: *const c_char) -> *mut c_char {
+    let copy = CString::from(CStr::from_ptr(s)).into_raw();
+    allocated().insert(copy as usize);
+    copy
+}
+
+/// Frees a string from [`copy`](./fn.copy.html).
+///
+/// # Panics
+///
+/// If the string didn't come from `copy`, or has already been freed.
+#[cfg(feature = "tracked-alloc")]
+pub(crate) unsafe fn free(s: *mut c_char) {
+    if s.is_null() {
+        return;
+    }
+    if !allocated().remove(&(s as usize)) {
+        panic!("Freed a string which was never allocated for tmux, or was already freed");
+    }
+    drop(CString::from_raw(s));
+}
+
+#[cfg(feature = "tracked-alloc")]
+fn allocated() -> std::sync::MutexGuard<'static, BTreeSet<usize>> {
+    // A test which panicked while holding the lock leaves the set as it was.
+    ALLOCATED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[no_mangle]
+pub static mut clients: clients = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub static mut global_environ: *mut environ = ptr::addr_of_mut!(GLOBAL_ENVIRON) as *mut environ;
+
+#[no_mangle]
+pub static mut global_options: *mut options = ptr::addr_of_mut!(GLOBAL_OPTIONS) as *mut options;
+
+#[no_mangle]
+pub static mut global_s_options: *mut options = ptr::addr_of_mut!(GLOBAL_S_OPTIONS) as *mut options;
+
+#[no_mangle]
+pub static mut global_w_options: *mut options = ptr::addr_of_mut!(GLOBAL_W_OPTIONS) as *mut options;
+
+#[no_mangle]
+pub static mut marked_pane: cmd_find_state = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub static mut sessions: sessions = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub static mut socket_path: *const c_char = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub static mut start_time: timeval = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub static mut window_copy_mode: window_mode = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub static mut windows: windows = unsafe { mem::zeroed() };
+
+#[no_mangle]
+pub unsafe extern "C" fn alerts_check_session(_arg1: *mut session) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn alerts_reset_all() {}
+
+#[no_mangle]
+pub unsafe extern "C" fn args_free(_arg1: *mut args) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn args_first_value(
+    _arg1: *mut args,
+    _arg2: u_char,
+    _arg3: *mut *mut args_value,
+) -> *const c_char {
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn args_get(_arg1: *mut args, _arg2: u_char) -> *const c_char {
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn args_has(_arg1: *mut args, _arg2: u_char) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn args_next_value(_arg1: *mut *mut args_value) -> *const c_char {
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn args_parse(
+    _arg1: *const c_char,
+    _arg2: c_int,
+    _arg3: *mut *mut c_char,
+) -> *mut args {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn b64_ntop(
+    _arg1: *const c_char,
+    _arg2: usize,
+    _arg3: *mut c_char,
+    _arg4: usize,
+) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_find_client(
+    _arg1: *mut cmdq_item,
+    _arg2: *const c_char,
+    _arg3: c_int,
+) -> *mut client {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_find_from_client(
+    _arg1: *mut cmd_find_state,
+    _arg2: *mut client,
+    _arg3: c_int,
+) -> c_int {
+    -1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_find_from_pane(
+    _arg1: *mut cmd_find_state,
+    _arg2: *mut window_pane,
+    _arg3: c_int,
+) -> c_int {
+    -1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_find_from_winlink(
+    _arg1: *mut cmd_find_state,
+    _arg2: *mut winlink,
+    _arg3: c_int,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_find_target(
+    _arg1: *mut cmd_find_state,
+    _arg2: *mut cmdq_item,
+    _arg3: *const c_char,
+    _arg4: cmd_find_type,
+    _arg5: c_int,
+) -> c_int {
+    -1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_list_free(_arg1: *mut cmd_list) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_list_print(_arg1: *mut cmd_list) -> *mut c_char {
+    copy(EMPTY.as_ptr() as *const c_char)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmd_string_parse(
+    _arg1: *const c_char,
+    _arg2: *const c_char,
+    _arg3: u_int,
+    _arg4: *mut *mut c_char,
+) -> *mut cmd_list {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmdq_append(_arg1: *mut client, _arg2: *mut cmdq_item) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmdq_error(_arg1: *mut cmdq_item, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmdq_get_command(
+    _arg1: *mut cmd_list,
+    _arg2: *mut cmd_find_state,
+    _arg3: *mut mouse_event,
+    _arg4: c_int,
+) -> *mut cmdq_item {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cmdq_print(_arg1: *mut cmdq_item, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn colour_fromstring(_s: *const c_char) -> c_int {
+    -1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn colour_tostring(_arg1: c_int) -> *const c_char {
+    EMPTY.as_ptr() as *const c_char
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn environ_create() -> *mut environ {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn environ_find(
+    _arg1: *mut environ,
+    _arg2: *const c_char,
+) -> *mut environ_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn environ_free(_arg1: *mut environ) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn environ_set(
+    _arg1: *mut environ,
+    _arg2: *const c_char,
+    _arg3: *const c_char,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn environ_update(
+    _arg1: *mut options,
+    _arg2: *mut environ,
+    _arg3: *mut environ,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn event_add(_ev: *mut event, _timeout: *const timeval) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn event_del(_arg1: *mut event) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn event_once(
+    _arg1: c_int,
+    _arg2: c_short,
+    _arg3: Option<unsafe extern "C" fn(c_int, c_short, *mut c_void)>,
+    _arg4: *mut c_void,
+    _arg5: *const timeval,
+) -> c_int {
+    -1
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn event_set(
+    _arg1: *mut event,
+    _arg2: c_int,
+    _arg3: c_short,
+    _arg4: Option<unsafe extern "C" fn(c_int, c_short, *mut c_void)>,
+    _arg5: *mut c_void,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_add(
+    _arg1: *mut format_tree,
+    _arg2: *const c_char,
+    _arg3: *const c_char,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_create(
+    _arg1: *mut client,
+    _arg2: *mut cmdq_item,
+    _arg3: c_int,
+    _arg4: c_int,
+) -> *mut format_tree {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_defaults(
+    _arg1: *mut format_tree,
+    _arg2: *mut client,
+    _arg3: *mut session,
+    _arg4: *mut winlink,
+    _arg5: *mut window_pane,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_each(
+    _arg1: *mut format_tree,
+    _arg2: Option<unsafe extern "C" fn(*const c_char, *const c_char, *mut c_void)>,
+    _arg3: *mut c_void,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_expand(
+    _arg1: *mut format_tree,
+    arg2: *const c_char,
+) -> *mut c_char {
+    copy(arg2)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_free(_arg1: *mut format_tree) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_single(
+    _arg1: *mut cmdq_item,
+    arg2: *const c_char,
+    _arg3: *mut client,
+    _arg4: *mut session,
+    _arg5: *mut winlink,
+    _arg6: *mut window_pane,
+) -> *mut c_char {
+    copy(arg2)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn format_width(_arg1: *const c_char) -> u_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn grid_get_cell(
+    _arg1: *mut grid,
+    _arg2: u_int,
+    _arg3: u_int,
+    _arg4: *mut grid_cell,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn grid_peek_line(_arg1: *mut grid, _arg2: u_int) -> *const grid_line {
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_add(
+    _arg1: *const c_char,
+    _arg2: key_code,
+    _arg3: c_int,
+    _arg4: *mut cmd_list,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_first(_arg1: *mut key_table) -> *mut key_binding {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_first_table() -> *mut key_table {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_get(
+    _arg1: *mut key_table,
+    _arg2: key_code,
+) -> *mut key_binding {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_get_table(
+    _arg1: *const c_char,
+    _arg2: c_int,
+) -> *mut key_table {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_next(
+    _arg1: *mut key_table,
+    _arg2: *mut key_binding,
+) -> *mut key_binding {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_next_table(_arg1: *mut key_table) -> *mut key_table {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_remove(_arg1: *const c_char, _arg2: key_code) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_bindings_remove_table(_arg1: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_string_lookup_key(_arg1: key_code) -> *const c_char {
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn key_string_lookup_string(_arg1: *const c_char) -> key_code {
+    KEYC_UNKNOWN as key_code
+}
+
+// Layout cells are built and freed the way tmux does, since a cell and its pane point at each
+// other and the crate has to keep them in step.
+#[no_mangle]
+pub unsafe extern "C" fn layout_create_cell(arg1: *mut layout_cell) -> *mut layout_cell {
+    let lc = libc::calloc(1, mem::size_of::<layout_cell>()) as *mut layout_cell;
+    (*lc).type_ = layout_type_LAYOUT_WINDOWPANE;
+    (*lc).parent = arg1;
+    (*lc).cells.tqh_last = &mut (*lc).cells.tqh_first;
+    lc
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_dump(_arg1: *mut layout_cell) -> *mut c_char {
+    copy(EMPTY.as_ptr() as *const c_char)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_fix_offsets(_arg1: *mut layout_cell) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_fix_panes(_arg1: *mut window) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_free_cell(arg1: *mut layout_cell) {
+    if arg1.is_null() {
+        return;
+    }
+    if (*arg1).type_ == layout_type_LAYOUT_WINDOWPANE {
+        if !(*arg1).wp.is_null() {
+            (*(*arg1).wp).layout_cell = ptr::null_mut();
+        }
+    } else {
+        let mut child = (*arg1).cells.tqh_first;
+        while !child.is_null() {
+            let next = (*child).entry.tqe_next;
+            layout_free_cell(child);
+            child = next;
+        }
+    }
+    libc::free(arg1 as *mut c_void);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_make_leaf(arg1: *mut layout_cell, arg2: *mut window_pane) {
+    (*arg1).type_ = layout_type_LAYOUT_WINDOWPANE;
+    (*arg1).cells.tqh_first = ptr::null_mut();
+    (*arg1).cells.tqh_last = &mut (*arg1).cells.tqh_first;
+    (*arg2).layout_cell = arg1;
+    (*arg1).wp = arg2;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_make_node(arg1: *mut layout_cell, arg2: layout_type) {
+    (*arg1).type_ = arg2;
+    (*arg1).cells.tqh_first = ptr::null_mut();
+    (*arg1).cells.tqh_last = &mut (*arg1).cells.tqh_first;
+    if !(*arg1).wp.is_null() {
+        (*(*arg1).wp).layout_cell = ptr::null_mut();
+    }
+    (*arg1).wp = ptr::null_mut();
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_parse(_arg1: *mut window, _arg2: *const c_char) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_resize_pane_to(
+    _arg1: *mut window_pane,
+    _arg2: layout_type,
+    _arg3: u_int,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_set_size(
+    arg1: *mut layout_cell,
+    arg2: u_int,
+    arg3: u_int,
+    arg4: u_int,
+    arg5: u_int,
+) {
+    (*arg1).sx = arg2;
+    (*arg1).sy = arg3;
+    (*arg1).xoff = arg4;
+    (*arg1).yoff = arg5;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn layout_split_pane(
+    _arg1: *mut window_pane,
+    _arg2: layout_type,
+    _arg3: c_int,
+    _arg4: c_int,
+) -> *mut layout_cell {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_add(
+    _arg1: *mut mode_tree_data,
+    _arg2: *mut mode_tree_item,
+    _arg3: *mut c_void,
+    _arg4: u64,
+    _arg5: *const c_char,
+    _arg6: *const c_char,
+    _arg7: c_int,
+) -> *mut mode_tree_item {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_build(_arg1: *mut mode_tree_data) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_draw(_arg1: *mut mode_tree_data) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_free(_arg1: *mut mode_tree_data) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_get_current(_arg1: *mut mode_tree_data) -> *mut c_void {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_key(
+    _arg1: *mut mode_tree_data,
+    _arg2: *mut client,
+    _arg3: *mut key_code,
+    _arg4: *mut mouse_event,
+    _arg5: *mut u_int,
+    _arg6: *mut u_int,
+) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_resize(_arg1: *mut mode_tree_data, _arg2: u_int, _arg3: u_int) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_start(
+    _arg1: *mut window_pane,
+    _arg2: *mut args,
+    _arg3: mode_tree_build_cb,
+    _arg4: mode_tree_draw_cb,
+    _arg5: mode_tree_search_cb,
+    _arg6: *mut c_void,
+    _arg7: *mut *const c_char,
+    _arg8: u_int,
+    _arg9: *mut *mut screen,
+) -> *mut mode_tree_data {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mode_tree_zoom(_arg1: *mut mode_tree_data, _arg2: *mut args) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn notify_client(_arg1: *const c_char, _arg2: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn notify_session(_arg1: *const c_char, _arg2: *mut session) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn notify_window(_arg1: *const c_char, _arg2: *mut window) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_create(_arg1: *mut options) -> *mut options {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_first(_arg1: *mut options) -> *mut options_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_get(
+    _arg1: *mut options,
+    _arg2: *const c_char,
+) -> *mut options_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_get_number(
+    _arg1: *mut options,
+    _arg2: *const c_char,
+) -> c_longlong {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_get_only(
+    _arg1: *mut options,
+    _arg2: *const c_char,
+) -> *mut options_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_next(_arg1: *mut options_entry) -> *mut options_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_remove(_arg1: *mut options_entry) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_set_number(
+    _arg1: *mut options,
+    _arg2: *const c_char,
+    _arg3: c_longlong,
+) -> *mut options_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_set_style(
+    _arg1: *mut options,
+    _arg2: *const c_char,
+    _arg3: c_int,
+    _arg4: *const c_char,
+) -> *mut options_entry {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn options_tostring(
+    _arg1: *mut options_entry,
+    _arg2: c_int,
+    _arg3: c_int,
+) -> *mut c_char {
+    copy(EMPTY.as_ptr() as *const c_char)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn osdep_get_cwd(_arg1: c_int) -> *mut c_char {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn osdep_get_name(_arg1: c_int, _arg2: *mut c_char) -> *mut c_char {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn paste_get_top(_arg1: *mut *const c_char) -> *mut paste_buffer {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn paste_rename(
+    _arg1: *const c_char,
+    _arg2: *const c_char,
+    _arg3: *mut *mut c_char,
+) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn recalculate_sizes() {}
+
+#[no_mangle]
+pub unsafe extern "C" fn resize_window(_arg1: *mut window, _arg2: u_int, _arg3: u_int) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_check_marked() -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_check_unattached() {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_client_detach(_arg1: *mut client, _arg2: msgtype) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_client_get_cwd(
+    _arg1: *mut client,
+    _arg2: *mut session,
+) -> *const c_char {
+    ptr::null()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_client_handle_key(_arg1: *mut client, _arg2: key_code) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_client_set_key_table(_arg1: *mut client, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_client_unref(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_destroy_session(_arg1: *mut session) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_kill_pane(_arg1: *mut window_pane) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_redraw_client(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_redraw_session(_arg1: *mut session) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_redraw_window(_arg1: *mut window) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_redraw_window_borders(_arg1: *mut window) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_status_client(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_status_session(_arg1: *mut session) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_status_window(_arg1: *mut window) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn server_unzoom_window(_arg1: *mut window) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_add_ref(_arg1: *mut session, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_check_name(_arg1: *const c_char) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_create(
+    _arg1: *const c_char,
+    _arg2: *const c_char,
+    _arg3: *const c_char,
+    _arg4: *mut environ,
+    _arg5: *mut options,
+    _arg6: *mut termios,
+) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_destroy(_arg1: *mut session, _arg2: c_int, _arg3: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_find(_arg1: *const c_char) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_find_by_id(_arg1: u_int) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_remove_ref(_arg1: *mut session, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_select(_arg1: *mut session, _arg2: c_int) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn session_update_activity(_arg1: *mut session, _arg2: *mut timeval) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn sessions_RB_INSERT(
+    _arg1: *mut sessions,
+    _arg2: *mut session,
+) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sessions_RB_MINMAX(_arg1: *mut sessions, _arg2: c_int) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sessions_RB_NEXT(_arg1: *mut session) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sessions_RB_REMOVE(
+    _arg1: *mut sessions,
+    _arg2: *mut session,
+) -> *mut session {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn spawn_pane(
+    _arg1: *mut spawn_context,
+    _arg2: *mut *mut c_char,
+) -> *mut window_pane {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn spawn_window(
+    _arg1: *mut spawn_context,
+    _arg2: *mut *mut c_char,
+) -> *mut winlink {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn status_message_clear(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn status_message_set(_arg1: *mut client, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn status_prompt_clear(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn status_prompt_set(
+    _arg1: *mut client,
+    _arg2: *const c_char,
+    _arg3: *const c_char,
+    _arg4: prompt_input_cb,
+    _arg5: prompt_free_cb,
+    _arg6: *mut c_void,
+    _arg7: c_int,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn status_timer_start(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn tty_putcode_ptr2(
+    _arg1: *mut tty,
+    _arg2: tty_code_code,
+    _arg3: *const c_void,
+    _arg4: *const c_void,
+) {
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tty_set_title(_arg1: *mut tty, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn tty_term_has(_arg1: *mut tty_term, _arg2: tty_code_code) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn tty_update_client_offset(_arg1: *mut client) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn utf8_cstrwidth(arg1: *const c_char) -> u_int {
+    let string = std::ffi::CStr::from_ptr(arg1).to_string_lossy();
+    string.chars().map(char_width).sum()
+}
+
+// How many columns tmux gives a character, for the East Asian wide characters and the zero
+// width ones which the crate's width handling has to get right, rather than all of wcwidth.
+fn char_width(c: char) -> u_int {
+    match c as u32 {
+        0..=0x1f | 0x7f..=0x9f => 0,
+        0x300..=0x36f | 0x200b..=0x200f | 0xfe00..=0xfe0f => 0,
+        0x1100..=0x115f
+        | 0x2e80..=0x303e
+        | 0x3041..=0x33ff
+        | 0x3400..=0x4dbf
+        | 0x4e00..=0x9fff
+        | 0xa000..=0xa4cf
+        | 0xac00..=0xd7a3
+        | 0xf900..=0xfaff
+        | 0xfe30..=0xfe4f
+        | 0xff00..=0xff60
+        | 0xffe0..=0xffe6
+        | 0x1f300..=0x1f64f
+        | 0x1f900..=0x1f9ff
+        | 0x20000..=0x3fffd => 2,
+        _ => 1,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_count_panes(_arg1: *mut window) -> u_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_find_by_id(_arg1: u_int) -> *mut window {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_pane_find_by_id(_arg1: u_int) -> *mut window_pane {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_pane_index(_arg1: *mut window_pane, _arg2: *mut u_int) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_pane_reset_mode(_arg1: *mut window_pane) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_pane_set_mode(
+    _arg1: *mut window_pane,
+    _arg2: *const window_mode,
+    _arg3: *mut cmd_find_state,
+    _arg4: *mut args,
+) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_redraw_active_switch(_arg1: *mut window, _arg2: *mut window_pane) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_set_active_pane(
+    _arg1: *mut window,
+    _arg2: *mut window_pane,
+    _arg3: c_int,
+) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_set_name(_arg1: *mut window, _arg2: *const c_char) {}
+
+#[no_mangle]
+pub unsafe extern "C" fn window_unzoom(_arg1: *mut window) -> c_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn windows_RB_MINMAX(_arg1: *mut windows, _arg2: c_int) -> *mut window {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn windows_RB_NEXT(_arg1: *mut window) -> *mut window {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn winlink_count(_arg1: *mut winlinks) -> u_int {
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn winlinks_RB_MINMAX(_arg1: *mut winlinks, _arg2: c_int) -> *mut winlink {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn winlinks_RB_NEXT(_arg1: *mut winlink) -> *mut winlink {
+    ptr::null_mut()
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn xcalloc(arg1: usize, arg2: usize) -> *mut c_void {
+    libc::calloc(arg1, arg2)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn xstrdup(arg1: *const c_char) -> *mut c_char {
+    copy(arg1)
+}
@@ -9,6 +9,7 @@ pub use crate::tmux_bindings::{
     function_plugin,
     cmd_entry,
     notification_plugin,
+    multi_plugin,
 
     FORMAT_PLUGIN,
     FORMAT_FUNCTION_PLUGIN,
@@ -32,11 +33,34 @@ pub use crate::tmux_bindings::{
     cmd_retval_CMD_RETURN_WAIT,
     cmd_retval_CMD_RETURN_STOP,
 
+    GRID_ATTR_BRIGHT,
+    GRID_ATTR_DIM,
+    GRID_ATTR_UNDERSCORE,
+    GRID_ATTR_BLINK,
+    GRID_ATTR_REVERSE,
+    GRID_ATTR_HIDDEN,
+    GRID_ATTR_ITALICS,
+    GRID_ATTR_CHARSET,
+    GRID_ATTR_STRIKETHROUGH,
+    GRID_ATTR_UNDERSCORE_2,
+    GRID_ATTR_UNDERSCORE_3,
+    GRID_ATTR_UNDERSCORE_4,
+    GRID_ATTR_UNDERSCORE_5,
+    GRID_ATTR_ALL_UNDERSCORE,
+    COLOUR_FLAG_256,
+    COLOUR_FLAG_RGB,
+
+    args,
+    args_get,
+    args_has,
     cmd,
     cmd_entry__bindgen_ty_1,
     cmd_entry_flag,
     cmd_find_state,
     cmd_find_type,
+    cmd_find_type_CMD_FIND_PANE,
+    cmd_find_type_CMD_FIND_WINDOW,
+    cmd_find_type_CMD_FIND_SESSION,
     cmdq_item,
     cmdq_print,
     cmdq_error,
@@ -52,3 +76,71 @@ pub use crate::tmux_bindings::{
     window_mode,
     winlink,
 };
+
+pub mod alerts;
+mod arguments;
+pub mod borders;
+pub mod boxed;
+pub mod builtin;
+pub mod choose;
+mod clients;
+mod cmdq;
+mod colour;
+pub mod completion;
+pub mod control;
+mod copy_mode;
+pub mod events;
+pub mod filters;
+pub mod format;
+mod grid;
+mod history;
+mod ids;
+pub mod keys;
+mod layout;
+#[cfg(feature = "leak-debug")]
+pub mod leaks;
+pub mod maybe;
+pub mod modal;
+mod options;
+mod panes;
+pub mod process;
+pub mod prompt;
+pub mod refresh;
+mod search;
+pub mod server;
+mod sessions;
+pub mod shell;
+mod spawn;
+pub mod status;
+pub mod strings;
+mod styles;
+pub mod subcommands;
+mod symbols;
+pub mod target;
+pub mod time;
+mod timer;
+mod titles;
+mod ttys;
+pub mod window_status;
+mod windows;
+
+pub use self::arguments::{Args, Positionals, Values};
+pub use self::clients::{all as clients, Client, Clients, Redraw};
+pub use self::cmdq::{
+    queue_commands, CmdResult, CmdqItem, CommandError, IntoCmdRetval, WaitHandle, WaitToken,
+};
+pub use self::colour::Colour;
+pub use self::copy_mode::CopyMode;
+pub use self::history::{CellStyle, HistoryLine, HistoryLines};
+pub use self::ids::{PaneId, SessionId, WindowId};
+pub use self::layout::{Geometry, Layout, LayoutError, Orientation};
+pub use self::options::{OptionValue, Options, PluginOptions};
+pub use self::panes::{Pane, Respawn, SplitDirection, SplitSize};
+pub use self::search::{Match, Matches, Pattern, SearchDirection};
+pub use self::sessions::{RenameError, Session, SessionBuilder};
+pub use self::spawn::SpawnError;
+pub use self::status::display_message;
+pub use self::styles::{Attributes, Style, StyledSpan, StyledText};
+pub use self::symbols::{has_symbol, Symbol};
+pub use self::ttys::{SpecialChar, Termios, Tty, TtyTerm};
+pub use self::windows::Window;
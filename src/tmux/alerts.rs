@@ -0,0 +1,234 @@
+//! Window activity, bell and silence monitoring.
+//!
+//! tmux watches windows for activity, bells and silence according to their
+//! `monitor-activity`, `monitor-bell` and `monitor-silence` options, and fires the
+//! `alert-activity`, `alert-bell` and `alert-silence` hooks when it notices one. The methods
+//! here arm that monitoring on particular windows, [`AlertEvent`](./struct.AlertEvent.html)
+//! reads the hooks' notifications, and [`watch`](./fn.watch.html) registers
+//! a callback for a window's alerts, run by [`dispatch`](./fn.dispatch.html) from a
+//! notification plugin.
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::notification_plugin;
+//! use tmux_plugin::tmux::alerts::{self, Alert};
+//! use tmux_plugin::tmux::{display_message, Window};
+//! use std::ffi::CString;
+//! use std::time::Duration;
+//!
+//! // Watches a window for a build to finish, going by it falling silent.
+//! notification_plugin!(|notify_entry| {
+//!     if let Some(window) = unsafe { Window::from_raw((*notify_entry).window) } {
+//!         if window.name().to_bytes() == b"build" && !alerts::is_watched(window) {
+//!             window.monitor_silence(Some(Duration::from_secs(10)));
+//!             alerts::watch(window, |window, alert| {
+//!                 if alert == Alert::Silence {
+//!                     let message = CString::new("Build finished").unwrap();
+//!                     for client in tmux_plugin::tmux::clients() {
+//!                         display_message(client, &message);
+//!                     }
+//!                     alerts::unwatch(window);
+//!                 }
+//!             });
+//!         }
+//!     }
+//!     unsafe { alerts::dispatch(notify_entry) };
+//! });
+//! # fn main() {}
+//! ```
+
+use super::{Session, Window, WindowId};
+use crate::tmux_bindings::{
+    self, notify_entry, winlink, WINDOW_ACTIVITY, WINDOW_BELL, WINDOW_SILENCE,
+};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// A kind of alert tmux can raise for a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Alert {
+    /// There was output in the window.
+    Activity,
+    /// A bell rang in the window.
+    Bell,
+    /// There was no output in the window for the `monitor-silence` interval.
+    Silence,
+}
+
+impl Alert {
+    /// The alert for the name of an `alert-*` hook.
+    pub fn from_hook(name: &CStr) -> Option<Alert> {
+        match name.to_bytes() {
+            b"alert-activity" => Some(Alert::Activity),
+            b"alert-bell" => Some(Alert::Bell),
+            b"alert-silence" => Some(Alert::Silence),
+            _ => None,
+        }
+    }
+
+    fn window_flag(self) -> u32 {
+        match self {
+            Alert::Activity => WINDOW_ACTIVITY,
+            Alert::Bell => WINDOW_BELL,
+            Alert::Silence => WINDOW_SILENCE,
+        }
+    }
+}
+
+/// An `alert-activity`, `alert-bell` or `alert-silence` notification.
+///
+/// tmux raises an alert once for each session a window is linked into, so the same window
+/// may alert several times, once for each of its winlinks.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::alerts::AlertEvent;
+/// use std::process::Command;
+///
+/// // Forwards bells to desktop notifications.
+/// notification_plugin!(b"alert-bell\0", |notify_entry| {
+///     if let Some(event) = unsafe { AlertEvent::from_notify(notify_entry) } {
+///         let title = format!(
+///             "Bell in {}:{}",
+///             event.session.name().to_string_lossy(),
+///             event.index
+///         );
+///         let _ = Command::new("notify-send")
+///             .arg(title)
+///             .arg(event.window.name().to_string_lossy().as_ref())
+///             .spawn();
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlertEvent {
+    /// Which alert was raised.
+    pub alert: Alert,
+    /// The session of the winlink the alert was raised for.
+    pub session: Session,
+    /// The window the alert was raised in.
+    pub window: Window,
+    /// The index of the window in the session.
+    pub index: i32,
+    /// The winlink the alert was raised for, linking the window into the session.
+    pub winlink: *mut winlink,
+}
+
+impl AlertEvent {
+    /// Reads an `alert-*` notification, returning `None` for any other notification.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `notify_entry` passed to a notification callback.
+    pub unsafe fn from_notify(ne: *mut notify_entry) -> Option<AlertEvent> {
+        let alert = Alert::from_hook(CStr::from_ptr((*ne).name))?;
+        let wl = (*ne).fs.wl;
+        if wl.is_null() {
+            return None;
+        }
+        Some(AlertEvent {
+            alert,
+            session: Session::from_raw((*wl).session)?,
+            window: Window::from_raw((*wl).window)?,
+            index: (*wl).idx,
+            winlink: wl,
+        })
+    }
+}
+
+impl Window {
+    /// Turns the window's `monitor-activity` option on or off.
+    pub fn monitor_activity(&self, enabled: bool) {
+        self.set_monitor(b"monitor-activity\0", enabled.into());
+    }
+
+    /// Turns the window's `monitor-bell` option on or off.
+    pub fn monitor_bell(&self, enabled: bool) {
+        self.set_monitor(b"monitor-bell\0", enabled.into());
+    }
+
+    /// Sets the window's `monitor-silence` option, the number of seconds without output after
+    /// which the window is flagged, or turns it off.
+    pub fn monitor_silence(&self, interval: Option<Duration>) {
+        let seconds = interval.map_or(0, |interval| interval.as_secs().max(1));
+        self.set_monitor(b"monitor-silence\0", seconds as i64);
+        // tmux only rearms silence timers when the option is set.
+        unsafe { tmux_bindings::alerts_reset_all() }
+    }
+
+    /// Whether tmux has noticed an alert in the window which hasn't been cleared yet.
+    ///
+    /// Alerts are cleared when the window is next selected.
+    pub fn has_alert(&self, alert: Alert) -> bool {
+        unsafe { (*self.as_ptr()).flags as u32 & alert.window_flag() != 0 }
+    }
+
+    fn set_monitor(&self, name: &[u8], value: i64) {
+        unsafe {
+            tmux_bindings::options_set_number(
+                (*self.as_ptr()).options,
+                name.as_ptr() as *const c_char,
+                value,
+            );
+        }
+    }
+}
+
+type Callback = Rc<RefCell<dyn FnMut(Window, Alert)>>;
+
+thread_local! {
+    /// The callbacks registered for each window, by window ID.
+    static WATCHERS: RefCell<HashMap<WindowId, Vec<Callback>>> = RefCell::new(HashMap::new());
+}
+
+/// Registers a callback to be run by [`dispatch`](./fn.dispatch.html) for each of a window's
+/// alerts.
+///
+/// Only alerts the window is being monitored for are raised, so this is usually combined with
+/// one of the `monitor_*` methods on [`Window`](../struct.Window.html).
+pub fn watch<F: FnMut(Window, Alert) + 'static>(window: Window, callback: F) {
+    WATCHERS.with(|watchers| {
+        watchers
+            .borrow_mut()
+            .entry(window.id())
+            .or_default()
+            .push(Rc::new(RefCell::new(callback)));
+    });
+}
+
+/// Removes every callback registered for a window.
+pub fn unwatch(window: Window) {
+    WATCHERS.with(|watchers| watchers.borrow_mut().remove(&window.id()));
+}
+
+/// Whether any callbacks are registered for a window.
+pub fn is_watched(window: Window) -> bool {
+    WATCHERS.with(|watchers| watchers.borrow().contains_key(&window.id()))
+}
+
+/// Runs the callbacks registered for an `alert-*` notification's window. Other notifications
+/// are ignored, so this can be called with every notification a plugin receives.
+///
+/// # Safety
+///
+/// The pointer must point to the `notify_entry` passed to a notification callback.
+pub unsafe fn dispatch(ne: *mut notify_entry) {
+    let (alert, window) = match AlertEvent::from_notify(ne) {
+        Some(event) => (event.alert, event.window),
+        None => return,
+    };
+    // The callbacks are copied out before running them, so that they can watch and unwatch
+    // windows themselves.
+    let callbacks = WATCHERS.with(|watchers| watchers.borrow().get(&window.id()).cloned());
+    for callback in callbacks.unwrap_or_default() {
+        (callback.borrow_mut())(window, alert);
+    }
+}
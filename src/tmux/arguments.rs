@@ -0,0 +1,445 @@
+use super::CmdqItem;
+use crate::tmux_bindings::{self, args, args_value, cmd};
+use crate::{Error, Result};
+use std::ffi::{CStr, OsStr};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::os::raw::c_char;
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+use std::slice;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The flags and other arguments a command was run with, as tmux parsed them.
+///
+/// This is a thin wrapper around a [`*mut args`](./struct.args.html). tmux only parses the
+/// flags of commands whose arguments are described in braces in
+/// [`cmd_plugin!`](../macro.cmd_plugin.html). Every argument of any other command is
+/// positional.
+///
+/// ```rust
+/// use tmux_plugin::cmd_plugin;
+/// use tmux_plugin::tmux::Args;
+/// use std::ffi::{CStr, CString};
+///
+/// // Usage: greet [-l] [-g greeting] name ...
+/// cmd_plugin!("greet", "", {
+///     flags: "l",
+///     options: { g: "greeting" },
+///     arguments: "name ...",
+/// }, 1, -1, |cmd, _args, item| {
+///     let args = unsafe { Args::from_cmd(cmd) }.expect("tmux parsed the arguments");
+///     let greeting = args.value('g').map(CStr::to_string_lossy);
+///     let greeting = greeting.as_deref().unwrap_or("Hello");
+///     for name in args.positionals() {
+///         let mut message = format!("{}, {}!", greeting, name.to_string_lossy());
+///         if args.flag('l') {
+///             message = message.to_lowercase();
+///         }
+///         item.print(&CString::new(message).unwrap_or_default());
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Args(NonNull<args>);
+
+impl Args {
+    /// Wraps a raw arguments pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to live tmux `args`.
+    pub unsafe fn from_raw(ptr: *mut args) -> Option<Args> {
+        NonNull::new(ptr).map(Args)
+    }
+
+    /// The arguments of a command, returning `None` if it has none.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a live tmux `cmd`.
+    pub unsafe fn from_cmd(cmd: *mut cmd) -> Option<Args> {
+        Args::from_raw((*cmd).args)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut args {
+        self.0.as_ptr()
+    }
+
+    /// Whether the flag was given.
+    pub fn flag(&self, flag: char) -> bool {
+        match flag_byte(flag) {
+            Some(flag) => unsafe { tmux_bindings::args_has(self.as_ptr(), flag) != 0 },
+            None => false,
+        }
+    }
+
+    /// The value given with a flag, or the last one if it was given more than once, or `None`
+    /// if the flag wasn't given.
+    pub fn value(&self, flag: char) -> Option<&CStr> {
+        let flag = flag_byte(flag)?;
+        let value = unsafe { tmux_bindings::args_get(self.as_ptr(), flag) };
+        if value.is_null() {
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(value) })
+        }
+    }
+
+    /// The value given with a flag, parsed with [`FromStr`], or `None` if the flag wasn't
+    /// given.
+    ///
+    /// A value which can't be parsed is an
+    /// [`Error::InvalidArgument`](../enum.Error.html#variant.InvalidArgument), saying which
+    /// flag it was given with and why, so a command body can pass it on with `?`, for it to be
+    /// reported to the user:
+    ///
+    /// ```rust
+    /// use tmux_plugin::cmd_plugin;
+    /// use tmux_plugin::tmux::Args;
+    /// use std::ffi::CString;
+    /// use std::time::Duration;
+    ///
+    /// // Usage: countdown [-n count] [-i interval]
+    /// cmd_plugin!("countdown", "", {
+    ///     options: { n: "count", i: "interval" },
+    /// }, 0, 0, |cmd, _args, item| -> tmux_plugin::Result<()> {
+    ///     let args = unsafe { Args::from_cmd(cmd) }.expect("tmux parsed the arguments");
+    ///     let count = args.value_parsed::<u32>('n')?.unwrap_or(3);
+    ///     let interval = args.value_duration('i')?.unwrap_or(Duration::from_secs(1));
+    ///     let message = format!("counting down from {} every {:?}", count, interval);
+    ///     item.print(&CString::new(message)?);
+    ///     Ok(())
+    /// });
+    /// # fn main() {}
+    /// ```
+    ///
+    /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
+    pub fn value_parsed<T>(&self, flag: char) -> Result<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.parse_value(flag, parse_from_str)
+    }
+
+    /// The value given with a flag as a duration, or `None` if the flag wasn't given.
+    ///
+    /// The value is a number of seconds, such as `5` or `1.5`, or a number with a unit,
+    /// `ms`, `s`, `m` or `h`, such as `500ms` or `2m`. Anything else is an
+    /// [`Error::InvalidArgument`](../enum.Error.html#variant.InvalidArgument).
+    pub fn value_duration(&self, flag: char) -> Result<Option<Duration>> {
+        self.parse_value(flag, parse_duration)
+    }
+
+    /// The value given with a flag as a path, or `None` if the flag wasn't given.
+    ///
+    /// A relative path is taken as relative to the working directory of the item's client,
+    /// as builtin commands such as `source-file` do, rather than that of the server.
+    pub fn value_path(&self, flag: char, item: CmdqItem) -> Option<PathBuf> {
+        let path = Path::new(OsStr::from_bytes(self.value(flag)?.to_bytes()));
+        if path.is_absolute() {
+            return Some(path.to_owned());
+        }
+        let cwd = unsafe {
+            let client = item
+                .client()
+                .map_or(ptr::null_mut(), |client| client.as_ptr());
+            CStr::from_ptr(tmux_bindings::server_client_get_cwd(
+                client,
+                ptr::null_mut(),
+            ))
+        };
+        Some(Path::new(OsStr::from_bytes(cwd.to_bytes())).join(path))
+    }
+
+    /// Parses the value given with a flag, if it was given.
+    fn parse_value<T, F>(&self, flag: char, parse: F) -> Result<Option<T>>
+    where
+        F: FnOnce(&str) -> std::result::Result<T, String>,
+    {
+        self.value(flag)
+            .map(|value| parse_flag_value(flag, value, parse))
+            .transpose()
+    }
+
+    /// Every value given with a flag, in order.
+    pub fn values(&self, flag: char) -> Values<'_> {
+        Values {
+            args: self.as_ptr(),
+            flag: flag_byte(flag),
+            value: None,
+            lifetime: PhantomData,
+        }
+    }
+
+    /// The arguments after the flags.
+    pub fn positionals(&self) -> Positionals<'_> {
+        let argv = unsafe {
+            let args = self.as_ptr();
+            if (*args).argv.is_null() {
+                &[]
+            } else {
+                slice::from_raw_parts((*args).argv, (*args).argc as usize)
+            }
+        };
+        Positionals(argv.iter())
+    }
+
+    /// The argument after the flags at an index, counting from zero.
+    pub fn positional(&self, index: usize) -> Option<&CStr> {
+        self.positionals().nth(index)
+    }
+}
+
+/// Parses the value given with a flag, saying which flag it was given with if it can't be.
+fn parse_flag_value<T, F>(flag: char, value: &CStr, parse: F) -> Result<T>
+where
+    F: FnOnce(&str) -> std::result::Result<T, String>,
+{
+    let parsed = match value.to_str() {
+        Ok(text) => parse(text),
+        Err(_) => Err("not valid UTF-8".to_owned()),
+    };
+    parsed.map_err(|reason| Error::InvalidArgument {
+        flag,
+        value: value.to_string_lossy().into_owned(),
+        reason,
+    })
+}
+
+/// Parses a value with [`FromStr`](https://doc.rust-lang.org/std/str/trait.FromStr.html).
+fn parse_from_str<T>(value: &str) -> std::result::Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value.parse().map_err(|err: T::Err| err.to_string())
+}
+
+/// Parses a duration given as a number of seconds, or a number with a unit.
+fn parse_duration(value: &str) -> std::result::Result<Duration, String> {
+    let split = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number = match number.parse::<f64>() {
+        Ok(number) if number.is_finite() && number >= 0.0 => number,
+        _ => return Err("expected a duration, such as 30s or 500ms".to_owned()),
+    };
+    let seconds = match unit {
+        "ms" => 0.001,
+        "" | "s" => 1.0,
+        "m" => 60.0,
+        "h" => 60.0 * 60.0,
+        _ => return Err(format!("unknown unit `{}`, expected ms, s, m or h", unit)),
+    };
+    Duration::try_from_secs_f64(number * seconds).map_err(|err| err.to_string())
+}
+
+/// The byte tmux stores a flag as, if it can be one.
+fn flag_byte(flag: char) -> Option<u8> {
+    if flag.is_ascii_alphanumeric() {
+        Some(flag as u8)
+    } else {
+        None
+    }
+}
+
+/// An iterator over the arguments after a command's flags, returned by
+/// [`Args::positionals`](./struct.Args.html#method.positionals).
+#[derive(Debug, Clone)]
+pub struct Positionals<'a>(slice::Iter<'a, *mut c_char>);
+
+impl<'a> Iterator for Positionals<'a> {
+    type Item = &'a CStr;
+
+    fn next(&mut self) -> Option<&'a CStr> {
+        self.0.next().map(|arg| unsafe { CStr::from_ptr(*arg) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Positionals<'a> {
+    fn next_back(&mut self) -> Option<&'a CStr> {
+        self.0
+            .next_back()
+            .map(|arg| unsafe { CStr::from_ptr(*arg) })
+    }
+}
+
+impl<'a> ExactSizeIterator for Positionals<'a> {}
+
+/// An iterator over the values given with a flag, returned by
+/// [`Args::values`](./struct.Args.html#method.values).
+#[derive(Debug, Clone)]
+pub struct Values<'a> {
+    args: *mut args,
+    /// The flag, or `None` once there are no more values.
+    flag: Option<u8>,
+    /// The last value returned, or `None` before the first.
+    value: Option<*mut args_value>,
+    lifetime: PhantomData<&'a args>,
+}
+
+impl<'a> Iterator for Values<'a> {
+    type Item = &'a CStr;
+
+    fn next(&mut self) -> Option<&'a CStr> {
+        let flag = self.flag?;
+        let value = unsafe {
+            match &mut self.value {
+                Some(value) => tmux_bindings::args_next_value(value),
+                None => {
+                    let mut first = ptr::null_mut();
+                    let value = tmux_bindings::args_first_value(self.args, flag, &mut first);
+                    self.value = Some(first);
+                    value
+                }
+            }
+        };
+        if value.is_null() {
+            self.flag = None;
+            None
+        } else {
+            Some(unsafe { CStr::from_ptr(value) })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::fmt;
+
+    #[test]
+    fn durations_in_seconds() {
+        assert_eq!(parse_duration("5"), Ok(Duration::from_secs(5)));
+        assert_eq!(parse_duration("1.5"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("0"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration(".25"), Ok(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn durations_with_units() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1.5h"), Ok(Duration::from_secs(5400)));
+    }
+
+    #[test]
+    fn invalid_durations() {
+        let expected = Err("expected a duration, such as 30s or 500ms".to_owned());
+        for value in &["", "abc", "ms", "-1", "-1s", "5 s", "1.5.2", "nan", "inf"] {
+            assert_eq!(parse_duration(value), expected, "{:?}", value);
+        }
+        assert_eq!(
+            parse_duration("5x"),
+            Err("unknown unit `x`, expected ms, s, m or h".to_owned())
+        );
+        assert_eq!(
+            parse_duration("1e3"),
+            Err("unknown unit `e3`, expected ms, s, m or h".to_owned())
+        );
+        assert_eq!(
+            parse_duration("5sec"),
+            Err("unknown unit `sec`, expected ms, s, m or h".to_owned())
+        );
+    }
+
+    #[test]
+    fn durations_which_overflow() {
+        assert!(parse_duration("10000000000000000000").is_ok());
+        assert!(parse_duration("100000000000000000000").is_err());
+        assert!(parse_duration("100000000000000000000ms").is_ok());
+        assert!(parse_duration("10000000000000000000h").is_err());
+    }
+
+    #[test]
+    fn flags_are_ascii_alphanumeric() {
+        assert_eq!(flag_byte('t'), Some(b't'));
+        assert_eq!(flag_byte('F'), Some(b'F'));
+        assert_eq!(flag_byte('1'), Some(b'1'));
+        assert_eq!(flag_byte('-'), None);
+        assert_eq!(flag_byte('é'), None);
+        assert_eq!(flag_byte('\0'), None);
+    }
+
+    fn parse<T>(flag: char, value: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = CString::new(value).unwrap();
+        parse_flag_value(flag, &value, parse_from_str)
+    }
+
+    /// Checks that the error names the flag and the value it was given, whatever the reason.
+    fn assert_invalid<T: fmt::Debug>(result: Result<T>, expected_flag: char, expected_value: &str) {
+        match result {
+            Err(Error::InvalidArgument { flag, value, .. }) => {
+                assert_eq!((flag, &*value), (expected_flag, expected_value))
+            }
+            result => panic!("expected an invalid argument, got {:?}", result),
+        }
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse::<u32>('n', "42").unwrap(), 42);
+        assert_eq!(parse::<i8>('n', "-128").unwrap(), -128);
+        assert_eq!(parse::<f64>('r', "2.5").unwrap(), 2.5);
+        assert_invalid(parse::<u32>('n', "-1"), 'n', "-1");
+        assert_invalid(parse::<u32>('n', ""), 'n', "");
+        assert_invalid(parse::<u32>('n', " 1"), 'n', " 1");
+        assert_invalid(parse::<u32>('c', "lots"), 'c', "lots");
+        assert_invalid(parse::<f64>('r', "2.5.1"), 'r', "2.5.1");
+    }
+
+    #[test]
+    fn numbers_which_overflow() {
+        assert_eq!(parse::<u32>('n', "4294967295").unwrap(), u32::MAX);
+        assert_invalid(parse::<u32>('n', "4294967296"), 'n', "4294967296");
+        assert_eq!(parse::<i8>('n', "127").unwrap(), 127);
+        assert_invalid(parse::<i8>('n', "128"), 'n', "128");
+        assert_invalid(parse::<i8>('n', "-129"), 'n', "-129");
+    }
+
+    #[test]
+    fn invalid_values_name_the_flag() {
+        let value = CStr::from_bytes_with_nul(b"lots\0").unwrap();
+        let err = parse_flag_value('n', value, |_| Err::<u32, _>("too many".to_owned()));
+        assert_eq!(
+            err.unwrap_err().to_string(),
+            "invalid value for -n: lots (too many)"
+        );
+
+        let value = CStr::from_bytes_with_nul(b"5\xff\0").unwrap();
+        match parse_flag_value('i', value, parse_duration) {
+            Err(Error::InvalidArgument {
+                flag,
+                value,
+                reason,
+            }) => {
+                assert_eq!(flag, 'i');
+                assert_eq!(value, "5\u{fffd}");
+                assert_eq!(reason, "not valid UTF-8");
+            }
+            result => panic!("expected an invalid argument, got {:?}", result),
+        }
+        assert_invalid(parse::<u32>('i', "2m"), 'i', "2m");
+
+        let value = CStr::from_bytes_with_nul(b"2m\0").unwrap();
+        let parsed = parse_flag_value('i', value, parse_duration).unwrap();
+        assert_eq!(parsed, Duration::from_secs(120));
+    }
+}
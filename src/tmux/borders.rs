@@ -0,0 +1,126 @@
+//! Format variables for pane borders, as shown by the `pane-border-format` option.
+//!
+//! tmux expands `pane-border-format` once for each pane, each time it draws the borders, so
+//! variables meant for it are about one pane at a time, and are expanded far more often than
+//! those in the status line. [`variable`](./fn.variable.html) turns a closure taking the pane
+//! into one for the closure form of [`format_plugin!`](../../macro.format_plugin.html), keeping
+//! each pane's value for a while in a [`PaneCache`](./struct.PaneCache.html).
+//! [`border_pane`](./fn.border_pane.html) tells a variable which is also used elsewhere when
+//! it is being drawn in a border.
+
+use super::{Pane, PaneId};
+use crate::tmux_bindings::{format_tree, FORMAT_PANE, FORMAT_STATUS};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::c_int;
+use std::time::{Duration, Instant};
+
+/// The pane a format is being expanded for, if any.
+///
+/// # Safety
+///
+/// The pointer must point to the `format_tree` passed to a format callback.
+pub unsafe fn pane(ft: *mut format_tree) -> Option<Pane> {
+    Pane::from_raw((*ft).wp)
+}
+
+/// The pane whose border a format is being expanded for, or `None` if the format is being
+/// expanded for something else, such as the status line or `display-message`.
+///
+/// # Safety
+///
+/// The pointer must point to the `format_tree` passed to a format callback.
+pub unsafe fn border_pane(ft: *mut format_tree) -> Option<Pane> {
+    let pane = pane(ft)?;
+    let for_status = (*ft).flags & FORMAT_STATUS as c_int != 0;
+    if for_status && (*ft).tag == FORMAT_PANE | pane.id().0 {
+        Some(pane)
+    } else {
+        None
+    }
+}
+
+/// Values kept for each pane, by its ID, until they are older than a time to live.
+///
+/// Values for panes which have since been closed are dropped as other values are added.
+#[derive(Debug)]
+pub struct PaneCache<T> {
+    ttl: Duration,
+    values: HashMap<PaneId, (Instant, T)>,
+}
+
+impl<T: Clone> PaneCache<T> {
+    /// Creates an empty cache, whose values are worked out again once older than `ttl`.
+    pub fn new(ttl: Duration) -> PaneCache<T> {
+        PaneCache {
+            ttl,
+            values: HashMap::new(),
+        }
+    }
+
+    /// The pane's value, working it out with `compute` if there is none or it is out of date.
+    pub fn get_or_insert_with<F: FnOnce(Pane) -> T>(&mut self, pane: Pane, compute: F) -> T {
+        let ttl = self.ttl;
+        if let Some((updated, value)) = self.values.get(&pane.id()) {
+            if updated.elapsed() < ttl {
+                return value.clone();
+            }
+        }
+        self.values.retain(|&id, _| Pane::find_by_id(id).is_some());
+        let value = compute(pane);
+        self.values
+            .insert(pane.id(), (Instant::now(), value.clone()));
+        value
+    }
+
+    /// Forgets a pane's value, so that it is worked out again the next time it is needed.
+    pub fn invalidate(&mut self, pane: Pane) {
+        self.values.remove(&pane.id());
+    }
+
+    /// Forgets every pane's value.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+/// Turns a closure taking a pane into a format variable's closure, for
+/// [`format_plugin!`](../../macro.format_plugin.html), which keeps each pane's value for
+/// `ttl`.
+///
+/// The variable is empty when it isn't expanded for a pane.
+///
+/// For example, to show the git branch of each pane's working directory in its border, with
+/// `set -g pane-border-format '#{pane_index} #{pane_git_branch}'`:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::borders;
+/// use std::ffi::CString;
+/// use std::process::Command;
+/// use std::time::Duration;
+///
+/// format_plugin!("pane_git_branch", borders::variable(Duration::from_secs(5), |pane| {
+///     let output = pane.cwd().and_then(|cwd| {
+///         Command::new("git")
+///             .args(&["rev-parse", "--abbrev-ref", "HEAD"])
+///             .current_dir(cwd)
+///             .output()
+///             .ok()
+///     });
+///     let branch = output.map(|output| String::from_utf8_lossy(&output.stdout).into_owned());
+///     CString::new(branch.unwrap_or_default().trim()).unwrap_or_default()
+/// }));
+/// # fn main() {}
+/// ```
+pub fn variable<F, R>(ttl: Duration, mut compute: F) -> impl FnMut(*mut format_tree) -> CString
+where
+    F: FnMut(Pane) -> R,
+    R: AsRef<CStr>,
+{
+    let mut cache = PaneCache::new(ttl);
+    move |ft| match unsafe { pane(ft) } {
+        Some(pane) => cache.get_or_insert_with(pane, |pane| compute(pane).as_ref().to_owned()),
+        None => CString::default(),
+    }
+}
@@ -0,0 +1,73 @@
+//! Handing Rust values to tmux as `void *` data, with a destructor tmux calls to drop them.
+//!
+//! Many tmux structures carry a `void *` for whoever created them, such as the data of a
+//! status line prompt or a timer, along with a function tmux calls to free it. Rust values
+//! can't be freed with `free()`: they have to be dropped, by Rust. [`into_raw`](./fn.into_raw.html)
+//! boxes a value for tmux to hold on to, and [`drop_raw::<T>`](./fn.drop_raw.html) is the
+//! matching destructor, to register wherever tmux takes one.
+//!
+//! For example, the round trip tmux takes the data on, from being handed over to being freed:
+//!
+//! ```rust
+//! use tmux_plugin::tmux::boxed;
+//! use std::rc::Rc;
+//!
+//! let shared = Rc::new("prompt state");
+//! let data = boxed::into_raw(Rc::clone(&shared));
+//! let destructor = boxed::destructor::<Rc<&str>>();
+//!
+//! // tmux passes the data to callbacks while it holds on to it...
+//! assert_eq!(**unsafe { boxed::as_ref::<Rc<&str>>(data) }, "prompt state");
+//! assert_eq!(Rc::strong_count(&shared), 2);
+//!
+//! // ...and calls the destructor once it is done with it.
+//! unsafe { destructor(data) };
+//! assert_eq!(Rc::strong_count(&shared), 1);
+//! ```
+
+use std::os::raw::c_void;
+
+/// A destructor tmux calls with the data it was given, as [`drop_raw`](./fn.drop_raw.html) is.
+pub type Destructor = unsafe extern "C" fn(*mut c_void);
+
+/// Boxes a value to hand to tmux as `void *` data.
+///
+/// The value lives until it is dropped with [`drop_raw::<T>`](./fn.drop_raw.html), for the
+/// same `T`, so that should be registered as the data's destructor.
+pub fn into_raw<T: 'static>(value: T) -> *mut c_void {
+    #[cfg(feature = "leak-debug")]
+    super::leaks::box_created();
+    Box::into_raw(Box::new(value)) as *mut c_void
+}
+
+/// Borrows a value boxed by [`into_raw`](./fn.into_raw.html).
+///
+/// # Safety
+///
+/// The data must have come from `into_raw` with a value of type `T`, and not have been
+/// dropped. Nothing may borrow it mutably while this borrow lasts.
+pub unsafe fn as_ref<'a, T>(data: *mut c_void) -> &'a T {
+    &*(data as *const T)
+}
+
+/// Drops a value boxed by [`into_raw`](./fn.into_raw.html). This is the destructor to give
+/// tmux along with the data, in place of `free()`.
+///
+/// Null data is ignored, as `free()` ignores it.
+///
+/// # Safety
+///
+/// The data must be null, or have come from `into_raw` with a value of type `T` and not have
+/// been dropped already.
+pub unsafe extern "C" fn drop_raw<T>(data: *mut c_void) {
+    if !data.is_null() {
+        #[cfg(feature = "leak-debug")]
+        super::leaks::box_dropped();
+        drop(Box::from_raw(data as *mut T));
+    }
+}
+
+/// The destructor for data of type `T`, for structures which take one as a field.
+pub fn destructor<T>() -> Destructor {
+    drop_raw::<T>
+}
@@ -0,0 +1,201 @@
+//! The values of tmux's builtin format variables.
+//!
+//! Each function here works out the same value as the builtin variable of the same name, from
+//! the client, session, window and pane a [`format_tree`](../struct.format_tree.html) is being
+//! expanded for, so format plugins can build on them. They return `None` where tmux would leave
+//! the variable out, such as `pane_id` when there is no pane.
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::builtin;
+//! use std::ffi::CString;
+//!
+//! // The window's index and name, with the last part of the pane's directory.
+//! format_plugin!(b"window_label\0", |format_tree| {
+//!     let ft = unsafe { &*format_tree };
+//!     let directory = builtin::pane_current_path(ft)
+//!         .and_then(|path| path.file_name().map(|name| name.to_string_lossy().into_owned()))
+//!         .unwrap_or_default();
+//!     let label = format!(
+//!         "{}:{} ({})",
+//!         builtin::window_index(ft).unwrap_or_default(),
+//!         builtin::window_name(ft).unwrap_or_default(),
+//!         directory,
+//!     );
+//!     CString::new(label).unwrap_or_default()
+//! });
+//! # fn main() {}
+//! ```
+
+use super::{Client, Pane, Session, Window};
+use crate::tmux_bindings::{self, format_tree};
+use std::ffi::{CStr, OsStr};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+
+fn client(ft: &format_tree) -> Option<Client> {
+    unsafe { Client::from_raw(ft.c) }
+}
+
+fn session(ft: &format_tree) -> Option<Session> {
+    unsafe { Session::from_raw(ft.s) }
+}
+
+fn window(ft: &format_tree) -> Option<Window> {
+    unsafe { Window::from_raw(ft.w) }
+}
+
+fn pane(ft: &format_tree) -> Option<Pane> {
+    unsafe { Pane::from_raw(ft.wp) }
+}
+
+/// `client_name`: the client's name, usually the path of its tty.
+pub fn client_name(ft: &format_tree) -> Option<String> {
+    Some(client(ft)?.name().to_string_lossy().into_owned())
+}
+
+/// `client_width`: the width of the client's terminal.
+pub fn client_width(ft: &format_tree) -> Option<u32> {
+    Some(client(ft)?.size().0)
+}
+
+/// `client_height`: the height of the client's terminal.
+pub fn client_height(ft: &format_tree) -> Option<u32> {
+    Some(client(ft)?.size().1)
+}
+
+/// `session_name`: the session's name.
+pub fn session_name(ft: &format_tree) -> Option<String> {
+    Some(session(ft)?.name().to_string_lossy().into_owned())
+}
+
+/// `session_id`: the session's unique ID, such as `$1`.
+pub fn session_id(ft: &format_tree) -> Option<String> {
+    Some(session(ft)?.id().to_string())
+}
+
+/// `session_windows`: the number of windows in the session.
+pub fn session_windows(ft: &format_tree) -> Option<u32> {
+    let session = session(ft)?;
+    Some(unsafe { tmux_bindings::winlink_count(&mut (*session.as_ptr()).windows) })
+}
+
+/// `session_attached`: the number of clients attached to the session.
+pub fn session_attached(ft: &format_tree) -> Option<u32> {
+    Some(unsafe { (*session(ft)?.as_ptr()).attached })
+}
+
+/// `window_index`: the index of the window in the session.
+pub fn window_index(ft: &format_tree) -> Option<i32> {
+    if ft.wl.is_null() {
+        return None;
+    }
+    Some(unsafe { (*ft.wl).idx })
+}
+
+/// `window_name`: the window's name.
+pub fn window_name(ft: &format_tree) -> Option<String> {
+    Some(window(ft)?.name().to_string_lossy().into_owned())
+}
+
+/// `window_id`: the window's unique ID, such as `@1`.
+pub fn window_id(ft: &format_tree) -> Option<String> {
+    Some(window(ft)?.id().to_string())
+}
+
+/// `window_active`: whether the window is the session's current window.
+pub fn window_active(ft: &format_tree) -> Option<bool> {
+    let session = session(ft)?;
+    if ft.wl.is_null() {
+        return None;
+    }
+    Some(unsafe { (*session.as_ptr()).curw } == ft.wl)
+}
+
+/// `window_width`: the window's width.
+pub fn window_width(ft: &format_tree) -> Option<u32> {
+    Some(window(ft)?.size().0)
+}
+
+/// `window_height`: the window's height.
+pub fn window_height(ft: &format_tree) -> Option<u32> {
+    Some(window(ft)?.size().1)
+}
+
+/// `window_panes`: the number of panes in the window.
+pub fn window_panes(ft: &format_tree) -> Option<u32> {
+    Some(unsafe { tmux_bindings::window_count_panes(window(ft)?.as_ptr()) })
+}
+
+/// `pane_id`: the pane's unique ID, such as `%1`.
+pub fn pane_id(ft: &format_tree) -> Option<String> {
+    Some(pane(ft)?.id().to_string())
+}
+
+/// `pane_index`: the index of the pane in its window, counting from `pane-base-index`.
+pub fn pane_index(ft: &format_tree) -> Option<u32> {
+    let mut index = 0;
+    if unsafe { tmux_bindings::window_pane_index(pane(ft)?.as_ptr(), &mut index) } != 0 {
+        return None;
+    }
+    Some(index)
+}
+
+/// `pane_active`: whether the pane is its window's active pane.
+pub fn pane_active(ft: &format_tree) -> Option<bool> {
+    let pane = pane(ft)?;
+    Some(pane.window().active_pane() == Some(pane))
+}
+
+/// `pane_width`: the pane's width.
+pub fn pane_width(ft: &format_tree) -> Option<u32> {
+    Some(pane(ft)?.size().0)
+}
+
+/// `pane_height`: the pane's height.
+pub fn pane_height(ft: &format_tree) -> Option<u32> {
+    Some(pane(ft)?.size().1)
+}
+
+/// `pane_pid`: the process ID of the pane's first process, usually a shell.
+pub fn pane_pid(ft: &format_tree) -> Option<tmux_bindings::pid_t> {
+    Some(pane(ft)?.pid())
+}
+
+/// `pane_current_command`: the name of the process in the foreground of the pane's terminal.
+pub fn pane_current_command(ft: &format_tree) -> Option<String> {
+    let wp = pane(ft)?.as_ptr();
+    unsafe {
+        if (*wp).fd == -1 {
+            return None;
+        }
+        let name = tmux_bindings::osdep_get_name((*wp).fd, (*wp).tty.as_mut_ptr());
+        if name.is_null() {
+            return None;
+        }
+        let command = CStr::from_ptr(name).to_string_lossy().into_owned();
+        super::strings::free(name);
+        Some(command)
+    }
+}
+
+/// `pane_current_path`: the working directory of the process in the foreground of the pane's
+/// terminal.
+pub fn pane_current_path(ft: &format_tree) -> Option<PathBuf> {
+    let wp = pane(ft)?.as_ptr();
+    unsafe {
+        if (*wp).fd == -1 {
+            return None;
+        }
+        // This points to a static buffer in tmux, so isn't freed.
+        let cwd = tmux_bindings::osdep_get_cwd((*wp).fd);
+        if cwd.is_null() {
+            return None;
+        }
+        Some(PathBuf::from(OsStr::from_bytes(
+            CStr::from_ptr(cwd).to_bytes(),
+        )))
+    }
+}
@@ -0,0 +1,306 @@
+//! Choosing from a plugin's own entries in tmux's tree mode, the interface of `choose-tree`.
+//!
+//! Build a [`Chooser`](./struct.Chooser.html) from [`Choice`](./struct.Choice.html)s, each with
+//! a name, a format for the text shown beside it, and a closure to run when it is chosen, and
+//! [show](./struct.Chooser.html#method.show) it in a pane. The usual keys work as they do in
+//! `choose-tree`: moving, searching, expanding and collapsing, zooming, and `q` to quit.
+
+use super::{Client, Pane};
+use crate::tmux_bindings::{
+    self, args, cmd_find_state, key_code, mode_tree_data, mode_tree_item, mouse_event, screen,
+    screen_write_ctx, session, window_mode, window_mode_entry, winlink, PANE_REDRAW,
+};
+use std::cell::RefCell;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+use std::rc::Rc;
+
+type Callback = Rc<RefCell<Box<dyn FnMut(Option<Client>)>>>;
+
+/// An entry in a [`Chooser`](./struct.Chooser.html).
+pub struct Choice {
+    name: CString,
+    format: CString,
+    on_select: Option<Callback>,
+    children: Vec<Choice>,
+}
+
+impl Choice {
+    /// Creates an entry which runs the closure when it is chosen.
+    ///
+    /// The format is expanded for the pane the chooser is shown in, each time the entries are
+    /// drawn, and shown beside the name. The closure is passed the client which chose the
+    /// entry, if it was chosen from a client rather than by `send-keys`.
+    pub fn new<F>(name: &CStr, format: &CStr, on_select: F) -> Choice
+    where
+        F: FnMut(Option<Client>) + 'static,
+    {
+        Choice {
+            name: name.to_owned(),
+            format: format.to_owned(),
+            on_select: Some(Rc::new(RefCell::new(Box::new(on_select)))),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates an entry which only groups the entries added to it with
+    /// [`child`](#method.child), and can't be chosen itself.
+    pub fn group(name: &CStr, format: &CStr) -> Choice {
+        Choice {
+            name: name.to_owned(),
+            format: format.to_owned(),
+            on_select: None,
+            children: Vec::new(),
+        }
+    }
+
+    /// Adds an entry beneath this one in the tree.
+    pub fn child(mut self, child: Choice) -> Choice {
+        self.children.push(child);
+        self
+    }
+}
+
+/// A tree of entries to choose from, shown in tmux's tree mode.
+///
+/// For example, to pick a project to open:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use tmux_plugin::tmux::choose::{Choice, Chooser};
+/// use std::ffi::CString;
+///
+/// cmd_plugin!("choose-project", "", "", 0, 0, |_cmd, _args, item| {
+///     let pane = item
+///         .target_client()
+///         .and_then(|client| client.session())
+///         .and_then(|session| session.current_window())
+///         .and_then(|window| window.active_pane());
+///     if let Some(pane) = pane {
+///         let mut chooser = Chooser::new();
+///         for project in &["website", "tmux", "dotfiles"] {
+///             let name = CString::new(*project).unwrap();
+///             let path = CString::new(format!("~/src/{}", project)).unwrap();
+///             let message = path.clone();
+///             let choice = Choice::new(&name, &path, move |client| {
+///                 if let Some(client) = client {
+///                     tmux::display_message(client, &message);
+///                 }
+///             });
+///             chooser = chooser.choice(choice);
+///         }
+///         chooser.show(pane);
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Default)]
+pub struct Chooser {
+    choices: Vec<Choice>,
+}
+
+impl Chooser {
+    /// Creates a chooser with no entries.
+    pub fn new() -> Chooser {
+        Chooser::default()
+    }
+
+    /// Adds an entry at the top level of the tree.
+    pub fn choice(mut self, choice: Choice) -> Chooser {
+        self.choices.push(choice);
+        self
+    }
+
+    /// Shows the chooser in a pane, replacing any chooser it is already showing.
+    ///
+    /// The pane returns to what it was showing once an entry is chosen or the chooser is
+    /// closed, and the entries and their closures are dropped then.
+    pub fn show(self, pane: Pane) {
+        let wp = pane.as_ptr();
+        let mut argv = [crate::cstr!("choose").as_ptr(), crate::cstr!("-N").as_ptr()];
+        unsafe {
+            let wme = (*wp).modes.tqh_first;
+            if !wme.is_null() && ptr::eq((*wme).mode, &MODE.0) {
+                tmux_bindings::window_pane_reset_mode(wp);
+            }
+            // The preview is empty, so start with it hidden.
+            let args = tmux_bindings::args_parse(
+                crate::cstr!("N").as_ptr(),
+                argv.len() as c_int,
+                argv.as_mut_ptr() as *mut *mut c_char,
+            );
+            PENDING.with(|pending| *pending.borrow_mut() = Some(self.choices));
+            tmux_bindings::window_pane_set_mode(wp, &MODE.0, ptr::null_mut(), args);
+            PENDING.with(|pending| pending.borrow_mut().take());
+            if !args.is_null() {
+                tmux_bindings::args_free(args);
+            }
+        }
+    }
+}
+
+thread_local! {
+    /// The entries of the chooser being shown, until the mode's `init` takes them.
+    static PENDING: RefCell<Option<Vec<Choice>>> = const { RefCell::new(None) };
+}
+
+struct Mode(window_mode);
+
+// The mode's name is never written to.
+unsafe impl Sync for Mode {}
+
+static MODE: Mode = Mode(window_mode {
+    name: crate::cstr!("tree-mode").as_ptr(),
+    default_format: ptr::null(),
+    init: Some(init),
+    free: Some(free),
+    resize: Some(resize),
+    key: Some(key),
+    key_table: None,
+    command: None,
+    formats: None,
+});
+
+struct Data {
+    tree: *mut mode_tree_data,
+    pane: *mut tmux_bindings::window_pane,
+    sort_list: [*const c_char; 1],
+    choices: Vec<Choice>,
+}
+
+unsafe extern "C" fn init(
+    wme: *mut window_mode_entry,
+    _fs: *mut cmd_find_state,
+    args: *mut args,
+) -> *mut screen {
+    let choices = PENDING
+        .with(|pending| pending.borrow_mut().take())
+        .unwrap_or_default();
+    let data = Box::into_raw(Box::new(Data {
+        tree: ptr::null_mut(),
+        pane: (*wme).wp,
+        sort_list: [crate::cstr!("order").as_ptr()],
+        choices,
+    }));
+    (*wme).data = data as *mut c_void;
+
+    let mut s = ptr::null_mut();
+    let tree = tmux_bindings::mode_tree_start(
+        (*wme).wp,
+        args,
+        Some(build),
+        Some(draw),
+        Some(search),
+        data as *mut c_void,
+        (*data).sort_list.as_mut_ptr(),
+        1,
+        &mut s,
+    );
+    (*data).tree = tree;
+    tmux_bindings::mode_tree_zoom(tree, args);
+    tmux_bindings::mode_tree_build(tree);
+    tmux_bindings::mode_tree_draw(tree);
+    s
+}
+
+unsafe extern "C" fn free(wme: *mut window_mode_entry) {
+    let data = Box::from_raw((*wme).data as *mut Data);
+    tmux_bindings::mode_tree_free(data.tree);
+}
+
+unsafe extern "C" fn resize(wme: *mut window_mode_entry, sx: u32, sy: u32) {
+    let data = &*((*wme).data as *const Data);
+    tmux_bindings::mode_tree_resize(data.tree, sx, sy);
+}
+
+unsafe extern "C" fn key(
+    wme: *mut window_mode_entry,
+    c: *mut tmux_bindings::client,
+    _s: *mut session,
+    _wl: *mut winlink,
+    mut key: key_code,
+    m: *mut mouse_event,
+) {
+    let wp = (*wme).wp;
+    let tree = (*((*wme).data as *const Data)).tree;
+    let mut finished =
+        tmux_bindings::mode_tree_key(tree, c, &mut key, m, ptr::null_mut(), ptr::null_mut()) != 0;
+
+    let mut chosen = None;
+    if key == b'\r' as key_code {
+        let choice = tmux_bindings::mode_tree_get_current(tree) as *const Choice;
+        if let Some(callback) = choice.as_ref().and_then(|choice| choice.on_select.as_ref()) {
+            // Leaving the mode drops the entries, so hold on to the closure while it runs.
+            chosen = Some(Rc::clone(callback));
+            finished = true;
+        }
+    }
+
+    if finished {
+        tmux_bindings::window_pane_reset_mode(wp);
+    } else {
+        tmux_bindings::mode_tree_draw(tree);
+        (*wp).flags |= PANE_REDRAW as c_int;
+    }
+    if let Some(callback) = chosen {
+        if let Ok(mut callback) = callback.try_borrow_mut() {
+            callback(Client::from_raw(c));
+        }
+    }
+}
+
+unsafe extern "C" fn build(
+    modedata: *mut c_void,
+    _sort_type: u32,
+    _tag: *mut u64,
+    _filter: *const c_char,
+) {
+    let data = &*(modedata as *const Data);
+    for choice in &data.choices {
+        add(data, ptr::null_mut(), choice);
+    }
+}
+
+unsafe fn add(data: &Data, parent: *mut mode_tree_item, choice: &Choice) {
+    let text = tmux_bindings::format_single(
+        ptr::null_mut(),
+        choice.format.as_ptr(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+        ptr::null_mut(),
+        data.pane,
+    );
+    let item = tmux_bindings::mode_tree_add(
+        data.tree,
+        parent,
+        choice as *const Choice as *mut c_void,
+        choice as *const Choice as u64,
+        choice.name.as_ptr(),
+        text,
+        -1,
+    );
+    super::strings::free(text);
+    for child in &choice.children {
+        add(data, item, child);
+    }
+}
+
+unsafe extern "C" fn draw(
+    _modedata: *mut c_void,
+    _itemdata: *mut c_void,
+    _ctx: *mut screen_write_ctx,
+    _sx: u32,
+    _sy: u32,
+) {
+}
+
+unsafe extern "C" fn search(
+    _modedata: *mut c_void,
+    itemdata: *mut c_void,
+    ss: *const c_char,
+) -> c_int {
+    let choice = &*(itemdata as *const Choice);
+    (!libc::strstr(choice.name.as_ptr(), ss).is_null()) as c_int
+}
@@ -0,0 +1,209 @@
+use super::Session;
+use crate::tmux_bindings::{
+    self, client, msgtype_MSG_DETACH, tty_code_code_TTYC_MS, CLIENT_REDRAWBORDERS,
+    CLIENT_REDRAWSTATUS, CLIENT_REDRAWSTATUSALWAYS, CLIENT_REDRAWWINDOW, TTY_STARTED,
+    WINLINK_ALERTFLAGS,
+};
+use bitflags::bitflags;
+use std::ffi::CStr;
+use std::ptr::{self, NonNull};
+
+/// A client attached to the tmux server.
+///
+/// This is a thin wrapper around a [`*mut client`](./struct.client.html). It is only valid
+/// for as long as tmux keeps the underlying client alive, which in practice means for the
+/// duration of the plugin callback it was obtained in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Client(NonNull<client>);
+
+impl Client {
+    /// Wraps a raw client pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `client`.
+    pub unsafe fn from_raw(ptr: *mut client) -> Option<Client> {
+        NonNull::new(ptr).map(Client)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut client {
+        self.0.as_ptr()
+    }
+
+    /// The client's name, usually the path of its tty.
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr((*self.as_ptr()).name) }
+    }
+
+    /// The process ID of the tmux client process.
+    pub fn pid(&self) -> tmux_bindings::pid_t {
+        unsafe { (*self.as_ptr()).pid }
+    }
+
+    /// The width and height of the client's terminal, in cells.
+    pub fn size(&self) -> (u32, u32) {
+        unsafe { ((*self.as_ptr()).tty.sx, (*self.as_ptr()).tty.sy) }
+    }
+
+    /// The session the client is attached to, if any.
+    pub fn session(&self) -> Option<Session> {
+        unsafe { Session::from_raw((*self.as_ptr()).session) }
+    }
+
+    /// Switches the client to another session, the same way the `switch-client` command does.
+    pub fn switch_to(&self, session: Session) {
+        let c = self.as_ptr();
+        let s = session.as_ptr();
+        unsafe {
+            if !(*c).session.is_null() && (*c).session != s {
+                (*c).last_session = (*c).session;
+            }
+            (*c).session = s;
+            tmux_bindings::environ_update((*s).options, (*c).environ, (*s).environ);
+
+            tmux_bindings::server_client_set_key_table(c, ptr::null());
+            tmux_bindings::tty_update_client_offset(c);
+            tmux_bindings::status_timer_start(c);
+            tmux_bindings::notify_client(crate::cstr!("client-session-changed").as_ptr(), c);
+            tmux_bindings::session_update_activity(s, ptr::null_mut());
+            tmux_bindings::gettimeofday(&mut (*s).last_attached_time, ptr::null_mut());
+
+            tmux_bindings::recalculate_sizes();
+            tmux_bindings::server_check_unattached();
+            tmux_bindings::server_redraw_client(c);
+            if !(*s).curw.is_null() {
+                (*(*s).curw).flags &= !(WINLINK_ALERTFLAGS as i32);
+            }
+            tmux_bindings::alerts_check_session(s);
+        }
+    }
+
+    /// Marks parts of the client's screen to be redrawn the next time tmux returns to its
+    /// event loop.
+    ///
+    /// For example:
+    ///
+    /// ```rust
+    /// use tmux_plugin::{cmd_plugin, tmux};
+    /// use tmux_plugin::tmux::Redraw;
+    ///
+    /// // Redraws the borders and status line of the invoking client, as after changing a style.
+    /// cmd_plugin!(b"redraw-chrome\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+    ///     if let Some(client) = item.client() {
+    ///         client.redraw(Redraw::BORDERS | Redraw::STATUS);
+    ///     }
+    ///     tmux::CmdResult::Normal
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn redraw(&self, parts: Redraw) {
+        unsafe { (*self.as_ptr()).flags |= parts.bits() as i32 }
+    }
+
+    /// Detaches the client from its session, the same way the `detach-client` command does.
+    pub fn detach(&self) {
+        unsafe { tmux_bindings::server_client_detach(self.as_ptr(), msgtype_MSG_DETACH) }
+    }
+
+    /// Sets the clipboard of the client's terminal with an OSC 52 escape sequence, the same
+    /// way tmux does when copying in copy mode.
+    ///
+    /// This does nothing and returns `false` if the `set-clipboard` option is `off`, or the
+    /// terminal doesn't declare support for setting the clipboard with the `Ms` capability.
+    pub fn set_clipboard(&self, data: &[u8]) -> bool {
+        let tty = self.tty();
+        if !tty.term().is_some_and(|term| term.has_clipboard()) {
+            return false;
+        }
+        unsafe {
+            if (*tty.as_ptr()).flags as u32 & TTY_STARTED == 0
+                || tmux_bindings::options_get_number(
+                    tmux_bindings::global_options,
+                    crate::cstr!("set-clipboard").as_ptr(),
+                ) == 0
+            {
+                return false;
+            }
+            let mut encoded = vec![0 as libc::c_char; 4 * data.len().div_ceil(3) + 1];
+            if tmux_bindings::b64_ntop(
+                data.as_ptr() as *const libc::c_char,
+                data.len(),
+                encoded.as_mut_ptr(),
+                encoded.len(),
+            ) == -1
+            {
+                return false;
+            }
+            tmux_bindings::tty_putcode_ptr2(
+                tty.as_ptr(),
+                tty_code_code_TTYC_MS,
+                crate::cstr!("").as_ptr() as *const libc::c_void,
+                encoded.as_ptr() as *const libc::c_void,
+            );
+        }
+        true
+    }
+}
+
+bitflags! {
+    /// Parts of a client's screen to [redraw](./struct.Client.html#method.redraw).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Redraw: u32 {
+        /// The panes of the current window.
+        const WINDOW = CLIENT_REDRAWWINDOW;
+        /// The status line, if its contents have changed.
+        const STATUS = CLIENT_REDRAWSTATUS;
+        /// The status line, even if its contents haven't changed.
+        const STATUS_ALWAYS = CLIENT_REDRAWSTATUSALWAYS;
+        /// The pane borders.
+        const BORDERS = CLIENT_REDRAWBORDERS;
+        /// Everything, the same as tmux's `refresh-client`.
+        const ALL = Self::WINDOW.bits()
+            | Self::STATUS.bits()
+            | Self::STATUS_ALWAYS.bits()
+            | Self::BORDERS.bits();
+    }
+}
+
+/// Iterates over every client connected to the server.
+///
+/// This includes clients which aren't attached to a session, such as those running a single
+/// command, so check [`Client::session`](./struct.Client.html#method.session) when only
+/// attached clients matter.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use std::ffi::CString;
+///
+/// // Lists the attached clients and their sizes.
+/// cmd_plugin!(b"list-sizes\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+///     for client in tmux::clients().filter(|client| client.session().is_some()) {
+///         let (width, height) = client.size();
+///         let line = format!("{}: {}x{}", client.name().to_string_lossy(), width, height);
+///         item.print(&CString::new(line).unwrap());
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+pub fn all() -> Clients {
+    Clients(unsafe { tmux_bindings::clients.tqh_first })
+}
+
+/// An iterator over the clients connected to the server, returned by
+/// [`clients`](./fn.clients.html).
+#[derive(Debug, Clone)]
+pub struct Clients(*mut client);
+
+impl Iterator for Clients {
+    type Item = Client;
+
+    fn next(&mut self) -> Option<Client> {
+        let client = unsafe { Client::from_raw(self.0)? };
+        self.0 = unsafe { (*self.0).entry.tqe_next };
+        Some(client)
+    }
+}
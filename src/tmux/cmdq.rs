@@ -0,0 +1,473 @@
+use super::events::{ExternalSource, Refresh};
+use super::Client;
+use crate::tmux_bindings::{self, client, cmd_list, cmd_retval, cmdq_item, cmdq_list};
+use crate::Error;
+use std::cell::Cell;
+use std::error;
+use std::ffi::{CStr, CString, NulError};
+use std::fmt;
+use std::io;
+use std::os::raw::c_char;
+use std::ptr::{self, NonNull};
+use std::rc::Rc;
+
+/// An item on a tmux command queue.
+///
+/// This is what tmux passes to a command while it executes. It is used to print output,
+/// report errors, and to find the client on whose behalf the command is running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CmdqItem(NonNull<cmdq_item>);
+
+impl CmdqItem {
+    /// Wraps a raw command queue item pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `cmdq_item`.
+    pub unsafe fn from_raw(ptr: *mut cmdq_item) -> Option<CmdqItem> {
+        NonNull::new(ptr).map(CmdqItem)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut cmdq_item {
+        self.0.as_ptr()
+    }
+
+    /// The client that this item was queued for, if any.
+    ///
+    /// Commands run from a configuration file or from a hook have no client of their own.
+    /// Use [`target_client`](#method.target_client) to find the best client to act upon instead.
+    pub fn client(&self) -> Option<Client> {
+        unsafe { Client::from_raw((*self.as_ptr()).client) }
+    }
+
+    /// Finds the most appropriate client for this item, the same way builtin commands
+    /// without a `-t` flag do.
+    pub fn target_client(&self) -> Option<Client> {
+        unsafe {
+            Client::from_raw(tmux_bindings::cmd_find_client(
+                self.as_ptr(),
+                ptr::null(),
+                1,
+            ))
+        }
+    }
+
+    /// Prints a line of output, either to the client's terminal or to a view mode window.
+    pub fn print(&self, message: &CStr) {
+        unsafe {
+            tmux_bindings::cmdq_print(self.as_ptr(), crate::cstr!("%s").as_ptr(), message.as_ptr())
+        }
+    }
+
+    /// Reports an error for this item.
+    ///
+    /// The command should then return `CMD_RETURN_ERROR`. A command can instead return
+    /// [`CmdResult::Error`](./enum.CmdResult.html), which reports the error itself.
+    pub fn error(&self, message: &CStr) {
+        unsafe {
+            tmux_bindings::cmdq_error(self.as_ptr(), crate::cstr!("%s").as_ptr(), message.as_ptr())
+        }
+    }
+
+    /// A token for a command to return as [`CmdResult::Wait`](./enum.CmdResult.html), so that
+    /// tmux runs nothing after it in the queue until the item is [resumed](#method.resume).
+    pub fn wait(self) -> WaitToken {
+        WaitToken {
+            item: self,
+            waiting: Rc::new(Cell::new(false)),
+            handled: false,
+        }
+    }
+
+    /// Lets tmux carry on with the queue, once a command which returned
+    /// [`CmdResult::Wait`](./enum.CmdResult.html) has finished.
+    ///
+    /// tmux continues after the callback this is called from returns. It only has an effect once
+    /// the command has returned, so it can't be called from the command's body itself.
+    ///
+    /// # Safety
+    ///
+    /// The item's command must have returned `CmdResult::Wait`, and the item not have been
+    /// resumed since, as tmux frees the item once it carries on.
+    pub unsafe fn resume(self) {
+        (*self.as_ptr()).flags &= !(tmux_bindings::CMDQ_WAITING as i32);
+    }
+}
+
+/// Queues tmux commands, given as they would be typed at the command prompt, to run after
+/// the current callback returns.
+///
+/// The commands are run in order, the same way as those run by a hook, for the client if one
+/// is given, and otherwise on the server's own queue. This is safe from any callback, including
+/// notifications, as nothing runs until tmux gets back to its queue. Only the parsing of the
+/// commands is reported here: errors from running them are shown to the client, if any.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::{self, Client};
+///
+/// // Greets clients when they attach.
+/// notification_plugin!("client-attached", |notify_entry| {
+///     let client = unsafe { Client::from_raw((*notify_entry).client) };
+///     let greeting = "display-message 'Welcome back'";
+///     tmux::queue_commands(greeting, client).expect("The command is valid");
+/// });
+/// # fn main() {}
+/// ```
+pub fn queue_commands(commands: &str, client: Option<Client>) -> Result<(), CommandError> {
+    let list = match parse_commands(commands)? {
+        Some(list) => list.as_ptr(),
+        None => return Ok(()),
+    };
+    unsafe {
+        let item = tmux_bindings::cmdq_get_command(list, ptr::null_mut(), ptr::null_mut(), 0);
+        tmux_bindings::cmdq_append(client.map_or(ptr::null_mut(), Client::as_ptr), item);
+        tmux_bindings::cmd_list_free(list);
+    }
+    Ok(())
+}
+
+/// Parses commands, returning `None` if there are none, only whitespace or comments.
+pub(crate) fn parse_commands(commands: &str) -> Result<Option<NonNull<cmd_list>>, CommandError> {
+    let commands = CString::new(commands).map_err(CommandError::Nul)?;
+    unsafe {
+        let mut cause: *mut c_char = ptr::null_mut();
+        let list = tmux_bindings::cmd_string_parse(commands.as_ptr(), ptr::null(), 0, &mut cause);
+        if list.is_null() && !cause.is_null() {
+            let message = CStr::from_ptr(cause).to_string_lossy().into_owned();
+            super::strings::free(cause);
+            return Err(CommandError::Parse(message));
+        }
+        Ok(NonNull::new(list))
+    }
+}
+
+/// The error returned when commands cannot be queued.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    /// The commands contain a null byte.
+    Nul(NulError),
+    /// The commands could not be parsed, for this reason.
+    Parse(String),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Nul(err) => err.fmt(f),
+            CommandError::Parse(cause) => f.write_str(cause),
+        }
+    }
+}
+
+impl error::Error for CommandError {}
+
+/// How a command finished, for tmux to carry on with the rest of its queue.
+///
+/// For example, a command which runs `make` and shows its output once it has finished, without
+/// tmux running anything else from the same queue before then:
+///
+/// ```rust
+/// use tmux_plugin::cmd_plugin;
+/// use tmux_plugin::tmux::CmdResult;
+/// use std::process::Command;
+/// use std::thread;
+///
+/// cmd_plugin!("make", "", "", 0, 0, |_cmd, _args, item| {
+///     let (token, handle) = match item.wait().handle() {
+///         Ok(waiting) => waiting,
+///         Err(err) => return CmdResult::Error(format!("can't wait for make: {}", err)),
+///     };
+///     thread::spawn(move || match Command::new("make").output() {
+///         Ok(output) if output.status.success() => handle.complete(),
+///         Ok(output) => handle.fail(String::from_utf8_lossy(&output.stderr)),
+///         Err(err) => handle.fail(format!("can't run make: {}", err)),
+///     });
+///     CmdResult::Wait(token)
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, PartialEq, Eq)]
+pub enum CmdResult {
+    /// The command succeeded.
+    Normal,
+    /// The command failed, with this message for the user.
+    Error(String),
+    /// The command is still running, and will finish later, with a
+    /// [`WaitHandle`](./struct.WaitHandle.html) or by
+    /// [resuming](./struct.CmdqItem.html#method.resume) its item.
+    Wait(WaitToken),
+    /// The command succeeded, and nothing after it in the queue should run.
+    Stop,
+}
+
+/// What a command returns as [`CmdResult::Wait`](./enum.CmdResult.html), from
+/// [`CmdqItem::wait`](./struct.CmdqItem.html#method.wait).
+#[derive(Debug, PartialEq, Eq)]
+pub struct WaitToken {
+    item: CmdqItem,
+    /// Set once the command has returned the token, so that the item is only resumed if tmux
+    /// is waiting for it, and cleared once it has been resumed or has gone.
+    waiting: Rc<Cell<bool>>,
+    /// Whether a handle has been made for the token, as only one can resume the item.
+    handled: bool,
+}
+
+impl WaitToken {
+    /// The item which is waiting.
+    pub fn item(&self) -> CmdqItem {
+        self.item
+    }
+
+    /// A handle for finishing the command from any thread, once it has returned the token,
+    /// which is given back along with it.
+    ///
+    /// This must be called on tmux's thread, from the command's body. A token has only one
+    /// handle, which can be cloned, so asking for another fails.
+    pub fn handle(mut self) -> io::Result<(WaitToken, WaitHandle)> {
+        if self.handled {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "the command already has a wait handle",
+            ));
+        }
+        let mut waiter = unsafe { Waiter::new(self.item, self.waiting.clone()) };
+        let source = ExternalSource::new(move |finish| {
+            waiter.finish(finish);
+            Refresh::Nothing
+        })?;
+        self.handled = true;
+        Ok((self, WaitHandle { source }))
+    }
+}
+
+/// Finishes a command which returned [`CmdResult::Wait`](./enum.CmdResult.html), from any
+/// thread, made with [`WaitToken::handle`](./struct.WaitToken.html#method.handle).
+///
+/// The handle can be cloned and sent to other threads. Whichever clone finishes the command
+/// first decides how it finished, and is reported on tmux's event loop, which then carries on
+/// with the rest of the queue. If every clone is dropped without finishing the command, it
+/// fails. A command which didn't return the token isn't waiting, and isn't affected.
+///
+/// See [`CmdResult`](./enum.CmdResult.html) for an example.
+#[derive(Clone)]
+pub struct WaitHandle {
+    source: ExternalSource<Finish>,
+}
+
+impl WaitHandle {
+    /// Finishes the command successfully.
+    pub fn complete(&self) {
+        self.source.push(Finish::Complete);
+    }
+
+    /// Fails the command, with this message for the user.
+    pub fn fail<M: Into<String>>(&self, message: M) {
+        self.source.push(Finish::Fail(message.into()));
+    }
+}
+
+impl fmt::Debug for WaitHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitHandle").finish_non_exhaustive()
+    }
+}
+
+enum Finish {
+    Complete,
+    Fail(String),
+}
+
+/// Resumes a waiting item, on tmux's thread, the first time one of its handles finishes it.
+struct Waiter {
+    item: CmdqItem,
+    /// The queue the item is on, which outlives it: tmux's global queue, or the queue of the
+    /// item's client, which the waiter holds a reference to.
+    queue: *mut cmdq_list,
+    client: *mut client,
+    /// tmux numbers each command it runs, so an item allocated in the same place after this
+    /// one is freed isn't mistaken for it.
+    number: u32,
+    waiting: Rc<Cell<bool>>,
+}
+
+impl Waiter {
+    /// # Safety
+    ///
+    /// The item must be live, as it is while its command runs.
+    unsafe fn new(item: CmdqItem, waiting: Rc<Cell<bool>>) -> Waiter {
+        let raw = item.as_ptr();
+        let client = (*raw).client;
+        if !client.is_null() {
+            (*client).references += 1;
+        }
+        Waiter {
+            item,
+            queue: (*raw).queue,
+            client,
+            number: (*raw).number,
+            waiting,
+        }
+    }
+
+    fn finish(&mut self, finish: Finish) {
+        if !self.waiting.replace(false) || !self.is_queued() {
+            return;
+        }
+        if let Finish::Fail(message) = finish {
+            report(self.item, message);
+        }
+        unsafe { self.item.resume() };
+    }
+
+    /// Whether the item is still on its queue, rather than having been freed by tmux, such as
+    /// when its client went away.
+    fn is_queued(&self) -> bool {
+        unsafe {
+            let mut entry = (*self.queue).tqh_first;
+            while !entry.is_null() {
+                if entry == self.item.as_ptr() {
+                    return (*entry).number == self.number;
+                }
+                entry = (*entry).entry.tqe_next;
+            }
+        }
+        false
+    }
+}
+
+impl Drop for Waiter {
+    fn drop(&mut self) {
+        self.finish(Finish::Fail(
+            "the command stopped without finishing".to_owned(),
+        ));
+        if !self.client.is_null() {
+            unsafe { tmux_bindings::server_client_unref(self.client) };
+        }
+    }
+}
+
+/// What a command body can return: a [`CmdResult`](./enum.CmdResult.html), a
+/// [`cmd_retval`](./type.cmd_retval.html), `()` for `CmdResult::Normal`, or a `Result` of any
+/// of them.
+///
+/// When a body returns an error, it is reported with [`CmdqItem::error`], along with the
+/// errors which caused it, such as `can't load config: file.toml: permission denied`, and the
+/// command returns `CMD_RETURN_ERROR`. With the `anyhow` feature, bodies can return
+/// `anyhow::Result` too:
+///
+/// ```rust
+/// # #[cfg(feature = "anyhow")]
+/// # mod example {
+/// use anyhow::Context;
+/// use tmux_plugin::cmd_plugin;
+///
+/// cmd_plugin!(b"load-theme\0", b"\0", b"file\0", 1, 1, |_cmd, args| -> anyhow::Result<()> {
+///     let path = args.last().context("no file")?.to_str()?;
+///     let _theme = std::fs::read_to_string(path).context("can't load theme")?;
+///     Ok(())
+/// });
+/// # }
+/// # fn main() {}
+/// ```
+///
+/// [`CmdqItem::error`]: ./struct.CmdqItem.html#method.error
+pub trait IntoCmdRetval {
+    /// Converts the value to a `cmd_retval`, reporting any error for the item.
+    fn into_cmd_retval(self, item: CmdqItem) -> cmd_retval;
+}
+
+impl IntoCmdRetval for cmd_retval {
+    fn into_cmd_retval(self, _item: CmdqItem) -> cmd_retval {
+        self
+    }
+}
+
+impl IntoCmdRetval for CmdResult {
+    fn into_cmd_retval(self, item: CmdqItem) -> cmd_retval {
+        match self {
+            CmdResult::Normal => tmux_bindings::cmd_retval_CMD_RETURN_NORMAL,
+            CmdResult::Error(message) => report(item, message),
+            CmdResult::Wait(token) if token.item != item => report(
+                item,
+                "command returned the wait token of another command, so it can't wait".to_owned(),
+            ),
+            CmdResult::Wait(token) => {
+                token.waiting.set(true);
+                tmux_bindings::cmd_retval_CMD_RETURN_WAIT
+            }
+            CmdResult::Stop => tmux_bindings::cmd_retval_CMD_RETURN_STOP,
+        }
+    }
+}
+
+impl IntoCmdRetval for () {
+    fn into_cmd_retval(self, _item: CmdqItem) -> cmd_retval {
+        tmux_bindings::cmd_retval_CMD_RETURN_NORMAL
+    }
+}
+
+impl<T: IntoCmdRetval> IntoCmdRetval for Result<T, Error> {
+    fn into_cmd_retval(self, item: CmdqItem) -> cmd_retval {
+        match self {
+            Ok(value) => value.into_cmd_retval(item),
+            Err(error) => {
+                let mut message = error.to_string();
+                let mut source = error::Error::source(&error);
+                while let Some(error) = source {
+                    message.push_str(": ");
+                    message.push_str(&error.to_string());
+                    source = error.source();
+                }
+                report(item, message)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl<T: IntoCmdRetval> IntoCmdRetval for Result<T, anyhow::Error> {
+    fn into_cmd_retval(self, item: CmdqItem) -> cmd_retval {
+        match self {
+            Ok(value) => value.into_cmd_retval(item),
+            // The alternate form includes each cause, separated by colons.
+            Err(error) => report(item, format!("{:#}", error)),
+        }
+    }
+}
+
+fn report(item: CmdqItem, message: String) -> cmd_retval {
+    let message = CString::new(message.replace('\0', "")).expect("Null bytes were removed");
+    item.error(&message);
+    tmux_bindings::cmd_retval_CMD_RETURN_ERROR
+}
+
+#[cfg(all(test, feature = "stub"))]
+mod tests {
+    use super::*;
+    use crate::tmux_bindings::cmdq_item;
+    use std::mem;
+
+    #[test]
+    fn waiting_with_another_items_token_is_an_error() {
+        let mut items: [cmdq_item; 2] = unsafe { mem::zeroed() };
+        let (first, second) = items.split_at_mut(1);
+        let first = unsafe { CmdqItem::from_raw(&mut first[0]) }.unwrap();
+        let second = unsafe { CmdqItem::from_raw(&mut second[0]) }.unwrap();
+
+        let token = first.wait();
+        let waiting = token.waiting.clone();
+        let retval = CmdResult::Wait(token).into_cmd_retval(second);
+        assert_eq!(retval, tmux_bindings::cmd_retval_CMD_RETURN_ERROR);
+        assert!(!waiting.get());
+
+        let token = first.wait();
+        let waiting = token.waiting.clone();
+        let retval = CmdResult::Wait(token).into_cmd_retval(first);
+        assert_eq!(retval, tmux_bindings::cmd_retval_CMD_RETURN_WAIT);
+        assert!(waiting.get());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::tmux_bindings;
+use std::ffi::{CStr, CString};
+use std::fmt;
+
+/// A colour, as tmux represents them in styles and options.
+///
+/// This is a thin wrapper around tmux's own representation: the 8 standard colours and their
+/// bright variants, the 256 colour palette, RGB colours, and `default`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Colour(pub i32);
+
+impl Colour {
+    /// The terminal's default colour.
+    pub const DEFAULT: Colour = Colour(8);
+
+    /// Parses a colour the same way tmux does, such as `red`, `brightblue`, `colour235` or
+    /// `#ff8700`.
+    ///
+    /// Returns `None` if tmux doesn't recognise the colour.
+    pub fn parse(name: &str) -> Option<Colour> {
+        let name = CString::new(name).ok()?;
+        match unsafe { tmux_bindings::colour_fromstring(name.as_ptr()) } {
+            -1 => None,
+            colour => Some(Colour(colour)),
+        }
+    }
+}
+
+impl Default for Colour {
+    fn default() -> Colour {
+        Colour::DEFAULT
+    }
+}
+
+impl fmt::Display for Colour {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = unsafe { CStr::from_ptr(tmux_bindings::colour_tostring(self.0)) };
+        f.write_str(&name.to_string_lossy())
+    }
+}
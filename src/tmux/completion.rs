@@ -0,0 +1,170 @@
+//! Describing a plugin's commands for shell completion.
+//!
+//! Every plugin exports a `plugin_completions` function, which returns a description of the
+//! commands it adds as JSON, for completion scripts to be generated from:
+//!
+//! ```c
+//! const char *plugin_completions(void);
+//! ```
+//!
+//! The description lists each command's name, aliases, usage, flags, and the rest of its
+//! arguments. Flags which take a value have a hint of what the value is, taken from the usage
+//! string, such as `target-pane` for `[-t target-pane]`. For instance:
+//!
+//! ```json
+//! {"commands":[{"name":"shout","aliases":["sh"],"usage":"[-n] [-p prefix] message",
+//!   "flags":[{"flag":"n"},{"flag":"p","value":"prefix"}],"arguments":"message"}]}
+//! ```
+//!
+//! Plugins without commands return an empty list.
+
+use crate::tmux_bindings::{cmd_entry, plugin, CMD_PLUGIN, MULTI_PLUGIN};
+use std::ffi::CStr;
+use std::fmt::Write;
+use std::os::raw::{c_char, c_int};
+use std::slice;
+
+/// Describes the commands a plugin adds, as JSON.
+///
+/// # Safety
+///
+/// The plugin must be one defined by this crate's macros, or be as valid as one.
+pub unsafe fn describe(plugin: &plugin) -> String {
+    let mut entries = Vec::new();
+    collect(plugin, &mut entries);
+
+    // Commands with several aliases are added once for each alias after the first, as
+    // commands named after them, all running the same function.
+    let mut commands: Vec<(&cmd_entry, Vec<String>)> = Vec::new();
+    for entry in entries {
+        let alias = string(entry.alias);
+        match commands
+            .iter_mut()
+            .find(|(command, _)| command.exec.map(|f| f as usize) == entry.exec.map(|f| f as usize))
+        {
+            Some((_, aliases)) => aliases.push(alias),
+            None if alias.is_empty() => commands.push((entry, Vec::new())),
+            None => commands.push((entry, vec![alias])),
+        }
+    }
+
+    let mut json = String::from("{\"commands\":[");
+    for (i, (entry, aliases)) in commands.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        describe_command(&mut json, entry, aliases);
+    }
+    json.push_str("]}");
+    json
+}
+
+unsafe fn collect<'a>(plugin: &'a plugin, entries: &mut Vec<&'a cmd_entry>) {
+    match plugin.type_ {
+        t if t == CMD_PLUGIN as c_int => entries.push(&plugin.__bindgen_anon_1.cmd),
+        t if t == MULTI_PLUGIN as c_int => {
+            let multi = plugin.__bindgen_anon_1.multi;
+            if !multi.plugins.is_null() {
+                for plugin in slice::from_raw_parts(multi.plugins, multi.length as usize) {
+                    collect(plugin, entries);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+unsafe fn describe_command(json: &mut String, entry: &cmd_entry, aliases: &[String]) {
+    let usage = string(entry.usage);
+    let hints = value_hints(&usage);
+    json.push_str("{\"name\":");
+    push_string(json, &string(entry.name));
+    json.push_str(",\"aliases\":[");
+    for (i, alias) in aliases.iter().enumerate() {
+        if i > 0 {
+            json.push(',');
+        }
+        push_string(json, alias);
+    }
+    json.push_str("],\"usage\":");
+    push_string(json, &usage);
+    json.push_str(",\"flags\":[");
+    let template = string(entry.args.template).into_bytes();
+    let mut first = true;
+    for (i, &flag) in template.iter().enumerate() {
+        if flag == b':' {
+            continue;
+        }
+        if !first {
+            json.push(',');
+        }
+        first = false;
+        json.push_str("{\"flag\":");
+        push_string(json, &(flag as char).to_string());
+        if template.get(i + 1) == Some(&b':') {
+            json.push_str(",\"value\":");
+            let hint = hints
+                .iter()
+                .find(|(f, _)| *f == flag as char)
+                .map_or("value", |(_, hint)| hint.as_str());
+            push_string(json, hint);
+        }
+        json.push('}');
+    }
+    json.push_str("],\"arguments\":");
+    push_string(json, arguments(&usage));
+    json.push('}');
+}
+
+/// The hints for flags' values in a usage string, from parts such as `[-t target-pane]`.
+fn value_hints(usage: &str) -> Vec<(char, String)> {
+    usage
+        .split('[')
+        .filter_map(|part| {
+            let part = part.split(']').next()?.strip_prefix('-')?;
+            let mut words = part.split_whitespace();
+            let mut flag = words.next()?.chars();
+            let hint = words.next()?;
+            match (flag.next(), flag.next()) {
+                (Some(flag), None) => Some((flag, hint.to_owned())),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// The arguments in a usage string after its flags.
+fn arguments(usage: &str) -> &str {
+    let mut rest = usage.trim_start();
+    while rest.starts_with("[-") {
+        match rest.find(']') {
+            Some(end) => rest = rest[end + 1..].trim_start(),
+            None => break,
+        }
+    }
+    rest
+}
+
+unsafe fn string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+fn push_string(json: &mut String, string: &str) {
+    json.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
@@ -0,0 +1,522 @@
+//! Control sockets, for driving a plugin from outside tmux.
+//!
+//! A [`ControlSocket`](./struct.ControlSocket.html) listens on a Unix socket next to the tmux
+//! server's own, and runs a handler for each line sent to it. The first word of the line picks
+//! the handler, which is passed the rest of the line, and whatever it returns is sent back as a
+//! single line: `ok`, followed by the handler's reply if it has one, or `error` followed by the
+//! error. Everything runs on tmux's event loop, so handlers can use tmux freely.
+//!
+//! Lines are limited to 64KiB: a longer one is answered with `error line too long`, and the
+//! connection is closed. So is a connection which leaves as much in replies unread.
+//!
+//! This lets scripts and other programs query a plugin, or push data into it, without running
+//! `tmux` commands. For example:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::control::{self, ControlSocket};
+//! use std::cell::RefCell;
+//! use std::ffi::CString;
+//!
+//! thread_local! {
+//!     static MESSAGE: RefCell<String> = RefCell::new(String::new());
+//! }
+//!
+//! // Shows a message set from outside tmux, with something like:
+//! //     echo "set Build passed" | socat - UNIX-CONNECT:/tmp/tmux-1000/default.message
+//! format_plugin!(b"message\0", |_format_tree| {
+//!     if !control::is_listening("message") {
+//!         let _ = ControlSocket::new("message")
+//!             .handle("set", |text| {
+//!                 MESSAGE.with(|message| *message.borrow_mut() = text.to_owned());
+//!                 Ok(String::new())
+//!             })
+//!             .handle("get", |_| Ok(MESSAGE.with(|message| message.borrow().clone())))
+//!             .listen();
+//!     }
+//!     MESSAGE.with(|message| CString::new(message.borrow().as_str()).unwrap_or_default())
+//! });
+//! # fn main() {}
+//! ```
+
+use super::server;
+use crate::tmux_bindings::{self, event, EV_PERSIST, EV_READ, EV_WRITE};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::raw::{c_int, c_short, c_void};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::ptr;
+use std::rc::{Rc, Weak};
+
+type Handler = Rc<RefCell<dyn FnMut(&str) -> Result<String, String>>>;
+
+/// The most a connection can send without ending the line, or leave unread in replies, before
+/// it is closed.
+const MAX_BUFFERED: usize = 64 * 1024;
+
+thread_local! {
+    /// The sockets which are listening, by name.
+    static SOCKETS: RefCell<HashMap<String, Rc<RefCell<Listener>>>> =
+        RefCell::new(HashMap::new());
+}
+
+/// The path of a plugin's control socket: the tmux server's socket path, followed by a dot
+/// and the name, such as `/tmp/tmux-1000/default.weather`.
+pub fn path(name: &str) -> PathBuf {
+    let mut path = OsString::from(server::socket_path());
+    path.push(".");
+    path.push(name);
+    PathBuf::from(path)
+}
+
+/// A control socket, set up with its handlers before it starts listening.
+pub struct ControlSocket {
+    name: String,
+    handlers: HashMap<String, Handler>,
+}
+
+impl ControlSocket {
+    /// A control socket with no handlers, which will listen at [`path(name)`](./fn.path.html).
+    pub fn new(name: &str) -> ControlSocket {
+        ControlSocket {
+            name: name.to_owned(),
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Adds a handler for lines starting with `command`. The handler is passed the rest of the
+    /// line, with the space after the command removed, and returns the reply or an error.
+    ///
+    /// Replies should fit on one line. Any newlines in them are sent as spaces.
+    pub fn handle<F>(mut self, command: &str, handler: F) -> ControlSocket
+    where
+        F: FnMut(&str) -> Result<String, String> + 'static,
+    {
+        self.handlers
+            .insert(command.to_owned(), Rc::new(RefCell::new(handler)));
+        self
+    }
+
+    /// Starts listening, returning the socket's path.
+    ///
+    /// Any socket left behind at the path is replaced, as is a socket of the same name this
+    /// plugin is already listening on. The socket is removed again when it is
+    /// [closed](./fn.close.html).
+    pub fn listen(self) -> io::Result<PathBuf> {
+        let ControlSocket { name, handlers } = self;
+        close(&name);
+        let path = path(&name);
+        match fs::remove_file(&path) {
+            Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+            _ => {}
+        }
+        let socket = UnixListener::bind(&path)?;
+        socket.set_nonblocking(true)?;
+        let listener = Rc::new(RefCell::new(Listener {
+            path: path.clone(),
+            socket,
+            event: Box::new(unsafe { mem::zeroed() }),
+            this: Box::new(Weak::new()),
+            handlers,
+            connections: HashMap::new(),
+        }));
+        {
+            let mut state = listener.borrow_mut();
+            *state.this = Rc::downgrade(&listener);
+            let fd = state.socket.as_raw_fd();
+            let arg = &*state.this as *const Weak<RefCell<Listener>> as *mut c_void;
+            unsafe {
+                tmux_bindings::event_set(
+                    &mut *state.event,
+                    fd,
+                    (EV_READ | EV_PERSIST) as c_short,
+                    Some(accept),
+                    arg,
+                );
+                tmux_bindings::event_add(&mut *state.event, ptr::null());
+            }
+        }
+        SOCKETS.with(|sockets| sockets.borrow_mut().insert(name, listener));
+        Ok(path)
+    }
+}
+
+/// Whether this plugin is listening on a control socket with this name.
+pub fn is_listening(name: &str) -> bool {
+    SOCKETS.with(|sockets| sockets.borrow().contains_key(name))
+}
+
+/// Stops listening on a control socket and removes it, disconnecting anything connected to
+/// it. Returns whether there was such a socket.
+pub fn close(name: &str) -> bool {
+    let listener = SOCKETS.with(|sockets| sockets.borrow_mut().remove(name));
+    listener.is_some()
+}
+
+struct Listener {
+    path: PathBuf,
+    socket: UnixListener,
+    event: Box<event>,
+    /// What the listener's event is passed, pointing back at the listener.
+    this: Box<Weak<RefCell<Listener>>>,
+    handlers: HashMap<String, Handler>,
+    connections: HashMap<RawFd, Connection>,
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        unsafe { tmux_bindings::event_del(&mut *self.event) };
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+struct Connection {
+    stream: UnixStream,
+    /// What has been read after the last complete line.
+    incoming: Vec<u8>,
+    /// Replies the other end hasn't been ready to read yet.
+    outgoing: Vec<u8>,
+    /// Whether the other end has stopped sending, so that the connection is closed once its
+    /// replies are written.
+    closing: bool,
+    read_event: Box<event>,
+    write_event: Box<event>,
+    /// What the connection's events are passed, pointing back at its listener.
+    listener: Box<Weak<RefCell<Listener>>>,
+}
+
+impl Connection {
+    /// A connection on a non-blocking stream, reading from it once tmux's event loop runs.
+    unsafe fn new(stream: UnixStream, listener: Weak<RefCell<Listener>>) -> Connection {
+        let mut connection = Connection {
+            stream,
+            incoming: Vec::new(),
+            outgoing: Vec::new(),
+            closing: false,
+            read_event: Box::new(mem::zeroed()),
+            write_event: Box::new(mem::zeroed()),
+            listener: Box::new(listener),
+        };
+        let fd = connection.stream.as_raw_fd();
+        let arg = &*connection.listener as *const Weak<RefCell<Listener>> as *mut c_void;
+        tmux_bindings::event_set(
+            &mut *connection.read_event,
+            fd,
+            (EV_READ | EV_PERSIST) as c_short,
+            Some(read),
+            arg,
+        );
+        tmux_bindings::event_set(
+            &mut *connection.write_event,
+            fd,
+            EV_WRITE as c_short,
+            Some(write),
+            arg,
+        );
+        tmux_bindings::event_add(&mut *connection.read_event, ptr::null());
+        connection
+    }
+
+    /// Queues a reply, and writes as much of what is queued as the other end is ready for.
+    fn send(&mut self, reply: &[u8]) -> io::Result<()> {
+        if self.outgoing.len() + reply.len() > MAX_BUFFERED {
+            return Err(io::Error::other("too many unread replies"));
+        }
+        self.outgoing.extend_from_slice(reply);
+        self.flush()
+    }
+
+    /// Writes as much of the queued replies as the other end is ready for, and waits for it to
+    /// be ready for the rest.
+    fn flush(&mut self) -> io::Result<()> {
+        while !self.outgoing.is_empty() {
+            match self.stream.write(&self.outgoing) {
+                Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+                Ok(n) => {
+                    self.outgoing.drain(..n);
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => {
+                    unsafe { tmux_bindings::event_add(&mut *self.write_event, ptr::null()) };
+                    return Ok(());
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(())
+    }
+
+    /// Stops reading, closing the connection once its replies are written.
+    fn close(&mut self) {
+        self.closing = true;
+        self.incoming.clear();
+        unsafe { tmux_bindings::event_del(&mut *self.read_event) };
+    }
+
+    /// Whether the connection is closing, and has nothing left to write.
+    fn is_closed(&self) -> bool {
+        self.closing && self.outgoing.is_empty()
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        unsafe {
+            tmux_bindings::event_del(&mut *self.read_event);
+            tmux_bindings::event_del(&mut *self.write_event);
+        }
+    }
+}
+
+unsafe extern "C" fn accept(_fd: c_int, _events: c_short, arg: *mut c_void) {
+    let listener = match (*(arg as *const Weak<RefCell<Listener>>)).upgrade() {
+        Some(listener) => listener,
+        None => return,
+    };
+    let mut state = listener.borrow_mut();
+    while let Ok((stream, _)) = state.socket.accept() {
+        if stream.set_nonblocking(true).is_err() {
+            continue;
+        }
+        let fd = stream.as_raw_fd();
+        let connection = Connection::new(stream, Rc::downgrade(&listener));
+        state.connections.insert(fd, connection);
+    }
+}
+
+unsafe extern "C" fn read(fd: c_int, _events: c_short, arg: *mut c_void) {
+    // The connection, and with it the argument, may be dropped while handling its lines, so
+    // the listener is taken out of the argument first.
+    let listener = match (*(arg as *const Weak<RefCell<Listener>>)).upgrade() {
+        Some(listener) => listener,
+        None => return,
+    };
+    let (lines, closed, too_long) = {
+        let mut state = listener.borrow_mut();
+        let connection = match state.connections.get_mut(&fd) {
+            Some(connection) => connection,
+            None => return,
+        };
+        let mut chunk = [0; 4096];
+        let mut lines = Vec::new();
+        let mut too_long = false;
+        let closed = loop {
+            match connection.stream.read(&mut chunk) {
+                Ok(0) => break true,
+                Ok(n) => {
+                    connection.incoming.extend_from_slice(&chunk[..n]);
+                    while let Some(end) = connection.incoming.iter().position(|&b| b == b'\n') {
+                        let line: Vec<u8> = connection.incoming.drain(..=end).collect();
+                        lines.push(String::from_utf8_lossy(&line[..end]).into_owned());
+                    }
+                    if connection.incoming.len() > MAX_BUFFERED {
+                        too_long = true;
+                        break true;
+                    }
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => break error.kind() != io::ErrorKind::WouldBlock,
+            }
+        };
+        if closed {
+            connection.close();
+        }
+        (lines, closed, too_long)
+    };
+
+    for line in lines {
+        let line = line.trim_end_matches('\r');
+        let (command, rest) = match line.find(' ') {
+            Some(space) => (&line[..space], &line[space + 1..]),
+            None => (line, ""),
+        };
+        // The handler is copied out before running it, so that it can use the socket itself.
+        let handler = listener.borrow().handlers.get(command).cloned();
+        let reply = match handler {
+            Some(handler) => match (handler.borrow_mut())(rest) {
+                Ok(reply) if reply.is_empty() => "ok\n".to_owned(),
+                Ok(reply) => format!("ok {}\n", reply.replace('\n', " ")),
+                Err(error) => format!("error {}\n", error.replace('\n', " ")),
+            },
+            None => format!("error unknown command: {}\n", command),
+        };
+        send(&listener, fd, reply.as_bytes());
+    }
+    if too_long {
+        send(&listener, fd, b"error line too long\n");
+    }
+
+    if closed {
+        let mut state = listener.borrow_mut();
+        if state
+            .connections
+            .get(&fd)
+            .is_some_and(Connection::is_closed)
+        {
+            state.connections.remove(&fd);
+        }
+    }
+}
+
+unsafe extern "C" fn write(fd: c_int, _events: c_short, arg: *mut c_void) {
+    let listener = match (*(arg as *const Weak<RefCell<Listener>>)).upgrade() {
+        Some(listener) => listener,
+        None => return,
+    };
+    let mut state = listener.borrow_mut();
+    let done = match state.connections.get_mut(&fd) {
+        Some(connection) => connection.flush().is_err() || connection.is_closed(),
+        None => return,
+    };
+    if done {
+        state.connections.remove(&fd);
+    }
+}
+
+/// Sends a reply on a connection, if it is still open, closing it if that fails.
+fn send(listener: &RefCell<Listener>, fd: RawFd, reply: &[u8]) {
+    let mut state = listener.borrow_mut();
+    let failed = match state.connections.get_mut(&fd) {
+        Some(connection) => connection.send(reply).is_err(),
+        None => return,
+    };
+    if failed {
+        state.connections.remove(&fd);
+    }
+}
+
+#[cfg(all(test, feature = "stub"))]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::net::Shutdown;
+    use std::process;
+
+    /// A listener with an `echo` handler, and a connection to it.
+    fn connected(name: &str) -> (Rc<RefCell<Listener>>, RawFd, UnixStream) {
+        let path = env::temp_dir().join(format!("tmux-plugin-{}.{}", process::id(), name));
+        let _ = fs::remove_file(&path);
+        let mut handlers = HashMap::new();
+        let echo: Handler = Rc::new(RefCell::new(|text: &str| Ok(text.to_owned())));
+        handlers.insert("echo".to_owned(), echo);
+        let listener = Rc::new(RefCell::new(Listener {
+            socket: UnixListener::bind(&path).unwrap(),
+            path,
+            event: Box::new(unsafe { mem::zeroed() }),
+            this: Box::new(Weak::new()),
+            handlers,
+            connections: HashMap::new(),
+        }));
+        let (ours, theirs) = UnixStream::pair().unwrap();
+        ours.set_nonblocking(true).unwrap();
+        let fd = ours.as_raw_fd();
+        let connection = unsafe { Connection::new(ours, Rc::downgrade(&listener)) };
+        listener.borrow_mut().connections.insert(fd, connection);
+        (listener, fd, theirs)
+    }
+
+    fn read_ready(listener: &Rc<RefCell<Listener>>, fd: RawFd) {
+        let arg = {
+            let state = listener.borrow();
+            &*state.connections[&fd].listener as *const Weak<RefCell<Listener>> as *mut c_void
+        };
+        unsafe { read(fd, EV_READ as c_short, arg) };
+    }
+
+    fn replies(stream: &mut UnixStream) -> String {
+        stream.set_nonblocking(true).unwrap();
+        let mut replies = Vec::new();
+        let _ = stream.read_to_end(&mut replies);
+        String::from_utf8(replies).unwrap()
+    }
+
+    #[test]
+    fn each_line_is_answered() {
+        let (listener, fd, mut theirs) = connected("answered");
+        theirs.write_all(b"echo hello\nnope\r\necho").unwrap();
+        read_ready(&listener, fd);
+        assert_eq!(
+            replies(&mut theirs),
+            "ok hello\nerror unknown command: nope\n"
+        );
+        theirs.write_all(b"\n").unwrap();
+        read_ready(&listener, fd);
+        assert_eq!(replies(&mut theirs), "ok\n");
+    }
+
+    #[test]
+    fn replies_are_sent_after_the_other_end_stops_sending() {
+        let (listener, fd, mut theirs) = connected("half-closed");
+        theirs.write_all(b"echo bye\n").unwrap();
+        theirs.shutdown(Shutdown::Write).unwrap();
+        read_ready(&listener, fd);
+        assert!(listener.borrow().connections.is_empty());
+        assert_eq!(replies(&mut theirs), "ok bye\n");
+    }
+
+    #[test]
+    fn long_lines_close_the_connection() {
+        let (listener, fd, mut theirs) = connected("long");
+        theirs.write_all(b"echo first\n").unwrap();
+        theirs.write_all(&vec![b'x'; MAX_BUFFERED + 1]).unwrap();
+        read_ready(&listener, fd);
+        assert!(listener.borrow().connections.is_empty());
+        assert_eq!(replies(&mut theirs), "ok first\nerror line too long\n");
+    }
+
+    #[test]
+    fn replies_wait_for_a_slow_reader() {
+        let (ours, mut theirs) = UnixStream::pair().unwrap();
+        ours.set_nonblocking(true).unwrap();
+        let mut connection = unsafe { Connection::new(ours, Weak::new()) };
+        let reply = vec![b'x'; 1024];
+        let mut sent = 0;
+        while connection.outgoing.is_empty() {
+            connection.send(&reply).unwrap();
+            sent += reply.len();
+        }
+        connection.send(b"last\n").unwrap();
+        sent += 5;
+
+        let mut received = Vec::new();
+        theirs.set_nonblocking(true).unwrap();
+        while received.len() < sent {
+            let _ = theirs.read_to_end(&mut received);
+            connection.flush().unwrap();
+        }
+        assert!(connection.outgoing.is_empty());
+        assert_eq!(received.len(), sent);
+        assert!(received.ends_with(b"xlast\n"));
+    }
+
+    #[test]
+    fn unread_replies_are_limited() {
+        let (ours, _theirs) = UnixStream::pair().unwrap();
+        ours.set_nonblocking(true).unwrap();
+        let mut connection = unsafe { Connection::new(ours, Weak::new()) };
+        let reply = vec![b'x'; 1024];
+        while connection.send(&reply).is_ok() {}
+        assert!(connection.outgoing.len() + reply.len() > MAX_BUFFERED);
+        assert!(connection.outgoing.len() <= MAX_BUFFERED);
+    }
+
+    #[test]
+    fn closing_waits_for_replies() {
+        let (ours, mut theirs) = UnixStream::pair().unwrap();
+        ours.set_nonblocking(true).unwrap();
+        let mut connection = unsafe { Connection::new(ours, Weak::new()) };
+        assert!(!connection.is_closed());
+        connection.outgoing.extend_from_slice(b"ok\n");
+        connection.close();
+        assert!(!connection.is_closed());
+        connection.flush().unwrap();
+        assert!(connection.is_closed());
+        assert_eq!(replies(&mut theirs), "ok\n");
+    }
+}
@@ -0,0 +1,191 @@
+use super::Pane;
+use crate::tmux_bindings::{self, window_copy_mode, window_mode_entry};
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+
+/// Control over a pane in copy mode.
+///
+/// This drives copy mode through the same commands as `send-keys -X`, so everything a key
+/// binding in the `copy-mode` or `copy-mode-vi` tables can do is available through
+/// [`command`](#method.command). If the user leaves copy mode, the methods on this type
+/// do nothing and return `false`.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+///
+/// // Copies the line the cursor is on into a buffer named "line".
+/// cmd_plugin!(b"copy-current-line\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+///     let pane = item
+///         .target_client()
+///         .and_then(|client| client.session())
+///         .and_then(|session| session.current_window())
+///         .and_then(|window| window.active_pane());
+///     if let Some(pane) = pane {
+///         let copy_mode = pane.enter_copy_mode();
+///         copy_mode.command("select-line", 1);
+///         copy_mode.copy_selection(Some("line"));
+///         copy_mode.exit();
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CopyMode(Pane);
+
+impl Pane {
+    /// Puts the pane into copy mode, the same way the `copy-mode` command does.
+    ///
+    /// If the pane is already in copy mode, this leaves it where it is.
+    pub fn enter_copy_mode(&self) -> CopyMode {
+        unsafe {
+            tmux_bindings::window_pane_set_mode(
+                self.as_ptr(),
+                &window_copy_mode,
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+        }
+        CopyMode(*self)
+    }
+
+    /// Returns a handle to the pane's copy mode, if the pane is currently in copy mode.
+    pub fn copy_mode(&self) -> Option<CopyMode> {
+        let copy_mode = CopyMode(*self);
+        if copy_mode.entry().is_null() {
+            None
+        } else {
+            Some(copy_mode)
+        }
+    }
+}
+
+impl CopyMode {
+    /// The pane in copy mode.
+    pub fn pane(&self) -> Pane {
+        self.0
+    }
+
+    /// Whether the pane is still in copy mode.
+    pub fn is_active(&self) -> bool {
+        !self.entry().is_null()
+    }
+
+    /// Runs a copy mode command, such as `cursor-up` or `begin-selection`, `repeat` times.
+    ///
+    /// `command` may include arguments separated by spaces, like `goto-line 10`.
+    pub fn command(&self, command: &str, repeat: u32) -> bool {
+        let wme = self.entry();
+        if wme.is_null() {
+            return false;
+        }
+        let argv = match std::iter::once("send-keys")
+            .chain(command.split_whitespace())
+            .map(CString::new)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(argv) => argv,
+            Err(_) => return false,
+        };
+        let mut argv = argv
+            .iter()
+            .map(|arg| arg.as_ptr() as *mut c_char)
+            .collect::<Vec<_>>();
+
+        unsafe {
+            let args = tmux_bindings::args_parse(
+                crate::cstr!("").as_ptr(),
+                argv.len() as c_int,
+                argv.as_mut_ptr(),
+            );
+            if args.is_null() {
+                return false;
+            }
+            let w = (*self.0.as_ptr()).window;
+            let wl = (*w).winlinks.tqh_first;
+            let s = if wl.is_null() {
+                ptr::null_mut()
+            } else {
+                (*wl).session
+            };
+            (*wme).prefix = repeat;
+            if let Some(command) = (*(*wme).mode).command {
+                command(wme, ptr::null_mut(), s, wl, args, ptr::null_mut());
+            }
+            tmux_bindings::args_free(args);
+        }
+        true
+    }
+
+    /// Moves the cursor to a cell, where `line` counts from the oldest line of history.
+    pub fn move_cursor_to(&self, x: u32, line: u32) -> bool {
+        self.command("history-top", 1)
+            && self.command("start-of-line", 1)
+            && (line == 0 || self.command("cursor-down", line))
+            && (x == 0 || self.command("cursor-right", x))
+    }
+
+    /// Selects the text from `start` to `end`, which are `(x, line)` pairs as in
+    /// [`move_cursor_to`](#method.move_cursor_to).
+    pub fn set_selection(&self, start: (u32, u32), end: (u32, u32)) -> bool {
+        self.command("clear-selection", 1)
+            && self.move_cursor_to(start.0, start.1)
+            && self.command("begin-selection", 1)
+            && self.move_cursor_to(end.0, end.1)
+    }
+
+    /// Clears the selection.
+    pub fn clear_selection(&self) -> bool {
+        self.command("clear-selection", 1)
+    }
+
+    /// Copies the selection into a paste buffer, and clears the selection.
+    ///
+    /// Without a name, the buffer is named automatically, as with the `copy-selection` command.
+    pub fn copy_selection(&self, buffer: Option<&str>) -> bool {
+        let buffer = match buffer.map(CString::new).transpose() {
+            Ok(buffer) => buffer,
+            Err(_) => return false,
+        };
+        let previous = unsafe { tmux_bindings::paste_get_top(ptr::null_mut()) };
+        if !self.command("copy-selection", 1) {
+            return false;
+        }
+        if let Some(buffer) = buffer {
+            unsafe {
+                let mut name = ptr::null();
+                let pb = tmux_bindings::paste_get_top(&mut name);
+                if pb.is_null() || pb == previous {
+                    return false;
+                }
+                let mut cause = ptr::null_mut();
+                if tmux_bindings::paste_rename(name, buffer.as_ptr(), &mut cause) != 0 {
+                    super::strings::free(cause);
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    /// Leaves copy mode.
+    pub fn exit(self) {
+        if self.is_active() {
+            unsafe { tmux_bindings::window_pane_reset_mode(self.0.as_ptr()) }
+        }
+    }
+
+    fn entry(&self) -> *mut window_mode_entry {
+        unsafe {
+            let wme = (*self.0.as_ptr()).modes.tqh_first;
+            if wme.is_null() || !ptr::eq((*wme).mode, &window_copy_mode) {
+                ptr::null_mut()
+            } else {
+                wme
+            }
+        }
+    }
+}
@@ -0,0 +1,523 @@
+//! Typed views of tmux notifications.
+//!
+//! tmux passes every notification to plugins as a bare
+//! [`notify_entry`](../struct.notify_entry.html), leaving it to each plugin to work out which
+//! fields matter for which event. The types here read the relevant ones for particular events.
+//!
+//! Events from outside tmux, such as from a background thread watching a file, are brought
+//! onto tmux's event loop with an [`ExternalSource`](./struct.ExternalSource.html).
+
+use super::{
+    clients, refresh, Client, Layout, LayoutError, RenameError, Session, SessionId, Window,
+    WindowId,
+};
+use crate::tmux_bindings::{self, client, event, notify_entry, EV_PERSIST, EV_READ};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString, NulError};
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::os::raw::{c_int, c_short, c_void};
+use std::os::unix::io::{AsRawFd, FromRawFd};
+use std::ptr;
+use std::sync::{mpsc, Arc};
+use std::thread::LocalKey;
+
+thread_local! {
+    /// The last size seen for each client, so resizes can report the size before them.
+    static SIZES: RefCell<HashMap<*mut client, (u32, u32)>> = RefCell::new(HashMap::new());
+}
+
+/// A client's terminal size, as of a `client-resized` or `client-session-changed` notification.
+///
+/// tmux doesn't keep a client's previous size, so it is remembered from one notification
+/// to the next. The first time a client is seen, its previous size isn't known.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::display_message;
+/// use tmux_plugin::tmux::events::ClientResize;
+/// use std::ffi::CString;
+///
+/// // Shows a client its new size whenever its terminal is resized.
+/// notification_plugin!(|notify_entry| {
+///     if let Some(resize) = unsafe { ClientResize::from_notify(notify_entry) } {
+///         if resize.changed() {
+///             let (width, height) = resize.new_size;
+///             let message = CString::new(format!("{}x{}", width, height)).unwrap();
+///             display_message(resize.client, &message);
+///         }
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientResize {
+    /// The client whose terminal was resized, or which changed session.
+    pub client: Client,
+    /// The client's width and height the last time it was seen, if it has been seen before.
+    pub old_size: Option<(u32, u32)>,
+    /// The client's current width and height.
+    pub new_size: (u32, u32),
+}
+
+impl ClientResize {
+    /// Reads a `client-resized` or `client-session-changed` notification, returning `None`
+    /// for any other notification.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `notify_entry` passed to a notification callback.
+    pub unsafe fn from_notify(ne: *mut notify_entry) -> Option<ClientResize> {
+        match CStr::from_ptr((*ne).name).to_bytes() {
+            b"client-resized" | b"client-session-changed" => {}
+            _ => return None,
+        }
+        let client = Client::from_raw((*ne).client)?;
+        let new_size = client.size();
+        let old_size = SIZES.with(|sizes| {
+            let mut sizes = sizes.borrow_mut();
+            let live = clients::all().map(Client::as_ptr).collect::<Vec<_>>();
+            sizes.retain(|c, _| live.contains(c));
+            sizes.insert(client.as_ptr(), new_size)
+        });
+        Some(ClientResize {
+            client,
+            old_size,
+            new_size,
+        })
+    }
+
+    /// Whether the client's size is different from the last time it was seen.
+    pub fn changed(&self) -> bool {
+        self.old_size != Some(self.new_size)
+    }
+}
+
+/// What to do about a rename or layout change, once a plugin has seen it.
+///
+/// tmux has already made the change by the time a plugin is notified of it, so cancelling a
+/// change puts back what was there before, and replacing it makes a second change. The
+/// notifications these cause are recognised and skipped by the types which are resolved with
+/// an outcome, so a plugin doesn't see its own corrections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome<T> {
+    /// Leave the change as it is.
+    Keep,
+    /// Change it again, to this.
+    Replace(T),
+    /// Undo the change, if what was there before is known.
+    Cancel,
+}
+
+thread_local! {
+    /// The names and layouts last seen or set for each session and window, by ID, so that
+    /// changes to them can be undone.
+    static WINDOW_NAMES: RefCell<HashMap<WindowId, CString>> = RefCell::new(HashMap::new());
+    static SESSION_NAMES: RefCell<HashMap<SessionId, CString>> = RefCell::new(HashMap::new());
+    static LAYOUTS: RefCell<HashMap<WindowId, String>> = RefCell::new(HashMap::new());
+}
+
+/// Records the latest value for an ID, returning the one before it, or `None` if the value
+/// hasn't changed since it was last recorded.
+fn record<I: Copy + Eq + Hash, T: PartialEq>(
+    seen: &'static LocalKey<RefCell<HashMap<I, T>>>,
+    id: I,
+    value: T,
+    live: impl Fn(I) -> bool,
+) -> Option<Option<T>> {
+    seen.with(|seen| {
+        let mut seen = seen.borrow_mut();
+        seen.retain(|&id, _| live(id));
+        if seen.get(&id) == Some(&value) {
+            return None;
+        }
+        Some(seen.insert(id, value))
+    })
+}
+
+fn window_live(id: WindowId) -> bool {
+    Window::find_by_id(id).is_some()
+}
+
+fn session_live(id: SessionId) -> bool {
+    Session::find_by_id(id).is_some()
+}
+
+/// A `window-renamed` notification, which can be resolved by keeping, replacing, or cancelling
+/// the new name.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::events::{Outcome, WindowRename};
+///
+/// // Enforces that window names are lower case.
+/// notification_plugin!("window-renamed", |notify_entry| {
+///     if let Some(rename) = unsafe { WindowRename::from_notify(notify_entry) } {
+///         let name = rename.new_name.to_string_lossy().to_lowercase();
+///         rename.resolve(Outcome::Replace(name)).expect("The name came from a C string");
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowRename {
+    /// The window which was renamed.
+    pub window: Window,
+    /// The window's name the last time it was seen, if it has been seen before.
+    pub old_name: Option<CString>,
+    /// The window's new name.
+    pub new_name: CString,
+}
+
+impl WindowRename {
+    /// Reads a `window-renamed` notification, returning `None` for any other notification, or
+    /// when the name hasn't changed since it was last seen, such as after a rename made by
+    /// [`resolve`](#method.resolve).
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `notify_entry` passed to a notification callback.
+    pub unsafe fn from_notify(ne: *mut notify_entry) -> Option<WindowRename> {
+        if CStr::from_ptr((*ne).name).to_bytes() != b"window-renamed" {
+            return None;
+        }
+        let window = Window::from_raw((*ne).window)?;
+        let new_name = window.name().to_owned();
+        let old_name = record(&WINDOW_NAMES, window.id(), new_name.clone(), window_live)?;
+        Some(WindowRename {
+            window,
+            old_name,
+            new_name,
+        })
+    }
+
+    /// Keeps, replaces, or cancels the new name.
+    pub fn resolve(self, outcome: Outcome<String>) -> Result<(), NulError> {
+        let name = match outcome {
+            Outcome::Keep => return Ok(()),
+            Outcome::Replace(name) => CString::new(name)?,
+            Outcome::Cancel => match self.old_name {
+                Some(name) => name,
+                None => return Ok(()),
+            },
+        };
+        let id = self.window.id();
+        WINDOW_NAMES.with(|names| names.borrow_mut().insert(id, name.clone()));
+        self.window.rename(&name.to_string_lossy())
+    }
+}
+
+/// A `session-renamed` notification, which can be resolved by keeping, replacing, or
+/// cancelling the new name, the same way as a [`WindowRename`](./struct.WindowRename.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionRename {
+    /// The session which was renamed.
+    pub session: Session,
+    /// The session's name the last time it was seen, if it has been seen before.
+    pub old_name: Option<CString>,
+    /// The session's new name.
+    pub new_name: CString,
+}
+
+impl SessionRename {
+    /// Reads a `session-renamed` notification, returning `None` for any other notification, or
+    /// when the name hasn't changed since it was last seen.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `notify_entry` passed to a notification callback.
+    pub unsafe fn from_notify(ne: *mut notify_entry) -> Option<SessionRename> {
+        if CStr::from_ptr((*ne).name).to_bytes() != b"session-renamed" {
+            return None;
+        }
+        let session = Session::from_raw((*ne).session)?;
+        let new_name = session.name().to_owned();
+        let old_name = record(&SESSION_NAMES, session.id(), new_name.clone(), session_live)?;
+        Some(SessionRename {
+            session,
+            old_name,
+            new_name,
+        })
+    }
+
+    /// Keeps, replaces, or cancels the new name.
+    ///
+    /// This fails if the name is taken by another session, or isn't a valid session name.
+    pub fn resolve(self, outcome: Outcome<String>) -> Result<(), RenameError> {
+        let name = match outcome {
+            Outcome::Keep => return Ok(()),
+            Outcome::Replace(name) => CString::new(name).map_err(RenameError::Nul)?,
+            Outcome::Cancel => match self.old_name {
+                Some(name) => name,
+                None => return Ok(()),
+            },
+        };
+        let id = self.session.id();
+        let previous = SESSION_NAMES.with(|names| names.borrow_mut().insert(id, name.clone()));
+        let result = self.session.rename(&name.to_string_lossy());
+        if result.is_err() {
+            SESSION_NAMES.with(|names| match previous {
+                Some(previous) => names.borrow_mut().insert(id, previous),
+                None => names.borrow_mut().remove(&id),
+            });
+        }
+        result
+    }
+}
+
+/// A `window-layout-changed` notification, which can be resolved by keeping, replacing, or
+/// cancelling the new layout.
+///
+/// Cancelling a change only works when the window still has the same panes, as after a resize,
+/// and does nothing otherwise, such as after a pane is split or closed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayoutChange {
+    /// The window whose layout changed.
+    pub window: Window,
+    /// The window's layout the last time it was seen, in tmux's own notation, if it has been
+    /// seen before.
+    pub old_layout: Option<String>,
+    /// The window's new layout, in tmux's own notation.
+    pub new_layout: String,
+}
+
+impl LayoutChange {
+    /// Reads a `window-layout-changed` notification, returning `None` for any other
+    /// notification, or when the layout hasn't changed since it was last seen.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `notify_entry` passed to a notification callback.
+    pub unsafe fn from_notify(ne: *mut notify_entry) -> Option<LayoutChange> {
+        if CStr::from_ptr((*ne).name).to_bytes() != b"window-layout-changed" {
+            return None;
+        }
+        let window = Window::from_raw((*ne).window)?;
+        let new_layout = window.layout_string()?;
+        let old_layout = record(&LAYOUTS, window.id(), new_layout.clone(), window_live)?;
+        Some(LayoutChange {
+            window,
+            old_layout,
+            new_layout,
+        })
+    }
+
+    /// Keeps, replaces, or cancels the new layout.
+    pub fn resolve(self, outcome: Outcome<Layout>) -> Result<(), LayoutError> {
+        let id = self.window.id();
+        match outcome {
+            Outcome::Keep => Ok(()),
+            Outcome::Replace(layout) => {
+                self.window.apply_layout(&layout)?;
+                if let Some(layout) = self.window.layout_string() {
+                    LAYOUTS.with(|layouts| layouts.borrow_mut().insert(id, layout));
+                }
+                Ok(())
+            }
+            Outcome::Cancel => {
+                let old_layout = match self.old_layout {
+                    Some(old_layout) => old_layout,
+                    None => return Ok(()),
+                };
+                let layout =
+                    CString::new(old_layout.as_str()).expect("The layout came from a C string");
+                let w = self.window.as_ptr();
+                LAYOUTS.with(|layouts| layouts.borrow_mut().insert(id, old_layout));
+                unsafe {
+                    tmux_bindings::window_unzoom(w);
+                    if tmux_bindings::layout_parse(w, layout.as_ptr()) == 0 {
+                        tmux_bindings::server_redraw_window(w);
+                    } else {
+                        let new_layout = self.new_layout;
+                        LAYOUTS.with(|layouts| layouts.borrow_mut().insert(id, new_layout));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// What to refresh after handling an event from an
+/// [`ExternalSource`](./struct.ExternalSource.html), with the functions in
+/// [`refresh`](../refresh/index.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Refresh {
+    /// Nothing needs redrawing.
+    Nothing,
+    /// The status line of every client.
+    All,
+    /// The status lines of the clients attached to a session.
+    Session(Session),
+    /// The status lines of the clients showing a window.
+    Window(Window),
+    /// The status line of one client.
+    Client(Client),
+}
+
+/// A way for other threads to send events to a plugin, which are handled on tmux's event loop.
+///
+/// tmux and the plugins it runs all live on one thread, which mustn't be blocked and which
+/// other threads mustn't touch tmux from. An external source is created on tmux's thread with a
+/// handler, and can then be cloned and sent to any number of other threads, which
+/// [push](#method.push) events to it. tmux wakes up for each event and runs the handler with
+/// it, then redraws the status lines the handler asks for, so new data is shown straight
+/// away instead of at the next `status-interval`.
+///
+/// Once every clone of the source is dropped, any events still waiting are handled and then
+/// the handler is dropped too.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::events::{ExternalSource, Refresh};
+/// use std::cell::{Cell, RefCell};
+/// use std::ffi::CString;
+/// use std::thread;
+/// use std::time::Duration;
+///
+/// thread_local! {
+///     static STARTED: Cell<bool> = Cell::new(false);
+///     static UNREAD: RefCell<String> = RefCell::new(String::new());
+/// }
+///
+/// // Shows the number of unread mails, checked on a background thread.
+/// format_plugin!(b"unread_mail\0", |_format_tree| {
+///     if !STARTED.with(|started| started.replace(true)) {
+///         let source = ExternalSource::new(|count: usize| {
+///             UNREAD.with(|unread| *unread.borrow_mut() = count.to_string());
+///             Refresh::All
+///         });
+///         if let Ok(source) = source {
+///             thread::spawn(move || loop {
+///                 let count = std::fs::read_dir("/var/mail/new").map_or(0, |dir| dir.count());
+///                 source.push(count);
+///                 thread::sleep(Duration::from_secs(30));
+///             });
+///         }
+///     }
+///     UNREAD.with(|unread| CString::new(unread.borrow().as_str()).unwrap_or_default())
+/// });
+/// # fn main() {}
+/// ```
+pub struct ExternalSource<T> {
+    shared: Arc<Shared<T>>,
+}
+
+struct Shared<T> {
+    sender: mpsc::Sender<T>,
+    /// The write end of a pipe, which wakes tmux up when written to. It is closed once the
+    /// last clone of the source is dropped, which tells the receiving end to go away.
+    wake: File,
+}
+
+struct Receiver<T> {
+    receiver: mpsc::Receiver<T>,
+    wake: File,
+    event: event,
+    handler: Box<dyn FnMut(T) -> Refresh>,
+}
+
+impl<T: Send + 'static> ExternalSource<T> {
+    /// Creates a source whose events are handled by `handler`, which returns what to refresh
+    /// afterwards.
+    ///
+    /// This must be called on tmux's thread, from a plugin callback.
+    pub fn new<F: FnMut(T) -> Refresh + 'static>(handler: F) -> io::Result<ExternalSource<T>> {
+        let mut fds = [0; 2];
+        if unsafe { libc::pipe(fds.as_mut_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let (read, write) = unsafe { (File::from_raw_fd(fds[0]), File::from_raw_fd(fds[1])) };
+        for fd in &fds {
+            unsafe {
+                let flags = libc::fcntl(*fd, libc::F_GETFL);
+                libc::fcntl(*fd, libc::F_SETFL, flags | libc::O_NONBLOCK);
+                libc::fcntl(*fd, libc::F_SETFD, libc::FD_CLOEXEC);
+            }
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Box::into_raw(Box::new(Receiver {
+            receiver,
+            wake: read,
+            event: unsafe { mem::zeroed() },
+            handler: Box::new(handler),
+        }));
+        unsafe {
+            tmux_bindings::event_set(
+                &mut (*receiver).event,
+                (*receiver).wake.as_raw_fd(),
+                (EV_READ | EV_PERSIST) as c_short,
+                Some(wake::<T>),
+                receiver as *mut c_void,
+            );
+            tmux_bindings::event_add(&mut (*receiver).event, ptr::null());
+        }
+        Ok(ExternalSource {
+            shared: Arc::new(Shared {
+                sender,
+                wake: write,
+            }),
+        })
+    }
+
+    /// Sends an event to be handled on tmux's event loop. This can be called from any thread.
+    pub fn push(&self, event: T) {
+        if self.shared.sender.send(event).is_ok() {
+            // If the pipe is full, tmux already has a wake up waiting, which is enough.
+            let _ = (&self.shared.wake).write(&[0]);
+        }
+    }
+}
+
+impl<T> Clone for ExternalSource<T> {
+    fn clone(&self) -> ExternalSource<T> {
+        ExternalSource {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+unsafe extern "C" fn wake<T>(_fd: c_int, _events: c_short, arg: *mut c_void) {
+    let receiver = arg as *mut Receiver<T>;
+    let mut buffer = [0; 64];
+    let closed = loop {
+        match (*receiver).wake.read(&mut buffer) {
+            Ok(0) => break true,
+            Ok(_) => {}
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+            Err(error) => break error.kind() != io::ErrorKind::WouldBlock,
+        }
+    };
+
+    let mut refreshes = Vec::new();
+    while let Ok(event) = (*receiver).receiver.try_recv() {
+        let target = ((*receiver).handler)(event);
+        if !refreshes.contains(&target) {
+            refreshes.push(target);
+        }
+    }
+    for target in refreshes {
+        match target {
+            Refresh::Nothing => {}
+            Refresh::All => refresh::status_all(),
+            Refresh::Session(session) => refresh::status(session),
+            Refresh::Window(window) => refresh::window_status(window),
+            Refresh::Client(client) => refresh::client_status(client),
+        }
+    }
+
+    if closed {
+        tmux_bindings::event_del(&mut (*receiver).event);
+        drop(Box::from_raw(receiver));
+    }
+}
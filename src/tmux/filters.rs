@@ -0,0 +1,70 @@
+//! Ready-made filters for [`notification_plugin!`](../../macro.notification_plugin.html).
+//!
+//! A filter is any `Fn(&notify_entry) -> bool`, checked before the notification's body runs.
+//! These cover the common cases, and can be combined in a closure of their own, such as
+//! `|ne| filters::visible(ne) && session(ne)`.
+
+use super::strings::to_tmux_string_lossy;
+use super::{clients, Session, Window};
+use crate::tmux_bindings::notify_entry;
+use std::ffi::{CStr, CString};
+
+/// Passes notifications about sessions whose names match a pattern, such as `work-*`.
+///
+/// Patterns are matched the same way tmux matches them, as with `fnmatch(3)`. Notifications
+/// about windows and panes are matched by the session they were found in.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::filters;
+/// use tmux_plugin::tmux::Window;
+///
+/// // Keeps the names of windows in work sessions lower case.
+/// notification_plugin!("window-renamed", if filters::session_matches("work-*"), |notify_entry| {
+///     if let Some(window) = unsafe { Window::from_raw((*notify_entry).window) } {
+///         let name = window.name().to_string_lossy().to_lowercase();
+///         window.rename(&name).expect("The name came from a C string");
+///     }
+/// });
+/// # fn main() {}
+/// ```
+pub fn session_matches(pattern: &str) -> impl Fn(&notify_entry) -> bool {
+    let pattern = to_tmux_string_lossy(pattern);
+    move |ne| session(ne).is_some_and(|session| matches(&pattern, session.name()))
+}
+
+/// Passes notifications about windows whose names match a pattern, matched as by
+/// [`session_matches`](./fn.session_matches.html).
+pub fn window_matches(pattern: &str) -> impl Fn(&notify_entry) -> bool {
+    let pattern = to_tmux_string_lossy(pattern);
+    move |ne| window(ne).is_some_and(|window| matches(&pattern, window.name()))
+}
+
+/// Passes notifications about windows, and the panes in them, which some client is looking
+/// at: the current window of a session with a client attached.
+pub fn visible(ne: &notify_entry) -> bool {
+    let window = match window(ne) {
+        Some(window) => window,
+        None => return false,
+    };
+    clients::all().any(|client| {
+        client
+            .session()
+            .and_then(|session| session.current_window())
+            == Some(window)
+    })
+}
+
+fn session(ne: &notify_entry) -> Option<Session> {
+    unsafe { Session::from_raw(ne.session).or_else(|| Session::from_raw(ne.fs.s)) }
+}
+
+fn window(ne: &notify_entry) -> Option<Window> {
+    unsafe { Window::from_raw(ne.window).or_else(|| Window::from_raw(ne.fs.w)) }
+}
+
+fn matches(pattern: &CString, name: &CStr) -> bool {
+    unsafe { libc::fnmatch(pattern.as_ptr(), name.as_ptr(), 0) == 0 }
+}
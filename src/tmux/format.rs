@@ -0,0 +1,737 @@
+//! Support for format variables.
+
+use super::events::{ExternalSource, Refresh};
+use super::strings::to_tmux_string_lossy;
+use super::{server, timer, Options};
+use crate::tmux_bindings::{self, client, format_tree, session, window, window_pane, winlink};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+use std::rc::{Rc, Weak};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// What a format variable is being expanded for: the client, session, window and pane.
+type Context = (
+    *mut client,
+    *mut session,
+    *mut winlink,
+    *mut window,
+    *mut window_pane,
+);
+
+/// A value which is reset once tmux returns to its event loop.
+///
+/// tmux expands every format it is drawing before it returns to its event loop, so anything
+/// worked out while expanding one variable holds for the others expanded along with it.
+#[derive(Default)]
+struct UntilNextLoop<T: Default> {
+    value: T,
+    stale: Rc<Cell<bool>>,
+    armed: bool,
+}
+
+impl<T: Default> UntilNextLoop<T> {
+    fn get_mut(&mut self) -> &mut T {
+        if self.stale.replace(false) {
+            self.value = T::default();
+            self.armed = false;
+        }
+        if !self.armed {
+            let stale = self.stale.clone();
+            timer::after(Duration::from_secs(0), move || stale.set(true));
+            self.armed = true;
+        }
+        &mut self.value
+    }
+}
+
+/// The values computed for a [`format_group_plugin!`](../../macro.format_group_plugin.html).
+///
+/// tmux asks for each variable separately, so the values for the same client, session,
+/// window and pane are reused until tmux returns to its event loop.
+#[doc(hidden)]
+#[derive(Default)]
+pub struct GroupCache(UntilNextLoop<HashMap<Context, HashMap<&'static str, String>>>);
+
+impl GroupCache {
+    /// Looks up one of the group's values, computing them all if need be.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `format_tree` passed to a format callback.
+    pub unsafe fn get(
+        &mut self,
+        ft: *mut format_tree,
+        key: &str,
+        compute: fn(*mut format_tree, &mut HashMap<&'static str, String>),
+    ) -> CString {
+        let context = ((*ft).c, (*ft).s, (*ft).wl, (*ft).w, (*ft).wp);
+        let values = self.0.get_mut().entry(context).or_insert_with(|| {
+            let mut values = HashMap::new();
+            compute(ft, &mut values);
+            values
+        });
+        let value = values.get(key).map_or("", String::as_str);
+        CString::new(value.split('\0').next().unwrap_or_default())
+            .expect("The value was cut at the first null byte")
+    }
+}
+
+thread_local! {
+    static REFERENCED: RefCell<UntilNextLoop<HashMap<String, bool>>> =
+        RefCell::new(UntilNextLoop::default());
+}
+
+/// Whether a format variable appears in any option, such as `status-right` or
+/// `window-status-format`, globally or for any session or window.
+///
+/// This is what [`format_plugin!`](../../macro.format_plugin.html) checks for variables
+/// marked `lazy`. Options are only searched once each time tmux expands formats, however
+/// many times this is called.
+pub fn is_referenced(name: &str) -> bool {
+    REFERENCED.with(|referenced| {
+        if let Some(&found) = referenced.borrow_mut().get_mut().get(name) {
+            return found;
+        }
+        let found = all_options()
+            .iter()
+            .flat_map(|options| options.values())
+            .any(|value| mentions(&value, name));
+        referenced
+            .borrow_mut()
+            .get_mut()
+            .insert(name.to_owned(), found);
+        found
+    })
+}
+
+/// The global options and the options of every session and window.
+fn all_options() -> Vec<Options> {
+    let mut all = vec![
+        server::global_options(),
+        server::global_session_options(),
+        server::global_window_options(),
+    ];
+    unsafe {
+        let mut s =
+            tmux_bindings::sessions_RB_MINMAX(ptr::addr_of_mut!(tmux_bindings::sessions), -1);
+        while !s.is_null() {
+            all.extend(Options::from_raw((*s).options));
+            s = tmux_bindings::sessions_RB_NEXT(s);
+        }
+        let mut w = tmux_bindings::windows_RB_MINMAX(ptr::addr_of_mut!(tmux_bindings::windows), -1);
+        while !w.is_null() {
+            all.extend(Options::from_raw((*w).options));
+            w = tmux_bindings::windows_RB_NEXT(w);
+        }
+    }
+    all
+}
+
+/// Whether `name` appears in `value` as a whole variable name.
+fn mentions(value: &str, name: &str) -> bool {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+    value.match_indices(name).any(|(start, _)| {
+        let before = value[..start].chars().next_back();
+        let after = value[start + name.len()..].chars().next();
+        !before.is_some_and(is_name_char) && !after.is_some_and(is_name_char)
+    })
+}
+
+/// A tmux format string, built up piece by piece.
+///
+/// Text is escaped as it is added, so it is shown as is, even if it contains characters
+/// which mean something in formats, such as `#` or a comma inside a conditional.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::tmux::format::Format;
+///
+/// // Shows the session name, in red when a window is zoomed.
+/// let format = Format::new()
+///     .when(
+///         Format::var("window_zoomed_flag"),
+///         Format::new().styled("fg=red,bold", Format::var("session_name")),
+///         Format::var("session_name"),
+///     )
+///     .text(" #1");
+/// assert_eq!(
+///     format.to_string(),
+///     "#{?#{window_zoomed_flag},#[fg=red#,bold]#{session_name}#[default],#{session_name}} ##1",
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Format(String);
+
+impl Format {
+    /// An empty format.
+    pub fn new() -> Format {
+        Format::default()
+    }
+
+    /// A format which expands to a variable, like `#{session_name}`.
+    pub fn var(name: &str) -> Format {
+        Format::new().variable(name)
+    }
+
+    /// A condition which is true when two formats expand to the same thing, like
+    /// `#{==:#{pane_current_command},vim}`.
+    pub fn equals(left: Format, right: Format) -> Format {
+        Format(format!("#{{==:{},{}}}", left.0, right.0))
+    }
+
+    /// A condition which is true when two formats expand to different things.
+    pub fn not_equals(left: Format, right: Format) -> Format {
+        Format(format!("#{{!=:{},{}}}", left.0, right.0))
+    }
+
+    /// Adds text, shown as is.
+    pub fn text(mut self, text: &str) -> Format {
+        escape_into(&mut self.0, text);
+        self
+    }
+
+    /// Adds a variable, like `#{session_name}`.
+    pub fn variable(mut self, name: &str) -> Format {
+        self.0.push_str("#{");
+        self.0.push_str(name);
+        self.0.push('}');
+        self
+    }
+
+    /// Adds a conditional, which shows `then` if `condition` expands to something other than
+    /// an empty string or `0`, and `otherwise` if not.
+    ///
+    /// The condition is usually a [variable](#method.var), or a comparison such as
+    /// [`equals`](#method.equals).
+    pub fn when(mut self, condition: Format, then: Format, otherwise: Format) -> Format {
+        self.0.push_str("#{?");
+        self.0.push_str(&condition.0);
+        self.0.push(',');
+        self.0.push_str(&then.0);
+        self.0.push(',');
+        self.0.push_str(&otherwise.0);
+        self.0.push('}');
+        self
+    }
+
+    /// Switches to a style, like `fg=red` or `bg=colour235,bold`, for whatever is added next.
+    pub fn style(mut self, style: &str) -> Format {
+        self.0.push_str("#[");
+        escape_into(&mut self.0, style);
+        self.0.push(']');
+        self
+    }
+
+    /// Adds a format shown in a style, after which the style is reset to the default.
+    pub fn styled(self, style: &str, content: Format) -> Format {
+        self.style(style).append(content).style("default")
+    }
+
+    /// Adds another format.
+    pub fn append(mut self, other: Format) -> Format {
+        self.0.push_str(&other.0);
+        self
+    }
+
+    /// The format as a string, for use as the value of an option or format variable.
+    pub fn into_string(self) -> String {
+        self.0
+    }
+
+    /// The format as a C string, for returning from a plugin.
+    pub fn into_c_string(self) -> CString {
+        CString::new(self.0.split('\0').next().unwrap_or_default())
+            .expect("The format was cut at the first null byte")
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn escape_into(format: &mut String, text: &str) {
+    for c in text.chars() {
+        if let '#' | ',' | '}' = c {
+            format.push('#');
+        }
+        format.push(c);
+    }
+}
+
+/// The parameters and value passed to a format function, split out of its one input.
+///
+/// tmux passes a [`format_function_plugin!`](../../macro.format_function_plugin.html) just one
+/// string. By convention, functions which need parameters take them at the start of it,
+/// separated from each other and from the value by semicolons: `10;left;value`. A semicolon or
+/// backslash inside a parameter is escaped with a backslash, as [`escape`](#method.escape)
+/// does.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_function_plugin;
+/// use tmux_plugin::tmux::format::FunctionArgs;
+/// use std::ffi::CString;
+///
+/// // Pads a value to a width, such as `10;left;value`.
+/// format_function_plugin!(b"pad\0", |input| {
+///     let args = FunctionArgs::parse_n(input, 2);
+///     let width = args.arg(0).and_then(|width| width.parse().ok()).unwrap_or(0);
+///     let padded = match args.arg(1) {
+///         Some("right") => format!("{:>width$}", args.value(), width = width),
+///         _ => format!("{:<width$}", args.value(), width = width),
+///     };
+///     CString::new(padded).unwrap_or_default()
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FunctionArgs {
+    args: Vec<String>,
+    value: String,
+}
+
+impl FunctionArgs {
+    /// Splits an input at every unescaped semicolon. The last part is the value, and the ones
+    /// before it the parameters.
+    ///
+    /// If the value may contain semicolons of its own, as values from variables such as
+    /// `window_name` may, use [`parse_n`](#method.parse_n) instead.
+    ///
+    /// ```rust
+    /// use tmux_plugin::cstr;
+    /// use tmux_plugin::tmux::format::FunctionArgs;
+    ///
+    /// let input = cstr!("a\\;b;c;value");
+    /// let args = FunctionArgs::parse(input);
+    /// assert_eq!(args.args(), ["a;b", "c"]);
+    /// assert_eq!(args.value(), "value");
+    /// ```
+    pub fn parse(input: &CStr) -> FunctionArgs {
+        FunctionArgs::split(&input.to_string_lossy(), usize::MAX)
+    }
+
+    /// Splits up to `count` parameters off the start of an input. Everything after them is the
+    /// value, exactly as it was passed, semicolons and backslashes included.
+    ///
+    /// If the input has fewer parameters, the missing ones are left out, and the last part is
+    /// still the value.
+    ///
+    /// ```rust
+    /// use tmux_plugin::cstr;
+    /// use tmux_plugin::tmux::format::FunctionArgs;
+    ///
+    /// let input = cstr!("10;left;a;b");
+    /// let args = FunctionArgs::parse_n(input, 2);
+    /// assert_eq!(args.args(), ["10", "left"]);
+    /// assert_eq!(args.value(), "a;b");
+    /// ```
+    pub fn parse_n(input: &CStr, count: usize) -> FunctionArgs {
+        FunctionArgs::split(&input.to_string_lossy(), count)
+    }
+
+    /// Escapes a parameter, so that it is passed as is, even if it contains semicolons.
+    pub fn escape(arg: &str) -> String {
+        let mut escaped = String::with_capacity(arg.len());
+        for c in arg.chars() {
+            if let ';' | '\\' = c {
+                escaped.push('\\');
+            }
+            escaped.push(c);
+        }
+        escaped
+    }
+
+    /// The parameters.
+    pub fn args(&self) -> &[String] {
+        &self.args
+    }
+
+    /// One of the parameters, if it was passed.
+    pub fn arg(&self, index: usize) -> Option<&str> {
+        self.args.get(index).map(String::as_str)
+    }
+
+    /// The value the function was passed, after the parameters.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    fn split(input: &str, count: usize) -> FunctionArgs {
+        let mut args = Vec::new();
+        let mut current = String::new();
+        let mut chars = input.char_indices();
+        let mut rest = 0;
+        while args.len() < count {
+            match chars.next() {
+                Some((_, '\\')) => match chars.next() {
+                    Some((_, c)) => current.push(c),
+                    None => current.push('\\'),
+                },
+                Some((i, ';')) => {
+                    args.push(std::mem::take(&mut current));
+                    rest = i + 1;
+                }
+                Some((_, c)) => current.push(c),
+                None => break,
+            }
+        }
+        let value = if args.len() < count {
+            // Every separator was found, and the last part is the value.
+            current
+        } else {
+            input[rest..].to_owned()
+        };
+        FunctionArgs { args, value }
+    }
+}
+
+/// A number formatted for a format variable.
+///
+/// Numbers are always written the same way, with a `.` for the decimal point, no grouping of
+/// thousands and no exponent, so that they can be compared by tmux and read back by scripts.
+/// A `FormatValue` can be returned directly from a [`format_plugin!`](../../macro.format_plugin.html).
+///
+/// ```rust
+/// use tmux_plugin::tmux::format::FormatValue;
+///
+/// assert_eq!(FormatValue::from(1_000_000u64).as_str(), "1000000");
+/// assert_eq!(FormatValue::from(-3i64).as_str(), "-3");
+/// assert_eq!(FormatValue::decimal(2.0 / 3.0, 2).as_str(), "0.67");
+/// assert_eq!(FormatValue::decimal(-0.001, 1).as_str(), "0.0");
+/// assert_eq!(FormatValue::percentage(0.999).as_str(), "99");
+/// assert_eq!(FormatValue::decimal(f64::NAN, 2).as_str(), "");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FormatValue(CString);
+
+impl FormatValue {
+    /// A number with a fixed number of digits after the decimal point, rounded to the nearest.
+    ///
+    /// Infinite and NaN values are empty, rather than showing up as `inf` or `NaN`.
+    pub fn decimal(value: f64, precision: usize) -> FormatValue {
+        if !value.is_finite() {
+            return FormatValue::new(String::new());
+        }
+        let mut formatted = format!("{:.*}", precision, value);
+        // Values which round to zero shouldn't be shown as `-0`.
+        if formatted.starts_with('-') && formatted[1..].chars().all(|c| c == '0' || c == '.') {
+            formatted.remove(0);
+        }
+        FormatValue::new(formatted)
+    }
+
+    /// A fraction between 0 and 1 as a whole percentage, without the `%` sign.
+    ///
+    /// The percentage is rounded down, so that nothing is shown as `100` before it is
+    /// complete, and clamped between 0 and 100. NaN is empty.
+    pub fn percentage(fraction: f64) -> FormatValue {
+        if fraction.is_nan() {
+            return FormatValue::new(String::new());
+        }
+        let percent = (fraction * 100.0).floor().clamp(0.0, 100.0);
+        FormatValue::from(percent as u64)
+    }
+
+    /// The value as a string.
+    pub fn as_str(&self) -> &str {
+        self.0.to_str().expect("Formatted numbers are ASCII")
+    }
+
+    /// The value as a C string, for returning from a plugin.
+    pub fn into_c_string(self) -> CString {
+        self.0
+    }
+
+    fn new(formatted: String) -> FormatValue {
+        FormatValue(CString::new(formatted).expect("Formatted numbers have no null bytes"))
+    }
+}
+
+macro_rules! format_value_from {
+    ($($type:ty),*) => {
+        $(
+            impl From<$type> for FormatValue {
+                fn from(value: $type) -> FormatValue {
+                    FormatValue::new(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+format_value_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl AsRef<CStr> for FormatValue {
+    fn as_ref(&self) -> &CStr {
+        &self.0
+    }
+}
+
+impl fmt::Display for FormatValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A value which is slow to work out, such as one fetched over the network, worked out on
+/// another thread so that format plugins never wait for it.
+///
+/// Reading the value returns whatever was last worked out straight away. If that is older than
+/// the value's time to live, it is worked out again on another thread, and every client's
+/// status line is redrawn once it is ready. Until the value is first ready, it is `None`.
+///
+/// This is meant to be kept in a `thread_local!`, since tmux runs plugins on its one thread.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::format::BackgroundValue;
+/// use std::ffi::CString;
+/// use std::time::Duration;
+///
+/// thread_local! {
+///     static WEATHER: BackgroundValue<String> =
+///         BackgroundValue::new(Duration::from_secs(600), || {
+///             let output = std::process::Command::new("curl")
+///                 .args(&["-s", "https://wttr.in/?format=%t"])
+///                 .output();
+///             output.map_or_else(
+///                 |_| String::new(),
+///                 |output| String::from_utf8_lossy(&output.stdout).into_owned(),
+///             )
+///         });
+/// }
+///
+/// format_plugin!(b"weather\0", |_format_tree| {
+///     let weather = WEATHER.with(|weather| weather.get()).unwrap_or_default();
+///     CString::new(weather).unwrap_or_default()
+/// });
+/// # fn main() {}
+/// ```
+pub struct BackgroundValue<T> {
+    state: Rc<RefCell<Background<T>>>,
+}
+
+struct Background<T> {
+    value: Option<T>,
+    updated: Option<Instant>,
+    refreshing: bool,
+    ttl: Duration,
+    compute: Arc<dyn Fn() -> T + Send + Sync>,
+    source: Option<ExternalSource<T>>,
+}
+
+impl<T: Clone + Send + 'static> BackgroundValue<T> {
+    /// A value which is worked out by `compute`, and worked out again once it is older than
+    /// `ttl`.
+    pub fn new<F: Fn() -> T + Send + Sync + 'static>(
+        ttl: Duration,
+        compute: F,
+    ) -> BackgroundValue<T> {
+        BackgroundValue {
+            state: Rc::new(RefCell::new(Background {
+                value: None,
+                updated: None,
+                refreshing: false,
+                ttl,
+                compute: Arc::new(compute),
+                source: None,
+            })),
+        }
+    }
+
+    /// The last value worked out, starting to work it out again if it is out of date.
+    pub fn get(&self) -> Option<T> {
+        let stale = {
+            let state = self.state.borrow();
+            state
+                .updated
+                .is_none_or(|updated| updated.elapsed() >= state.ttl)
+        };
+        if stale {
+            self.refresh();
+        }
+        self.state.borrow().value.clone()
+    }
+
+    /// Starts working out the value again, even if it isn't out of date yet. This does nothing
+    /// if it is already being worked out.
+    pub fn refresh(&self) {
+        let mut state = self.state.borrow_mut();
+        if state.refreshing {
+            return;
+        }
+        if state.source.is_none() {
+            let weak = Rc::downgrade(&self.state);
+            state.source = ExternalSource::new(move |value| Self::update(&weak, value)).ok();
+        }
+        let compute = state.compute.clone();
+        match state.source.clone() {
+            Some(source) => {
+                state.refreshing = true;
+                thread::spawn(move || source.push(compute()));
+            }
+            // Without a way back from another thread, the value is worked out right away.
+            None => {
+                state.value = Some(compute());
+                state.updated = Some(Instant::now());
+            }
+        }
+    }
+
+    fn update(state: &Weak<RefCell<Background<T>>>, value: T) -> Refresh {
+        let state = match state.upgrade() {
+            Some(state) => state,
+            None => return Refresh::Nothing,
+        };
+        let mut state = state.borrow_mut();
+        state.value = Some(value);
+        state.updated = Some(Instant::now());
+        state.refreshing = false;
+        Refresh::All
+    }
+}
+
+/// A set of format variables, and the values they expand to.
+///
+/// tmux doesn't keep the variables for a notification, so
+/// [`from_notify`](#method.from_notify) builds them from the client, session, window and pane
+/// it is about, along with the `hook` variables tmux sets for hooks run by the same event.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::notification_plugin;
+/// use tmux_plugin::tmux::format::FormatTree;
+///
+/// // Logs the session and window of each window which is linked to a session.
+/// notification_plugin!("window-linked", |notify_entry| {
+///     let formats = unsafe { FormatTree::from_notify(notify_entry) };
+///     eprintln!(
+///         "{} linked to {}",
+///         formats.get("hook_window_name").unwrap_or_default(),
+///         formats.get("hook_session_name").unwrap_or_default(),
+///     );
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug)]
+pub struct FormatTree(ptr::NonNull<format_tree>);
+
+impl FormatTree {
+    /// Builds the variables for a notification.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `notify_entry` passed to a notification callback.
+    pub unsafe fn from_notify(ne: *mut tmux_bindings::notify_entry) -> FormatTree {
+        let ne = &*ne;
+        let ft = tmux_bindings::format_create(
+            ne.client,
+            ptr::null_mut(),
+            tmux_bindings::FORMAT_NONE as _,
+            tmux_bindings::FORMAT_NOJOBS as _,
+        );
+        let s = if ne.session.is_null() {
+            ne.fs.s
+        } else {
+            ne.session
+        };
+        tmux_bindings::format_defaults(ft, ne.client, s, ne.fs.wl, ne.fs.wp);
+
+        let mut tree = FormatTree(ptr::NonNull::new(ft).expect("format_create never fails"));
+        tree.add("hook", &CStr::from_ptr(ne.name).to_string_lossy());
+        if ne.pane != -1 {
+            tree.add("hook_pane", &format!("%{}", ne.pane));
+        }
+        if !s.is_null() {
+            tree.add("hook_session", &format!("${}", (*s).id));
+            tree.add(
+                "hook_session_name",
+                &CStr::from_ptr((*s).name).to_string_lossy(),
+            );
+        }
+        let w = if ne.window.is_null() {
+            ne.fs.w
+        } else {
+            ne.window
+        };
+        if !w.is_null() {
+            tree.add("hook_window", &format!("@{}", (*w).id));
+            tree.add(
+                "hook_window_name",
+                &CStr::from_ptr((*w).name).to_string_lossy(),
+            );
+        }
+        tree
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(&self) -> *mut format_tree {
+        self.0.as_ptr()
+    }
+
+    /// Sets a variable, replacing any value it already has.
+    pub fn add(&mut self, key: &str, value: &str) {
+        let key = to_tmux_string_lossy(key);
+        let value = to_tmux_string_lossy(value);
+        unsafe {
+            tmux_bindings::format_add(
+                self.as_ptr(),
+                key.as_ptr(),
+                crate::cstr!("%s").as_ptr(),
+                value.as_ptr(),
+            );
+        }
+    }
+
+    /// The value of a variable, or `None` if it isn't set.
+    ///
+    /// Only variables are looked up, not options, which `#{...}` also expands to; use
+    /// [`expand`](#method.expand) for those.
+    pub fn get(&self, key: &str) -> Option<String> {
+        unsafe extern "C" fn find(key: *const c_char, value: *const c_char, arg: *mut c_void) {
+            let (wanted, found) = &mut *(arg as *mut (&[u8], Option<String>));
+            if found.is_none() && CStr::from_ptr(key).to_bytes() == *wanted {
+                *found = Some(CStr::from_ptr(value).to_string_lossy().into_owned());
+            }
+        }
+
+        let mut search = (key.as_bytes(), None);
+        unsafe {
+            tmux_bindings::format_each(
+                self.as_ptr(),
+                Some(find),
+                &mut search as *mut (&[u8], Option<String>) as *mut _,
+            );
+        }
+        search.1
+    }
+
+    /// Expands a format using these variables.
+    pub fn expand(&self, format: &CStr) -> CString {
+        unsafe {
+            let expanded = tmux_bindings::format_expand(self.as_ptr(), format.as_ptr());
+            let result = CStr::from_ptr(expanded).to_owned();
+            super::strings::free(expanded);
+            result
+        }
+    }
+}
+
+impl Drop for FormatTree {
+    fn drop(&mut self) {
+        unsafe { tmux_bindings::format_free(self.as_ptr()) }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::tmux_bindings::{self, grid, grid_cell, GRID_FLAG_PADDING, GRID_LINE_WRAPPED};
+use std::mem;
+use std::ptr;
+
+/// The text of one grid line, along with the column each character starts at.
+pub(crate) struct Line {
+    pub(crate) text: String,
+    /// The byte offset in `text` and the column of each character, in order.
+    columns: Vec<(usize, u32)>,
+}
+
+impl Line {
+    /// Converts a byte offset in the line's text to the column it is displayed at.
+    pub(crate) fn column(&self, offset: usize) -> u32 {
+        match self
+            .columns
+            .binary_search_by_key(&offset, |&(byte, _)| byte)
+        {
+            Ok(index) => self.columns[index].1,
+            Err(index) if index < self.columns.len() => self.columns[index].1,
+            Err(_) => self.columns.last().map_or(0, |&(_, column)| column + 1),
+        }
+    }
+}
+
+/// The number of lines in the grid, including its history.
+pub(crate) unsafe fn line_count(gd: *mut grid) -> u32 {
+    (*gd).hsize + (*gd).sy
+}
+
+/// The number of cells which have been written to in a line.
+pub(crate) unsafe fn cells_used(gd: *mut grid, y: u32) -> u32 {
+    let gl = tmux_bindings::grid_peek_line(gd, y);
+    if gl.is_null() {
+        0
+    } else {
+        ptr::addr_of!((*gl).cellused).read_unaligned()
+    }
+}
+
+/// Whether a line continues onto the next one because it was too long for the pane.
+pub(crate) unsafe fn is_wrapped(gd: *mut grid, y: u32) -> bool {
+    let gl = tmux_bindings::grid_peek_line(gd, y);
+    !gl.is_null() && ptr::addr_of!((*gl).flags).read_unaligned() as u32 & GRID_LINE_WRAPPED != 0
+}
+
+/// Reads a cell, where `y` counts from the oldest line of history.
+pub(crate) unsafe fn cell(gd: *mut grid, x: u32, y: u32) -> grid_cell {
+    let mut gc: grid_cell = mem::zeroed();
+    tmux_bindings::grid_get_cell(gd, x, y, &mut gc);
+    gc
+}
+
+/// Reads the text of a line, where `y` counts from the oldest line of history.
+pub(crate) unsafe fn read_line(gd: *mut grid, y: u32) -> Line {
+    let mut line = Line {
+        text: String::new(),
+        columns: Vec::new(),
+    };
+    for x in 0..cells_used(gd, y) {
+        let gc = cell(gd, x, y);
+        if u32::from(gc.flags) & GRID_FLAG_PADDING != 0 {
+            continue;
+        }
+        line.columns.push((line.text.len(), x));
+        let size = usize::from(gc.data.size).min(gc.data.data.len());
+        line.text
+            .push_str(&String::from_utf8_lossy(&gc.data.data[..size]));
+    }
+    line
+}
@@ -0,0 +1,156 @@
+use super::grid;
+use super::Pane;
+use crate::tmux_bindings::GRID_FLAG_PADDING;
+use std::borrow::Cow;
+
+impl Pane {
+    /// Iterates over the lines of the pane's scrollback history, from newest to oldest.
+    ///
+    /// This doesn't include the lines currently visible in the pane. Each line is read from
+    /// the grid as the iterator reaches it, so stopping early never reads the rest of a long
+    /// history. Lines are numbered from the oldest line of history, so the iterator should be
+    /// used up before returning to tmux, since more output in the pane will renumber them.
+    ///
+    /// For example:
+    ///
+    /// ```rust
+    /// use tmux_plugin::{cmd_plugin, tmux};
+    /// use std::ffi::CString;
+    ///
+    /// // Prints the last command prompt from before the visible part of the pane.
+    /// cmd_plugin!(b"last-prompt\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+    ///     let pane = item
+    ///         .target_client()
+    ///         .and_then(|client| client.session())
+    ///         .and_then(|session| session.current_window())
+    ///         .and_then(|window| window.active_pane());
+    ///     let prompt = pane.and_then(|pane| {
+    ///         pane.history_lines()
+    ///             .find(|line| line.text().starts_with("$ "))
+    ///     });
+    ///     if let Some(prompt) = prompt {
+    ///         item.print(&CString::new(prompt.text()).unwrap());
+    ///     }
+    ///     tmux::CmdResult::Normal
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn history_lines(&self) -> HistoryLines {
+        HistoryLines {
+            pane: *self,
+            next_line: self.history_size(),
+        }
+    }
+
+    /// The number of lines of scrollback history the pane has, not counting the visible ones.
+    ///
+    /// The alternate screen, used by full-screen programs, has no history of its own, so this
+    /// is zero while the pane [is in it](#method.in_alt_screen).
+    pub fn history_size(&self) -> u32 {
+        unsafe { (*(*self.as_ptr()).base.grid).hsize }
+    }
+
+    /// The most lines of history the pane keeps, from the `history-limit` option when the pane
+    /// was created.
+    pub fn history_limit(&self) -> u32 {
+        unsafe { (*(*self.as_ptr()).base.grid).hlimit }
+    }
+}
+
+/// An iterator over a pane's [history](./struct.Pane.html#method.history_lines).
+#[derive(Debug, Clone)]
+pub struct HistoryLines {
+    pane: Pane,
+    next_line: u32,
+}
+
+impl Iterator for HistoryLines {
+    type Item = HistoryLine;
+
+    fn next(&mut self) -> Option<HistoryLine> {
+        if self.next_line == 0 {
+            return None;
+        }
+        self.next_line -= 1;
+        let gd = unsafe { (*self.pane.as_ptr()).base.grid };
+        let text = unsafe { grid::read_line(gd, self.next_line) }.text;
+        Some(HistoryLine {
+            pane: self.pane,
+            line: self.next_line,
+            text: if text.is_empty() {
+                Cow::Borrowed("")
+            } else {
+                Cow::Owned(text)
+            },
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.next_line as usize, Some(self.next_line as usize))
+    }
+}
+
+impl ExactSizeIterator for HistoryLines {}
+
+/// A line of a pane's scrollback history.
+#[derive(Debug, Clone)]
+pub struct HistoryLine {
+    pane: Pane,
+    line: u32,
+    text: Cow<'static, str>,
+}
+
+impl HistoryLine {
+    /// The line's number, counting from the oldest line of history.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// The line's text, without any styling.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Takes the line's text. Blank lines, which are common in history, aren't allocated.
+    pub fn into_text(self) -> Cow<'static, str> {
+        self.text
+    }
+
+    /// Whether the line continues onto the next one because it was too long for the pane.
+    pub fn is_wrapped(&self) -> bool {
+        unsafe { grid::is_wrapped((*self.pane.as_ptr()).base.grid, self.line) }
+    }
+
+    /// Reads the style of each character in the line's text, in order.
+    ///
+    /// Styles are read from the grid again, so this is only worth calling for the lines
+    /// that need them.
+    pub fn styles(&self) -> Vec<CellStyle> {
+        let gd = unsafe { (*self.pane.as_ptr()).base.grid };
+        let used = unsafe { grid::cells_used(gd, self.line) };
+        (0..used)
+            .map(|x| (x, unsafe { grid::cell(gd, x, self.line) }))
+            .filter(|(_, gc)| u32::from(gc.flags) & GRID_FLAG_PADDING == 0)
+            .map(|(column, gc)| CellStyle {
+                column,
+                attributes: gc.attr,
+                fg: gc.fg,
+                bg: gc.bg,
+            })
+            .collect()
+    }
+}
+
+/// The style of one character in a [`HistoryLine`](./struct.HistoryLine.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CellStyle {
+    /// The column the character starts at. Wide characters take up more than one column.
+    pub column: u32,
+    /// The character's attributes, as a combination of the `GRID_ATTR_*` flags.
+    pub attributes: u16,
+    /// The foreground colour, in tmux's encoding, where `COLOUR_FLAG_256` or
+    /// `COLOUR_FLAG_RGB` mark colours from the 256 colour palette or 24-bit colours.
+    pub fg: i32,
+    /// The background colour, in the same encoding as `fg`.
+    pub bg: i32,
+}
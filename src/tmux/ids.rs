@@ -0,0 +1,44 @@
+use std::fmt;
+
+macro_rules! id {
+    ($(#[$attr:meta])* $name:ident, $sigil:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub u32);
+
+        impl fmt::Display for $name {
+            /// Writes the ID the way tmux does, as a target, such as
+            #[doc = concat!("`", $sigil, "1`.")]
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, concat!($sigil, "{}"), self.0)
+            }
+        }
+    };
+}
+
+id!(
+    /// A session's unique ID, which unlike its name stays the same for as long as the session
+    /// exists, and is never reused.
+    ///
+    /// ```rust
+    /// use tmux_plugin::tmux::SessionId;
+    ///
+    /// assert_eq!(SessionId(3).to_string(), "$3");
+    /// ```
+    SessionId,
+    "$"
+);
+
+id!(
+    /// A window's unique ID, which unlike its index stays the same for as long as the window
+    /// exists, in whichever sessions it is linked into, and is never reused.
+    WindowId,
+    "@"
+);
+
+id!(
+    /// A pane's unique ID, which unlike its index stays the same for as long as the pane
+    /// exists, even if it is moved to another window, and is never reused.
+    PaneId,
+    "%"
+);
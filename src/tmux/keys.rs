@@ -0,0 +1,310 @@
+//! Keys, and the tables of key bindings which say what they do.
+//!
+//! tmux looks up each key a client presses in the client's current key table, such as `root`
+//! or `prefix`, and runs the commands bound to it. [`KeyTable`](./struct.KeyTable.html) reads
+//! those tables, for showing what keys are bound to, and lets plugins create tables of their
+//! own and switch clients into them, for modes such as resizing panes with the arrow keys.
+
+use super::cmdq::{parse_commands, CommandError};
+use super::Client;
+use crate::tmux_bindings::{
+    self, key_binding, key_code, key_table, KEYC_NONE, KEYC_UNKNOWN, KEY_BINDING_REPEAT,
+};
+use std::ffi::{CStr, CString, NulError};
+use std::fmt;
+use std::os::raw::c_int;
+use std::ptr::NonNull;
+
+/// A key, along with any modifiers, such as `C-b` or `M-Left`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key(pub key_code);
+
+impl Key {
+    /// Parses a key written the way tmux writes keys, such as `C-b`, `M-Left` or `F1`.
+    pub fn parse(key: &str) -> Option<Key> {
+        let key = CString::new(key).ok()?;
+        let code = unsafe { tmux_bindings::key_string_lookup_string(key.as_ptr()) };
+        if code == KEYC_UNKNOWN || code == KEYC_NONE {
+            None
+        } else {
+            Some(Key(code))
+        }
+    }
+
+    /// The key written the way tmux writes keys.
+    pub fn name(&self) -> String {
+        unsafe {
+            CStr::from_ptr(tmux_bindings::key_string_lookup_key(self.0))
+                .to_string_lossy()
+                .into_owned()
+        }
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name())
+    }
+}
+
+impl Client {
+    /// Handles a key as if the client had pressed it.
+    ///
+    /// The key goes through the same path as one typed at the client's terminal: it is looked
+    /// up in the client's current key table, so the prefix and any bindings work as usual, and
+    /// otherwise passed to the active pane or the mode it is in. Clients which aren't attached
+    /// to a session ignore keys.
+    ///
+    /// For example, to replay a recorded sequence of keys:
+    ///
+    /// ```rust
+    /// use tmux_plugin::{cmd_plugin, tmux};
+    /// use tmux_plugin::tmux::keys::Key;
+    ///
+    /// cmd_plugin!("replay", "", "key ...", 1, -1, |_cmd, args, item| {
+    ///     if let Some(client) = item.target_client() {
+    ///         for key in args.filter_map(|arg| Key::parse(&arg.to_string_lossy())) {
+    ///             client.inject_key(key);
+    ///         }
+    ///     }
+    ///     tmux::CmdResult::Normal
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn inject_key(&self, key: Key) {
+        unsafe { tmux_bindings::server_client_handle_key(self.as_ptr(), key.0) }
+    }
+
+    /// The key table the client's next key is looked up in.
+    pub fn key_table(&self) -> Option<KeyTable> {
+        unsafe { KeyTable::from_raw((*self.as_ptr()).keytable) }
+    }
+
+    /// Switches the client to a key table, as with `switch-client -T`.
+    ///
+    /// The client goes back to its default table after the next key, unless that key is bound
+    /// with [`bind_repeating`](./struct.KeyTable.html#method.bind_repeating) or its commands
+    /// switch the client to the table again.
+    pub fn set_key_table(&self, table: KeyTable) {
+        unsafe { tmux_bindings::server_client_set_key_table(self.as_ptr(), table.name().as_ptr()) }
+    }
+}
+
+/// A table of key bindings, such as `root`, `prefix` or `copy-mode`.
+///
+/// This is a thin wrapper around a [`*mut key_table`](../struct.key_table.html), which is only
+/// valid for as long as tmux keeps the table, which in practice means for the duration of the
+/// plugin callback it was obtained in.
+///
+/// For example, to list what the keys after the prefix do:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use tmux_plugin::tmux::keys::KeyTable;
+/// use std::ffi::CString;
+///
+/// cmd_plugin!("which-key", "", "[table]", 0, 1, |_cmd, args, item| {
+///     let name = args.last().map_or("prefix".into(), |arg| arg.to_string_lossy());
+///     if let Some(table) = KeyTable::find(&name) {
+///         for binding in table.bindings() {
+///             let line = format!("{:>10} {}", binding.key(), binding.command());
+///             item.print(&CString::new(line).unwrap_or_default());
+///         }
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+///
+/// Plugins have no hook which runs when they are loaded, so a plugin's own tables are best
+/// created the first time they are needed. For example, a mode for resizing the current pane
+/// with the arrow keys, which lasts until any other key is pressed:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use tmux_plugin::tmux::keys::{Key, KeyTable};
+///
+/// cmd_plugin!("resize-mode", "", "", 0, 0, |_cmd, _args, item| {
+///     let table = KeyTable::create("resize").expect("The name has no null bytes");
+///     for (key, flag) in &[("Left", "L"), ("Right", "R"), ("Up", "U"), ("Down", "D")] {
+///         let key = Key::parse(key).expect("The key is valid");
+///         let command = format!("resize-pane -{} 5", flag);
+///         table.bind_repeating(key, &command).expect("The command is valid");
+///     }
+///     if let Some(client) = item.target_client() {
+///         client.set_key_table(table);
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyTable(NonNull<key_table>);
+
+impl KeyTable {
+    /// Wraps a raw key table pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `key_table`.
+    pub unsafe fn from_raw(ptr: *mut key_table) -> Option<KeyTable> {
+        NonNull::new(ptr).map(KeyTable)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut key_table {
+        self.0.as_ptr()
+    }
+
+    /// Finds the table with a name, if there is one.
+    pub fn find(name: &str) -> Option<KeyTable> {
+        let name = CString::new(name).ok()?;
+        unsafe { KeyTable::from_raw(tmux_bindings::key_bindings_get_table(name.as_ptr(), 0)) }
+    }
+
+    /// Finds the table with a name, creating an empty one if there isn't one.
+    ///
+    /// tmux removes a table once its last binding is removed, so a table which is created
+    /// should be bound to before the plugin's callback returns.
+    pub fn create(name: &str) -> Result<KeyTable, NulError> {
+        let name = CString::new(name)?;
+        let table = unsafe { tmux_bindings::key_bindings_get_table(name.as_ptr(), 1) };
+        Ok(unsafe { KeyTable::from_raw(table) }.expect("tmux always creates the table"))
+    }
+
+    /// Binds a key to commands in this table, replacing anything it is already bound to, as
+    /// with `bind-key -T`.
+    ///
+    /// The commands are parsed the same way as those in a configuration file, and an error is
+    /// returned if they can't be, or if there are none.
+    pub fn bind(&self, key: Key, commands: &str) -> Result<(), CommandError> {
+        self.add(key, commands, 0)
+    }
+
+    /// Binds a key which can be repeated, as with `bind-key -r`.
+    ///
+    /// After the key is pressed, the client stays in this table for the `repeat-time` option,
+    /// so it can be pressed again, or another repeating key in the table pressed, without
+    /// switching to the table again.
+    pub fn bind_repeating(&self, key: Key, commands: &str) -> Result<(), CommandError> {
+        self.add(key, commands, KEY_BINDING_REPEAT as c_int)
+    }
+
+    fn add(&self, key: Key, commands: &str, flags: c_int) -> Result<(), CommandError> {
+        let list = parse_commands(commands)?
+            .ok_or_else(|| CommandError::Parse("no commands to bind".into()))?;
+        // The binding takes ownership of the command list.
+        unsafe {
+            tmux_bindings::key_bindings_add(self.name().as_ptr(), key.0, flags, list.as_ptr())
+        }
+        Ok(())
+    }
+
+    /// Removes a key's binding from this table, as with `unbind-key -T`.
+    ///
+    /// Removing the last binding removes the table too, after which it mustn't be used.
+    pub fn unbind(&self, key: Key) {
+        unsafe { tmux_bindings::key_bindings_remove(self.name().as_ptr(), key.0) }
+    }
+
+    /// Removes this table and all its bindings, as with `unbind-key -a -T`.
+    pub fn remove(self) {
+        unsafe { tmux_bindings::key_bindings_remove_table(self.name().as_ptr()) }
+    }
+
+    /// Iterates over every key table.
+    pub fn all() -> KeyTables {
+        KeyTables(unsafe { tmux_bindings::key_bindings_first_table() })
+    }
+
+    /// The table's name.
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr((*self.as_ptr()).name) }
+    }
+
+    /// What a key is bound to in this table, if anything.
+    pub fn get(&self, key: Key) -> Option<KeyBinding> {
+        unsafe { KeyBinding::from_raw(tmux_bindings::key_bindings_get(self.as_ptr(), key.0)) }
+    }
+
+    /// Iterates over the table's bindings, in the order `list-keys` shows them.
+    pub fn bindings(&self) -> KeyBindings {
+        KeyBindings {
+            table: *self,
+            next: unsafe { tmux_bindings::key_bindings_first(self.as_ptr()) },
+        }
+    }
+}
+
+/// An iterator over the key tables, returned by
+/// [`KeyTable::all`](./struct.KeyTable.html#method.all).
+#[derive(Debug)]
+pub struct KeyTables(*mut key_table);
+
+impl Iterator for KeyTables {
+    type Item = KeyTable;
+
+    fn next(&mut self) -> Option<KeyTable> {
+        let table = unsafe { KeyTable::from_raw(self.0)? };
+        self.0 = unsafe { tmux_bindings::key_bindings_next_table(self.0) };
+        Some(table)
+    }
+}
+
+/// A key bound to commands in a [`KeyTable`](./struct.KeyTable.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyBinding(NonNull<key_binding>);
+
+impl KeyBinding {
+    /// Wraps a raw key binding pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `key_binding`.
+    pub unsafe fn from_raw(ptr: *mut key_binding) -> Option<KeyBinding> {
+        NonNull::new(ptr).map(KeyBinding)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut key_binding {
+        self.0.as_ptr()
+    }
+
+    /// The key which is bound.
+    pub fn key(&self) -> Key {
+        Key(unsafe { (*self.as_ptr()).key })
+    }
+
+    /// The commands the key runs, as `list-keys` shows them.
+    pub fn command(&self) -> String {
+        unsafe {
+            let printed = tmux_bindings::cmd_list_print((*self.as_ptr()).cmdlist);
+            let command = CStr::from_ptr(printed).to_string_lossy().into_owned();
+            super::strings::free(printed);
+            command
+        }
+    }
+
+    /// Whether the key can be repeated without pressing the prefix again, as with `bind -r`.
+    pub fn repeats(&self) -> bool {
+        unsafe { (*self.as_ptr()).flags & KEY_BINDING_REPEAT as i32 != 0 }
+    }
+}
+
+/// An iterator over a table's key bindings, returned by
+/// [`KeyTable::bindings`](./struct.KeyTable.html#method.bindings).
+#[derive(Debug)]
+pub struct KeyBindings {
+    table: KeyTable,
+    next: *mut key_binding,
+}
+
+impl Iterator for KeyBindings {
+    type Item = KeyBinding;
+
+    fn next(&mut self) -> Option<KeyBinding> {
+        let binding = unsafe { KeyBinding::from_raw(self.next)? };
+        self.next = unsafe { tmux_bindings::key_bindings_next(self.table.as_ptr(), self.next) };
+        Some(binding)
+    }
+}
@@ -0,0 +1,416 @@
+use super::{Pane, Window};
+use crate::tmux_bindings::{
+    self, layout_cell, layout_type, layout_type_LAYOUT_LEFTRIGHT, layout_type_LAYOUT_TOPBOTTOM,
+};
+use std::error;
+use std::ffi::CStr;
+use std::fmt;
+use std::ptr;
+
+/// A snapshot of how a window is divided into panes.
+///
+/// This mirrors tmux's tree of `layout_cell`s: each split divides its area between its
+/// children, either side by side or one above the other, and each pane fills a leaf.
+/// It is a copy, so it doesn't change as the window's layout does.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use tmux_plugin::tmux::Layout;
+/// use std::ffi::CString;
+///
+/// // Prints the size of the largest pane in the current window.
+/// cmd_plugin!(b"largest-pane\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+///     let layout = item
+///         .target_client()
+///         .and_then(|client| client.session())
+///         .and_then(|session| session.current_window())
+///         .and_then(|window| window.layout());
+///     let largest = layout.and_then(|layout| {
+///         layout
+///             .leaves()
+///             .into_iter()
+///             .max_by_key(|(_, geometry)| geometry.width * geometry.height)
+///     });
+///     if let Some((pane, geometry)) = largest {
+///         let message = format!("{}: {}x{}", pane.id(), geometry.width, geometry.height);
+///         item.print(&CString::new(message).unwrap());
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Layout {
+    /// A single pane.
+    Pane { pane: Pane, geometry: Geometry },
+    /// An area split between several children.
+    Split {
+        orientation: Orientation,
+        geometry: Geometry,
+        children: Vec<Layout>,
+    },
+}
+
+/// How the children of a [`Layout::Split`](./enum.Layout.html#variant.Split) are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// Side by side, from left to right.
+    LeftRight,
+    /// One above the other, from top to bottom.
+    TopBottom,
+}
+
+/// The position and size of part of a layout, in cells relative to the top left of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Geometry {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Layout {
+    /// A layout leaf for a single pane, for building a layout to
+    /// [apply](./struct.Window.html#method.apply_layout) to a window.
+    pub fn pane(pane: Pane, width: u32, height: u32) -> Layout {
+        Layout::Pane {
+            pane,
+            geometry: Geometry {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+        }
+    }
+
+    /// A layout split between several children, for building a layout to
+    /// [apply](./struct.Window.html#method.apply_layout) to a window.
+    ///
+    /// The split's size is worked out from its children's, allowing a cell for the border
+    /// between each pair of them. Positions are worked out when the layout is applied, so
+    /// only sizes matter. A split with just one child is the same as the child alone.
+    pub fn split(orientation: Orientation, mut children: Vec<Layout>) -> Layout {
+        if children.len() == 1 {
+            return children.remove(0);
+        }
+        let borders = children.len().saturating_sub(1) as u32;
+        let sizes = children.iter().map(|child| child.geometry());
+        let (width, height) = match orientation {
+            Orientation::LeftRight => (
+                sizes.clone().map(|size| size.width).sum::<u32>() + borders,
+                sizes.map(|size| size.height).max().unwrap_or(0),
+            ),
+            Orientation::TopBottom => (
+                sizes.clone().map(|size| size.width).max().unwrap_or(0),
+                sizes.map(|size| size.height).sum::<u32>() + borders,
+            ),
+        };
+        Layout::Split {
+            orientation,
+            geometry: Geometry {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+            children,
+        }
+    }
+
+    /// Copies a layout tree.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a live tmux `layout_cell`.
+    pub unsafe fn from_raw(lc: *mut layout_cell) -> Layout {
+        let geometry = Geometry {
+            x: (*lc).xoff,
+            y: (*lc).yoff,
+            width: (*lc).sx,
+            height: (*lc).sy,
+        };
+        let orientation = if (*lc).type_ == layout_type_LAYOUT_LEFTRIGHT {
+            Orientation::LeftRight
+        } else if (*lc).type_ == layout_type_LAYOUT_TOPBOTTOM {
+            Orientation::TopBottom
+        } else {
+            return Layout::Pane {
+                pane: Pane::from_raw((*lc).wp).expect("A layout leaf always has a pane"),
+                geometry,
+            };
+        };
+        let mut children = Vec::new();
+        let mut child = (*lc).cells.tqh_first;
+        while !child.is_null() {
+            children.push(Layout::from_raw(child));
+            child = (*child).entry.tqe_next;
+        }
+        Layout::Split {
+            orientation,
+            geometry,
+            children,
+        }
+    }
+
+    /// The position and size of this part of the layout.
+    pub fn geometry(&self) -> Geometry {
+        match self {
+            Layout::Pane { geometry, .. } | Layout::Split { geometry, .. } => *geometry,
+        }
+    }
+
+    /// Every pane in this part of the layout, with its geometry, from left to right and
+    /// top to bottom.
+    pub fn leaves(&self) -> Vec<(Pane, Geometry)> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(&mut leaves);
+        leaves
+    }
+
+    fn collect_leaves(&self, leaves: &mut Vec<(Pane, Geometry)>) {
+        match self {
+            Layout::Pane { pane, geometry } => leaves.push((*pane, *geometry)),
+            Layout::Split { children, .. } => {
+                for child in children {
+                    child.collect_leaves(leaves);
+                }
+            }
+        }
+    }
+}
+
+impl Orientation {
+    fn layout_type(self) -> layout_type {
+        match self {
+            Orientation::LeftRight => layout_type_LAYOUT_LEFTRIGHT,
+            Orientation::TopBottom => layout_type_LAYOUT_TOPBOTTOM,
+        }
+    }
+}
+
+/// The error returned when a layout cannot be applied to a window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The layout leaves out one of the window's panes.
+    MissingPane(Pane),
+    /// The layout includes a pane from another window, or the same pane twice.
+    UnexpectedPane(Pane),
+    /// A split has no children.
+    EmptySplit,
+    /// The sizes in the layout don't add up, or don't match the window's size.
+    BadSize,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::MissingPane(pane) => write!(f, "layout is missing pane {}", pane.id()),
+            LayoutError::UnexpectedPane(pane) => write!(f, "unexpected pane {}", pane.id()),
+            LayoutError::EmptySplit => f.write_str("empty split in layout"),
+            LayoutError::BadSize => f.write_str("size mismatch in layout"),
+        }
+    }
+}
+
+impl error::Error for LayoutError {}
+
+impl Window {
+    /// A snapshot of the window's layout.
+    ///
+    /// This is `None` only while a window is being created, before it has any panes.
+    pub fn layout(&self) -> Option<Layout> {
+        unsafe {
+            let root = (*self.as_ptr()).layout_root;
+            if root.is_null() {
+                None
+            } else {
+                Some(Layout::from_raw(root))
+            }
+        }
+    }
+
+    /// The window's layout in tmux's own notation, as shown by `#{window_layout}` and
+    /// accepted by `select-layout`.
+    pub fn layout_string(&self) -> Option<String> {
+        unsafe {
+            let root = (*self.as_ptr()).layout_root;
+            if root.is_null() {
+                return None;
+            }
+            let dump = tmux_bindings::layout_dump(root);
+            if dump.is_null() {
+                return None;
+            }
+            let layout = CStr::from_ptr(dump).to_string_lossy().into_owned();
+            super::strings::free(dump);
+            Some(layout)
+        }
+    }
+}
+
+impl Window {
+    /// Rearranges the window's panes into a layout, the same way `select-layout` does with
+    /// a layout string.
+    ///
+    /// The layout must include each of the window's panes exactly once, and must be the
+    /// same size as the window. See [`Layout::pane`](./enum.Layout.html#method.pane) and
+    /// [`Layout::split`](./enum.Layout.html#method.split) for building one.
+    pub fn apply_layout(&self, layout: &Layout) -> Result<(), LayoutError> {
+        self.check_layout(layout)?;
+        let w = self.as_ptr();
+        unsafe {
+            tmux_bindings::window_unzoom(w);
+            let old_layout = tmux_bindings::layout_dump((*w).layout_root);
+
+            // Freeing a leaf detaches its pane, so the old tree has to go before the new one
+            // is built, as in tmux's own layout_parse.
+            tmux_bindings::layout_free_cell((*w).layout_root);
+            let root = build(layout, ptr::null_mut());
+            (*w).layout_root = root;
+            tmux_bindings::layout_fix_offsets(root);
+            tmux_bindings::layout_fix_panes(w);
+
+            super::strings::free((*w).old_layout);
+            (*w).old_layout = old_layout;
+            tmux_bindings::server_redraw_window(w);
+            tmux_bindings::notify_window(crate::cstr!("window-layout-changed").as_ptr(), w);
+        }
+        Ok(())
+    }
+
+    fn check_layout(&self, layout: &Layout) -> Result<(), LayoutError> {
+        let (width, height) = self.size();
+        let geometry = layout.geometry();
+        if (geometry.width, geometry.height) != (width, height) {
+            return Err(LayoutError::BadSize);
+        }
+        check_sizes(layout)?;
+
+        let mut panes = self.panes();
+        for (pane, _) in layout.leaves() {
+            match panes.iter().position(|&p| p == pane) {
+                Some(index) => panes.swap_remove(index),
+                None => return Err(LayoutError::UnexpectedPane(pane)),
+            };
+        }
+        match panes.first() {
+            Some(&pane) => Err(LayoutError::MissingPane(pane)),
+            None => Ok(()),
+        }
+    }
+}
+
+fn check_sizes(layout: &Layout) -> Result<(), LayoutError> {
+    let (orientation, geometry, children) = match layout {
+        Layout::Pane { geometry, .. } => {
+            return if geometry.width == 0 || geometry.height == 0 {
+                Err(LayoutError::BadSize)
+            } else {
+                Ok(())
+            };
+        }
+        Layout::Split {
+            orientation,
+            geometry,
+            children,
+        } => (orientation, geometry, children),
+    };
+    if children.is_empty() {
+        return Err(LayoutError::EmptySplit);
+    }
+    let borders = children.len() as u32 - 1;
+    let sizes = children.iter().map(|child| child.geometry());
+    let fits = match orientation {
+        Orientation::LeftRight => {
+            sizes.clone().all(|size| size.height == geometry.height)
+                && sizes.map(|size| size.width).sum::<u32>() + borders == geometry.width
+        }
+        Orientation::TopBottom => {
+            sizes.clone().all(|size| size.width == geometry.width)
+                && sizes.map(|size| size.height).sum::<u32>() + borders == geometry.height
+        }
+    };
+    if !fits {
+        return Err(LayoutError::BadSize);
+    }
+    children.iter().try_for_each(check_sizes)
+}
+
+/// Builds a tree of `layout_cell`s for a layout, which must already have been checked.
+unsafe fn build(layout: &Layout, parent: *mut layout_cell) -> *mut layout_cell {
+    let lc = tmux_bindings::layout_create_cell(parent);
+    let geometry = layout.geometry();
+    tmux_bindings::layout_set_size(lc, geometry.width, geometry.height, 0, 0);
+    match layout {
+        Layout::Pane { pane, .. } => tmux_bindings::layout_make_leaf(lc, pane.as_ptr()),
+        Layout::Split {
+            orientation,
+            children,
+            ..
+        } => {
+            tmux_bindings::layout_make_node(lc, orientation.layout_type());
+            for child in children {
+                let child = build(child, lc);
+                (*child).entry.tqe_next = ptr::null_mut();
+                (*child).entry.tqe_prev = (*lc).cells.tqh_last;
+                *(*lc).cells.tqh_last = child;
+                (*lc).cells.tqh_last = &mut (*child).entry.tqe_next;
+            }
+        }
+    }
+    lc
+}
+
+#[cfg(all(test, feature = "stub"))]
+mod tests {
+    use super::*;
+    use crate::tmux_bindings::{window, window_pane};
+    use std::mem;
+
+    #[test]
+    fn apply_layout_leaves_panes_in_the_new_tree() {
+        unsafe {
+            let mut w: window = mem::zeroed();
+            let mut wps: [window_pane; 2] = mem::zeroed();
+            (w.sx, w.sy) = (81, 24);
+            w.panes.tqh_first = &mut wps[0];
+            wps[0].entry.tqe_next = &mut wps[1];
+            let window = Window::from_raw(&mut w).unwrap();
+            let left = Pane::from_raw(&mut wps[0]).unwrap();
+            let right = Pane::from_raw(&mut wps[1]).unwrap();
+
+            let before = Layout::split(
+                Orientation::LeftRight,
+                vec![Layout::pane(left, 40, 24), Layout::pane(right, 40, 24)],
+            );
+            w.layout_root = build(&before, ptr::null_mut());
+            let after = Layout::split(
+                Orientation::LeftRight,
+                vec![Layout::pane(right, 20, 24), Layout::pane(left, 60, 24)],
+            );
+            window.apply_layout(&after).unwrap();
+
+            let root = w.layout_root;
+            let mut cells = Vec::new();
+            let mut lc = (*root).cells.tqh_first;
+            while !lc.is_null() {
+                cells.push(lc);
+                lc = (*lc).entry.tqe_next;
+            }
+            assert_eq!(cells.len(), 2);
+            for wp in &wps {
+                assert!(!wp.layout_cell.is_null());
+                assert!(cells.contains(&wp.layout_cell));
+                assert_eq!((*wp.layout_cell).parent, root);
+            }
+            assert_eq!((*cells[0]).wp, right.as_ptr());
+            assert_eq!((*cells[1]).wp, left.as_ptr());
+
+            tmux_bindings::layout_free_cell(root);
+            super::super::strings::free(w.old_layout);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//! Counting what the plugin hands over to tmux, to find leaks while developing it.
+//!
+//! This is only built with the `leak-debug` feature. With it, every
+//! [`OwnedTmuxString`](../strings/struct.OwnedTmuxString.html) and every value boxed with
+//! [`boxed::into_raw`](../boxed/fn.into_raw.html) is counted as it is made, handed over and
+//! dropped. A number of boxes which keeps growing as the plugin is used, for instance, points
+//! at data tmux was never given a destructor for.
+//!
+//! The counts can be shown in tmux with a format variable, such as:
+//!
+//! ```rust
+//! # #[cfg(feature = "leak-debug")]
+//! # mod example {
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::leaks;
+//!
+//! // Shown with `display-message '#{plugin_leaks}'`.
+//! format_plugin!("plugin_leaks", leaks::variable);
+//! # }
+//! # fn main() {}
+//! ```
+
+use crate::tmux_bindings::format_tree;
+use std::ffi::CString;
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static STRINGS_COPIED: AtomicUsize = AtomicUsize::new(0);
+static STRINGS_HANDED_OFF: AtomicUsize = AtomicUsize::new(0);
+static STRINGS_DROPPED: AtomicUsize = AtomicUsize::new(0);
+static BOXES_CREATED: AtomicUsize = AtomicUsize::new(0);
+static BOXES_DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+/// How many strings and boxes the plugin has made, handed over to tmux and dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Counts {
+    /// Strings copied for tmux.
+    pub strings_copied: usize,
+    /// Strings handed over to tmux, for tmux to free.
+    pub strings_handed_off: usize,
+    /// Strings dropped without being handed over.
+    pub strings_dropped: usize,
+    /// Values boxed to hand to tmux.
+    pub boxes_created: usize,
+    /// Boxed values dropped, by tmux calling their destructor.
+    pub boxes_dropped: usize,
+}
+
+impl Counts {
+    /// Strings which have been copied, but neither handed over nor dropped yet.
+    pub fn strings_outstanding(&self) -> usize {
+        self.strings_copied - self.strings_handed_off - self.strings_dropped
+    }
+
+    /// Boxed values which haven't been dropped yet.
+    pub fn boxes_live(&self) -> usize {
+        self.boxes_created - self.boxes_dropped
+    }
+}
+
+impl fmt::Display for Counts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "strings: {} copied, {} handed off, {} dropped, {} outstanding; \
+             boxes: {} created, {} dropped, {} live",
+            self.strings_copied,
+            self.strings_handed_off,
+            self.strings_dropped,
+            self.strings_outstanding(),
+            self.boxes_created,
+            self.boxes_dropped,
+            self.boxes_live(),
+        )
+    }
+}
+
+/// The counts so far.
+pub fn counts() -> Counts {
+    Counts {
+        strings_copied: STRINGS_COPIED.load(Ordering::Relaxed),
+        strings_handed_off: STRINGS_HANDED_OFF.load(Ordering::Relaxed),
+        strings_dropped: STRINGS_DROPPED.load(Ordering::Relaxed),
+        boxes_created: BOXES_CREATED.load(Ordering::Relaxed),
+        boxes_dropped: BOXES_DROPPED.load(Ordering::Relaxed),
+    }
+}
+
+/// A format variable's callback showing the counts, for
+/// [`format_plugin!`](../../macro.format_plugin.html).
+pub fn variable(_ft: *mut format_tree) -> CString {
+    CString::new(counts().to_string()).expect("The counts have no null bytes")
+}
+
+pub(crate) fn string_copied() {
+    STRINGS_COPIED.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn string_handed_off() {
+    STRINGS_HANDED_OFF.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn string_dropped() {
+    STRINGS_DROPPED.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn box_created() {
+    BOXES_CREATED.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn box_dropped() {
+    BOXES_DROPPED.fetch_add(1, Ordering::Relaxed);
+}
@@ -0,0 +1,100 @@
+//! Facilities only newer builds of the fork have, which return an error where they're
+//! missing rather than stopping the plugin from loading.
+//!
+//! Each function here looks up what it needs from tmux with a
+//! [`Symbol`](../struct.Symbol.html) the first time it is called, and returns
+//! [`Unsupported`](./struct.Unsupported.html) if the build of tmux the plugin was loaded into
+//! doesn't have it. A plugin can check once and leave out whatever depends on it:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::{borders, maybe};
+//! use std::ffi::CString;
+//!
+//! // The word under the cursor, or nothing with an older tmux.
+//! format_plugin!("cursor_word", |format_tree| {
+//!     let word = unsafe { borders::pane(format_tree) }
+//!         .map(|pane| maybe::word_at(pane, pane.cursor()))
+//!         .and_then(|word| word.ok().flatten());
+//!     CString::new(word.unwrap_or_default()).unwrap_or_default()
+//! });
+//! # fn main() {}
+//! ```
+//!
+//! Other functions can be wrapped the same way, with [`Symbol::require`].
+//!
+//! [`Symbol::require`]: ../struct.Symbol.html#method.require
+
+use super::{Pane, Symbol};
+use crate::tmux_bindings::grid;
+use std::error;
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+
+/// The error returned when the build of tmux the plugin was loaded into doesn't have a
+/// function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Unsupported {
+    name: &'static str,
+}
+
+impl Unsupported {
+    /// The error for a function tmux doesn't have.
+    pub fn new(name: &'static str) -> Unsupported {
+        Unsupported { name }
+    }
+
+    /// The name of the function tmux doesn't have.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl fmt::Display for Unsupported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "this version of tmux has no {}", self.name)
+    }
+}
+
+impl error::Error for Unsupported {}
+
+type GridWord = unsafe extern "C" fn(gd: *mut grid, x: u32, y: u32) -> *mut c_char;
+type GridLine = unsafe extern "C" fn(gd: *mut grid, y: u32) -> *mut c_char;
+
+static FORMAT_GRID_WORD: Symbol<GridWord> = unsafe { Symbol::new("format_grid_word") };
+static FORMAT_GRID_LINE: Symbol<GridLine> = unsafe { Symbol::new("format_grid_line") };
+
+/// The word at a position in the visible part of a pane, as a column and a row counting from
+/// zero, as `#{mouse_word}` is, or `None` if there is no word there.
+///
+/// This needs `format_grid_word`, from tmux 3.1.
+pub fn word_at(pane: Pane, (x, y): (u32, u32)) -> Result<Option<String>, Unsupported> {
+    let format_grid_word = FORMAT_GRID_WORD.require()?;
+    unsafe {
+        let gd = (*pane.as_ptr()).base.grid;
+        Ok(take(format_grid_word(gd, x, (*gd).hsize + y)))
+    }
+}
+
+/// The text of a row of the visible part of a pane, counting from zero, as `#{mouse_line}`
+/// is, or `None` if the row is empty.
+///
+/// This needs `format_grid_line`, from tmux 3.1.
+pub fn line_at(pane: Pane, y: u32) -> Result<Option<String>, Unsupported> {
+    let format_grid_line = FORMAT_GRID_LINE.require()?;
+    unsafe {
+        let gd = (*pane.as_ptr()).base.grid;
+        Ok(take(format_grid_line(gd, (*gd).hsize + y)))
+    }
+}
+
+/// Copies and frees a string tmux returned, if it returned one.
+unsafe fn take(s: *mut c_char) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let string = CStr::from_ptr(s).to_string_lossy().into_owned();
+    super::strings::free(s);
+    Some(string)
+}
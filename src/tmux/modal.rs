@@ -0,0 +1,373 @@
+//! Modes built from key tables, such as a mode for resizing panes with the arrow keys.
+//!
+//! A [`Mode`](./struct.Mode.html) is a key table of the plugin's own, along with closures to
+//! run for its keys, some text shown on the status line while a client is in the mode, and how
+//! long the mode lasts without a key being pressed. Once it is
+//! [installed](./struct.Mode.html#method.install), [`enter`](./fn.enter.html) switches a client
+//! into it. The client stays in the mode after each of its keys, until a key returns
+//! [`Next::Exit`](./enum.Next.html#variant.Exit), a key the mode doesn't bind is pressed, the
+//! mode times out, or [`exit`](./fn.exit.html) is called.
+
+use super::cmdq::{parse_commands, CommandError};
+use super::keys::{Key, KeyTable};
+use super::status::Message;
+use super::{timer, Args, Client, CmdqItem};
+use crate::tmux_bindings::{
+    self, cmd, cmd_entry, cmd_entry__bindgen_ty_1, cmd_entry_flag, cmd_list, cmd_retval,
+    cmd_retval_CMD_RETURN_NORMAL, cmdq_item,
+};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString, NulError};
+use std::mem;
+use std::os::raw::{c_char, c_int};
+use std::ptr;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// What a client does after one of a mode's keys has been handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Next {
+    /// The client stays in the mode.
+    Stay,
+    /// The client leaves the mode, going back to its default key table.
+    Exit,
+}
+
+type Handler = Rc<RefCell<Box<dyn FnMut(Client) -> Next>>>;
+
+enum Binding {
+    Handler(Handler),
+    Commands(String),
+}
+
+/// A mode which clients can be switched into.
+///
+/// For example, a mode for resizing the current pane with the arrow keys, leaving it with
+/// enter:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, cstr, tmux};
+/// use tmux_plugin::tmux::keys::Key;
+/// use tmux_plugin::tmux::modal::{self, Mode, Next};
+/// use std::time::Duration;
+///
+/// cmd_plugin!("resize-mode", "", "", 0, 0, |_cmd, _args, item| {
+///     if !modal::is_installed("resize") {
+///         let indicator = cstr!("-- RESIZE --");
+///         let mut mode = Mode::new("resize")
+///             .expect("The name has no null bytes")
+///             .indicator(indicator)
+///             .timeout(Duration::from_secs(5))
+///             .key(Key::parse("Enter").unwrap(), |_client| Next::Exit);
+///         for (key, flag) in &[("Left", "L"), ("Right", "R"), ("Up", "U"), ("Down", "D")] {
+///             let command = format!("resize-pane -{} 5", flag);
+///             mode = mode.command(Key::parse(key).unwrap(), &command);
+///         }
+///         mode.install().expect("The commands are valid");
+///     }
+///     if let Some(client) = item.target_client() {
+///         modal::enter(client, "resize");
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+pub struct Mode {
+    name: CString,
+    indicator: Option<CString>,
+    timeout: Option<Duration>,
+    bindings: Vec<(Key, Binding)>,
+}
+
+impl Mode {
+    /// Creates a mode with no keys, using the key table with the same name.
+    pub fn new(name: &str) -> Result<Mode, NulError> {
+        Ok(Mode {
+            name: CString::new(name)?,
+            indicator: None,
+            timeout: None,
+            bindings: Vec::new(),
+        })
+    }
+
+    /// Sets the text shown on the status line of clients in the mode, such as `-- RESIZE --`.
+    ///
+    /// The text is shown as a message, so it is hidden by a key press and shown again after
+    /// each of the mode's keys.
+    pub fn indicator(mut self, text: &CStr) -> Mode {
+        self.indicator = Some(text.to_owned());
+        self
+    }
+
+    /// Sets how long a client stays in the mode without pressing one of its keys.
+    ///
+    /// By default, clients stay in the mode until they leave it.
+    pub fn timeout(mut self, timeout: Duration) -> Mode {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Runs a closure when a key is pressed in the mode, replacing anything already bound to
+    /// it. The closure is passed the client which pressed the key, and says whether it stays
+    /// in the mode.
+    pub fn key<F>(mut self, key: Key, handler: F) -> Mode
+    where
+        F: FnMut(Client) -> Next + 'static,
+    {
+        let handler: Handler = Rc::new(RefCell::new(Box::new(handler)));
+        self.bindings.push((key, Binding::Handler(handler)));
+        self
+    }
+
+    /// Runs tmux commands when a key is pressed in the mode, replacing anything already bound
+    /// to it. The client stays in the mode after them.
+    pub fn command(mut self, key: Key, commands: &str) -> Mode {
+        self.bindings
+            .push((key, Binding::Commands(commands.to_owned())));
+        self
+    }
+
+    /// Installs the mode, replacing any installed mode or key table with the same name.
+    ///
+    /// Clients in a table which is replaced go back to their default key table. If any of
+    /// the commands can't be parsed, nothing is installed.
+    pub fn install(self) -> Result<(), CommandError> {
+        let Mode {
+            name,
+            indicator,
+            timeout,
+            bindings,
+        } = self;
+        let table = unsafe { tmux_bindings::key_bindings_get_table(name.as_ptr(), 0) };
+        if let Some(table) = unsafe { KeyTable::from_raw(table) } {
+            table.remove();
+        }
+        let mut handlers = HashMap::new();
+        let mut lists = Vec::new();
+        for (key, binding) in bindings {
+            let list = match binding {
+                Binding::Handler(handler) => {
+                    handlers.insert(key, handler);
+                    unsafe { new_list() }
+                }
+                Binding::Commands(commands) => match parse_commands(&commands) {
+                    Ok(list) => list.map_or_else(|| unsafe { new_list() }, |list| list.as_ptr()),
+                    Err(err) => {
+                        for (_, list) in lists {
+                            unsafe { tmux_bindings::cmd_list_free(list) };
+                        }
+                        return Err(err);
+                    }
+                },
+            };
+            unsafe { append(list, &name, key) };
+            lists.push((key, list));
+        }
+        for (key, list) in lists {
+            // The binding takes ownership of the command list.
+            unsafe { tmux_bindings::key_bindings_add(name.as_ptr(), key.0, 0, list) };
+        }
+
+        let state = State {
+            indicator,
+            timeout,
+            handlers,
+        };
+        MODES.with(|modes| modes.borrow_mut().insert(name, Rc::new(state)));
+        Ok(())
+    }
+}
+
+/// Whether a mode with a name has been installed.
+pub fn is_installed(name: &str) -> bool {
+    CString::new(name).is_ok_and(|name| MODES.with(|modes| modes.borrow().contains_key(&name)))
+}
+
+/// Switches a client into an installed mode, returning `false` if there is no such mode.
+pub fn enter(client: Client, name: &str) -> bool {
+    let name = match CString::new(name) {
+        Ok(name) => name,
+        Err(_) => return false,
+    };
+    match MODES.with(|modes| modes.borrow().get(&name).cloned()) {
+        Some(state) => {
+            unsafe { switch(client, &name, &state) };
+            true
+        }
+        None => false,
+    }
+}
+
+/// Switches a client out of the mode it is in, if any, back to its default key table.
+pub fn exit(client: Client) {
+    if let Some(name) = current(client) {
+        let indicator = MODES.with(|modes| {
+            let modes = modes.borrow();
+            modes
+                .get(name.as_c_str())
+                .and_then(|state| state.indicator.clone())
+        });
+        unsafe { leave(client, indicator.as_deref()) };
+    }
+}
+
+/// The name of the installed mode a client is in, if any.
+pub fn current(client: Client) -> Option<CString> {
+    let table = client.key_table()?;
+    MODES.with(|modes| {
+        let modes = modes.borrow();
+        modes
+            .get_key_value(table.name())
+            .map(|(name, _)| name.clone())
+    })
+}
+
+struct State {
+    indicator: Option<CString>,
+    timeout: Option<Duration>,
+    handlers: HashMap<Key, Handler>,
+}
+
+thread_local! {
+    /// The installed modes, by name.
+    static MODES: RefCell<HashMap<CString, Rc<State>>> = RefCell::new(HashMap::new());
+    /// How many times clients have been switched into or out of a mode, to tell whether a
+    /// timeout is still the latest one.
+    static SWITCHES: Cell<u64> = const { Cell::new(0) };
+}
+
+unsafe fn switch(client: Client, name: &CStr, state: &State) {
+    let c = client.as_ptr();
+    tmux_bindings::server_client_set_key_table(c, name.as_ptr());
+    tmux_bindings::server_status_client(c);
+    if let Some(indicator) = &state.indicator {
+        Message::new(indicator)
+            .duration(Duration::from_secs(0))
+            .show(client)
+            .expect("An unstyled message cannot have an invalid style");
+    }
+
+    let switches = SWITCHES.with(|switches| {
+        switches.set(switches.get().wrapping_add(1));
+        switches.get()
+    });
+    if let Some(timeout) = state.timeout {
+        (*c).references += 1;
+        let name = name.to_owned();
+        let indicator = state.indicator.clone();
+        timer::after(timeout, move || {
+            let current = current(client).is_some_and(|current| current == name);
+            if current && SWITCHES.with(Cell::get) == switches {
+                leave(client, indicator.as_deref());
+            }
+            tmux_bindings::server_client_unref(c);
+        });
+    }
+}
+
+unsafe fn leave(client: Client, indicator: Option<&CStr>) {
+    let c = client.as_ptr();
+    SWITCHES.with(|switches| switches.set(switches.get().wrapping_add(1)));
+    tmux_bindings::server_client_set_key_table(c, ptr::null());
+    tmux_bindings::server_status_client(c);
+    let message = (*c).message_string;
+    if indicator.is_some_and(|indicator| !message.is_null() && CStr::from_ptr(message) == indicator)
+    {
+        tmux_bindings::status_message_clear(c);
+    }
+}
+
+struct Entry(cmd_entry);
+
+// The entry's strings are never written to.
+unsafe impl Sync for Entry {}
+
+/// The command which runs a mode's closures, and keeps the client in the mode, at the end of
+/// each of the mode's bindings.
+static ENTRY: Entry = Entry(cmd_entry {
+    name: crate::cstr!("mode-key").as_ptr(),
+    alias: ptr::null(),
+    args: cmd_entry__bindgen_ty_1 {
+        template: crate::cstr!("").as_ptr(),
+        lower: 2,
+        upper: 2,
+    },
+    usage: crate::cstr!("mode key").as_ptr(),
+    source: cmd_entry_flag {
+        flag: 0,
+        type_: 0,
+        flags: 0,
+    },
+    target: cmd_entry_flag {
+        flag: 0,
+        type_: 0,
+        flags: 0,
+    },
+    flags: 0,
+    exec: Some(exec),
+});
+
+/// Allocates an empty command list, as tmux's own parser would.
+unsafe fn new_list() -> *mut cmd_list {
+    let list = tmux_bindings::xcalloc(1, mem::size_of::<cmd_list>()) as *mut cmd_list;
+    (*list).references = 1;
+    (*list).list.tqh_last = &mut (*list).list.tqh_first;
+    list
+}
+
+/// Appends the mode's own command for a key to the end of a command list.
+unsafe fn append(list: *mut cmd_list, mode: &CStr, key: Key) {
+    let key = CString::new(key.name()).expect("Key names have no null bytes");
+    let mut argv = [ENTRY.0.name, mode.as_ptr(), key.as_ptr()];
+    let cmd = tmux_bindings::xcalloc(1, mem::size_of::<cmd>()) as *mut cmd;
+    (*cmd).entry = &ENTRY.0;
+    (*cmd).args = tmux_bindings::args_parse(
+        ENTRY.0.args.template,
+        argv.len() as c_int,
+        argv.as_mut_ptr() as *mut *mut c_char,
+    );
+    (*cmd).qentry.tqe_next = ptr::null_mut();
+    (*cmd).qentry.tqe_prev = (*list).list.tqh_last;
+    *(*list).list.tqh_last = cmd;
+    (*list).list.tqh_last = &mut (*cmd).qentry.tqe_next;
+}
+
+unsafe extern "C" fn exec(cmd: *mut cmd, item: *mut cmdq_item) -> cmd_retval {
+    let args = match Args::from_cmd(cmd) {
+        Some(args) => args,
+        None => return cmd_retval_CMD_RETURN_NORMAL,
+    };
+    let (name, key) = match (args.positional(0), args.positional(1)) {
+        (Some(name), Some(key)) => (name, Key::parse(&key.to_string_lossy())),
+        _ => return cmd_retval_CMD_RETURN_NORMAL,
+    };
+    let client = match CmdqItem::from_raw(item).and_then(|item| item.client()) {
+        Some(client) => client,
+        None => return cmd_retval_CMD_RETURN_NORMAL,
+    };
+    let state = match MODES.with(|modes| modes.borrow().get(name).cloned()) {
+        Some(state) => state,
+        None => return cmd_retval_CMD_RETURN_NORMAL,
+    };
+
+    let handler = key.and_then(|key| state.handlers.get(&key).cloned());
+    let table = client.key_table();
+    let next = match handler {
+        Some(handler) => match handler.try_borrow_mut() {
+            Ok(mut handler) => handler(client),
+            Err(_) => Next::Stay,
+        },
+        None => Next::Stay,
+    };
+    if client.key_table() != table {
+        // The closure switched the client to another table itself.
+        return cmd_retval_CMD_RETURN_NORMAL;
+    }
+    match next {
+        // tmux has already put the client back in its default table for this key.
+        Next::Stay => switch(client, name, &state),
+        Next::Exit => leave(client, state.indicator.as_deref()),
+    }
+    cmd_retval_CMD_RETURN_NORMAL
+}
@@ -0,0 +1,199 @@
+use super::Colour;
+use crate::tmux_bindings::{self, options, options_entry};
+use std::ffi::{CStr, CString};
+use std::ptr::NonNull;
+
+/// A set of tmux options, such as the global session options or a window's options.
+///
+/// This is a thin wrapper around a [`*mut options`](./struct.options.html). Options which
+/// aren't set in a set are looked up in its parent, the same way tmux does, so the options
+/// of a window include the global window options it hasn't overridden.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options(NonNull<options>);
+
+impl Options {
+    /// Wraps a raw options pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `options`.
+    pub unsafe fn from_raw(ptr: *mut options) -> Option<Options> {
+        NonNull::new(ptr).map(Options)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut options {
+        self.0.as_ptr()
+    }
+
+    /// An option's value, as `show-options` would show it. Array options are joined with
+    /// spaces.
+    ///
+    /// Returns `None` if the option isn't set here or in any parent.
+    pub fn get(&self, name: &str) -> Option<String> {
+        self.value(name, false)
+    }
+
+    /// A numeric option's value. Flags are `0` or `1`, and choices are the index of the choice.
+    ///
+    /// Returns `None` if the option isn't set or isn't a number.
+    pub fn get_number(&self, name: &str) -> Option<i64> {
+        self.value(name, true)?.parse().ok()
+    }
+
+    /// The values of the options set directly in this set, ignoring its parent.
+    pub(crate) fn values(&self) -> Vec<String> {
+        let mut values = Vec::new();
+        unsafe {
+            let mut o = tmux_bindings::options_first(self.as_ptr());
+            while !o.is_null() {
+                let value = tmux_bindings::options_tostring(o, -1, 0);
+                if !value.is_null() {
+                    values.push(CStr::from_ptr(value).to_string_lossy().into_owned());
+                    super::strings::free(value);
+                }
+                o = tmux_bindings::options_next(o);
+            }
+        }
+        values
+    }
+
+    fn entry(&self, name: &str) -> Option<*mut options_entry> {
+        let name = CString::new(name).ok()?;
+        let o = unsafe { tmux_bindings::options_get(self.as_ptr(), name.as_ptr()) };
+        if o.is_null() {
+            None
+        } else {
+            Some(o)
+        }
+    }
+
+    fn value(&self, name: &str, numeric: bool) -> Option<String> {
+        let o = self.entry(name)?;
+        unsafe {
+            let value = tmux_bindings::options_tostring(o, -1, numeric as _);
+            if value.is_null() {
+                return None;
+            }
+            let string = CStr::from_ptr(value).to_string_lossy().into_owned();
+            super::strings::free(value);
+            Some(string)
+        }
+    }
+}
+
+/// A plugin's configuration, read from `@`-prefixed user options.
+///
+/// This is usually derived, with `#[derive(PluginOptions)]` from the `derive` feature. Each
+/// field is read from the user option named after it, with the plugin's prefix in front and
+/// underscores turned into dashes, so the field `refresh_interval` of a plugin with the
+/// prefix `weather` is set with `set -g @weather-refresh-interval 10`. The prefix defaults to
+/// the crate's name, and can be set with `#[plugin_options(prefix = "...")]`.
+///
+/// Fields can be any [`OptionValue`](./trait.OptionValue.html). A field whose option isn't set,
+/// or is set to something that can't be parsed, takes the value of its
+/// `#[option(default = "...")]`, which is parsed the same way as the option would be, or else
+/// its type's `Default`, which is also used if the default itself can't be parsed. The option
+/// of a field can be renamed with `#[option(name = "...")]`.
+///
+/// For example:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # mod example {
+/// use tmux_plugin::tmux::{self, Colour, PluginOptions as _};
+/// use tmux_plugin::{format_plugin, PluginOptions};
+/// use std::cell::RefCell;
+/// use std::ffi::CString;
+///
+/// #[derive(PluginOptions)]
+/// #[plugin_options(prefix = "clock")]
+/// struct Options {
+///     /// Set with `@clock-twelve-hour`.
+///     twelve_hour: bool,
+///     /// Set with `@clock-colour`.
+///     #[option(default = "green")]
+///     colour: Colour,
+///     /// Set with `@clock-label`.
+///     #[option(default = "Time")]
+///     label: String,
+/// }
+///
+/// thread_local! {
+///     static OPTIONS: RefCell<Option<Options>> = RefCell::new(None);
+/// }
+///
+/// format_plugin!(b"clock_label\0", |_format_tree| {
+///     OPTIONS.with(|options| {
+///         let mut options = options.borrow_mut();
+///         let options = options.get_or_insert_with(Options::load);
+///         // Pick up any changes made with `set-option` since the last time.
+///         options.refresh();
+///         let format = tmux::format::Format::new().styled(
+///             &format!("fg={}", options.colour),
+///             tmux::format::Format::new().text(&options.label),
+///         );
+///         format.into_c_string()
+///     })
+/// });
+/// # }
+/// # fn main() {}
+/// ```
+pub trait PluginOptions: Sized {
+    /// The names of the user options the configuration is read from, such as
+    /// `@clock-twelve-hour`.
+    const NAMES: &'static [&'static str];
+
+    /// Reads the configuration from the global session options.
+    fn load() -> Self;
+
+    /// Reads the configuration again, returning whether anything changed.
+    ///
+    /// tmux doesn't notify plugins when options are set, so this should be called whenever
+    /// the configuration is about to be used. It only looks up the plugin's own options, so
+    /// it is cheap enough to call every time a format is expanded.
+    fn refresh(&mut self) -> bool;
+}
+
+/// A type which can be read from a tmux option, for use in
+/// [`PluginOptions`](./trait.PluginOptions.html).
+pub trait OptionValue: Sized + PartialEq {
+    /// Parses an option's value, returning `None` if it isn't valid.
+    fn parse(value: &str) -> Option<Self>;
+}
+
+impl OptionValue for bool {
+    /// Accepts `on`, `yes`, `true` and `1`, or `off`, `no`, `false` and `0`.
+    fn parse(value: &str) -> Option<bool> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "on" | "yes" | "true" | "1" => Some(true),
+            "off" | "no" | "false" | "0" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl OptionValue for u32 {
+    fn parse(value: &str) -> Option<u32> {
+        value.trim().parse().ok()
+    }
+}
+
+impl OptionValue for i64 {
+    fn parse(value: &str) -> Option<i64> {
+        value.trim().parse().ok()
+    }
+}
+
+impl OptionValue for String {
+    fn parse(value: &str) -> Option<String> {
+        Some(value.to_owned())
+    }
+}
+
+impl OptionValue for Colour {
+    /// Accepts any colour tmux does, such as `red`, `colour235` or `#ff8700`.
+    fn parse(value: &str) -> Option<Colour> {
+        Colour::parse(value.trim())
+    }
+}
@@ -0,0 +1,337 @@
+use super::spawn::{c_string, Spawn, SpawnError};
+use super::{PaneId, Window};
+use crate::tmux_bindings::{
+    self, layout_type, layout_type_LAYOUT_LEFTRIGHT, layout_type_LAYOUT_TOPBOTTOM, window_pane,
+    PANE_REDRAW, SPAWN_BEFORE, SPAWN_KILL, SPAWN_RESPAWN,
+};
+use std::ffi::{CStr, OsStr};
+use std::fs;
+use std::os::raw::{c_char, c_int};
+use std::os::unix::ffi::OsStrExt;
+use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+
+/// A tmux pane.
+///
+/// This is a thin wrapper around a [`*mut window_pane`](./struct.window_pane.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pane(NonNull<window_pane>);
+
+/// Where a new pane is placed relative to the pane it was split from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// The size of a new pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitSize {
+    /// A number of cells.
+    Cells(u32),
+    /// A percentage of the pane being split.
+    Percent(u32),
+}
+
+impl Pane {
+    /// Wraps a raw pane pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `window_pane`.
+    pub unsafe fn from_raw(ptr: *mut window_pane) -> Option<Pane> {
+        NonNull::new(ptr).map(Pane)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut window_pane {
+        self.0.as_ptr()
+    }
+
+    /// Finds a pane by its unique ID.
+    pub fn find_by_id(id: PaneId) -> Option<Pane> {
+        unsafe { Pane::from_raw(tmux_bindings::window_pane_find_by_id(id.0)) }
+    }
+
+    /// The pane's unique ID, as used in `%` targets.
+    pub fn id(&self) -> PaneId {
+        PaneId(unsafe { (*self.as_ptr()).id })
+    }
+
+    /// The window this pane is in.
+    pub fn window(&self) -> Window {
+        unsafe { Window::from_raw((*self.as_ptr()).window) }
+            .expect("A pane always belongs to a window")
+    }
+
+    /// The pane's width and height, in cells.
+    pub fn size(&self) -> (u32, u32) {
+        unsafe { ((*self.as_ptr()).sx, (*self.as_ptr()).sy) }
+    }
+
+    /// The position of the cursor in the pane, as a column and a row of the visible part of the
+    /// pane, counting from zero.
+    ///
+    /// This is where the program in the pane left the cursor, even while the pane is in copy
+    /// mode.
+    pub fn cursor(&self) -> (u32, u32) {
+        unsafe { ((*self.as_ptr()).base.cx, (*self.as_ptr()).base.cy) }
+    }
+
+    /// Whether the program in the pane has switched to the alternate screen, as full-screen
+    /// programs such as editors and pagers do.
+    pub fn in_alt_screen(&self) -> bool {
+        unsafe { !(*self.as_ptr()).saved_grid.is_null() }
+    }
+
+    /// The pane's current working directory.
+    ///
+    /// This is the directory of the process in the foreground of the pane's terminal, the same
+    /// as `#{pane_current_path}`, so it follows `cd` in the pane's shell. Where tmux can't look
+    /// that up, it is read from `/proc` for the pane's first process instead. If neither works,
+    /// as once the pane is dead, this is the directory the pane was started in.
+    pub fn cwd(&self) -> Option<PathBuf> {
+        let wp = self.as_ptr();
+        unsafe {
+            if (*wp).fd != -1 {
+                // This points to a static buffer in tmux, so isn't freed.
+                let cwd = tmux_bindings::osdep_get_cwd((*wp).fd);
+                if !cwd.is_null() {
+                    return Some(path_from(CStr::from_ptr(cwd)));
+                }
+                if let Ok(cwd) = fs::read_link(format!("/proc/{}/cwd", (*wp).pid)) {
+                    return Some(cwd);
+                }
+            }
+            if (*wp).cwd.is_null() {
+                None
+            } else {
+                Some(path_from(CStr::from_ptr((*wp).cwd)))
+            }
+        }
+    }
+
+    /// Whether the process running in the pane has exited, leaving the pane dead.
+    ///
+    /// Panes only stay around once dead if the `remain-on-exit` option is on.
+    pub fn is_dead(&self) -> bool {
+        unsafe { (*self.as_ptr()).fd == -1 }
+    }
+
+    /// Splits the pane, starting the default shell in the new pane and making it the
+    /// active pane.
+    ///
+    /// This works like the `split-window` command: `SplitDirection::Right` is the same as
+    /// `split-window -h`, and `SplitDirection::Up` is the same as `split-window -vb`.
+    /// Without a size, the pane is split in half.
+    pub fn split(
+        &self,
+        direction: SplitDirection,
+        size: Option<SplitSize>,
+    ) -> Result<Pane, SpawnError> {
+        let wp = self.as_ptr();
+        let (type_, flags): (layout_type, u32) = match direction {
+            SplitDirection::Left => (layout_type_LAYOUT_LEFTRIGHT, SPAWN_BEFORE),
+            SplitDirection::Right => (layout_type_LAYOUT_LEFTRIGHT, 0),
+            SplitDirection::Up => (layout_type_LAYOUT_TOPBOTTOM, SPAWN_BEFORE),
+            SplitDirection::Down => (layout_type_LAYOUT_TOPBOTTOM, 0),
+        };
+        let size = match size {
+            None => -1,
+            Some(SplitSize::Cells(cells)) => cells as c_int,
+            Some(SplitSize::Percent(percent)) => {
+                let (sx, sy) = self.size();
+                let current = if type_ == layout_type_LAYOUT_LEFTRIGHT {
+                    sx
+                } else {
+                    sy
+                };
+                (current * percent / 100) as c_int
+            }
+        };
+
+        unsafe {
+            let w = (*wp).window;
+            let wl = (*w).winlinks.tqh_first;
+            if wl.is_null() {
+                return Err(SpawnError("window is not linked to a session".to_owned()));
+            }
+            tmux_bindings::server_unzoom_window(w);
+            let lc = tmux_bindings::layout_split_pane(wp, type_, size, flags as c_int);
+            if lc.is_null() {
+                return Err(SpawnError("no space for new pane".to_owned()));
+            }
+
+            let mut spawn = Spawn::new(ptr::null_mut());
+            spawn.sc.s = (*wl).session;
+            spawn.sc.wl = wl;
+            spawn.sc.wp0 = wp;
+            spawn.sc.lc = lc;
+            spawn.sc.flags = flags as c_int;
+            let new_wp = spawn.pane()?;
+
+            tmux_bindings::server_redraw_window(w);
+            tmux_bindings::server_status_session((*wl).session);
+            Ok(Pane::from_raw(new_wp).expect("spawn_pane succeeded"))
+        }
+    }
+
+    /// Makes this the active pane of its window, the same way the `select-pane` command does.
+    pub fn select(&self) {
+        let wp = self.as_ptr();
+        unsafe {
+            let w = (*wp).window;
+            if (*w).active == wp {
+                return;
+            }
+            tmux_bindings::server_unzoom_window(w);
+            tmux_bindings::window_redraw_active_switch(w, wp);
+            if tmux_bindings::window_set_active_pane(w, wp, 1) != 0 {
+                tmux_bindings::server_status_window(w);
+                tmux_bindings::server_redraw_window_borders(w);
+            }
+        }
+    }
+
+    /// Restarts the pane's process, the same way the `respawn-pane` command does.
+    ///
+    /// Unless [`Respawn::kill`](./struct.Respawn.html#method.kill) is set, this fails if the
+    /// pane is not [dead](#method.is_dead).
+    ///
+    /// For example:
+    ///
+    /// ```rust
+    /// use tmux_plugin::notification_plugin;
+    /// use tmux_plugin::tmux::{Pane, PaneId, Respawn};
+    ///
+    /// // Restart the development server whenever it dies.
+    /// notification_plugin!(b"pane-died\0", |notify_entry| {
+    ///     let pane_id = unsafe { (*notify_entry).pane };
+    ///     if let Some(pane) = Pane::find_by_id(PaneId(pane_id as u32)) {
+    ///         let respawn = Respawn::new()
+    ///             .command("cargo run")
+    ///             .env("RUST_LOG", "debug");
+    ///         let _ = pane.respawn(&respawn);
+    ///     }
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn respawn(&self, respawn: &Respawn) -> Result<(), SpawnError> {
+        let wp = self.as_ptr();
+        let command = respawn.command.as_deref().map(c_string).transpose()?;
+        let cwd = match &respawn.start_directory {
+            Some(directory) => Some(c_string(&directory.to_string_lossy())?),
+            None => None,
+        };
+        let environment = respawn
+            .environment
+            .iter()
+            .map(|(name, value)| Ok((c_string(name)?, c_string(value)?)))
+            .collect::<Result<Vec<_>, SpawnError>>()?;
+
+        unsafe {
+            let w = (*wp).window;
+            let wl = (*w).winlinks.tqh_first;
+            if wl.is_null() {
+                return Err(SpawnError("window is not linked to a session".to_owned()));
+            }
+
+            let mut argv = command
+                .iter()
+                .map(|command| command.as_ptr() as *mut c_char)
+                .collect::<Vec<_>>();
+            let mut spawn = Spawn::new(ptr::null_mut());
+            spawn.sc.s = (*wl).session;
+            spawn.sc.wl = wl;
+            spawn.sc.wp0 = wp;
+            spawn.sc.argc = argv.len() as c_int;
+            spawn.sc.argv = argv.as_mut_ptr();
+            for (name, value) in &environment {
+                tmux_bindings::environ_set(
+                    spawn.sc.environ,
+                    name.as_ptr(),
+                    crate::cstr!("%s").as_ptr(),
+                    value.as_ptr(),
+                );
+            }
+            spawn.sc.cwd = cwd.as_ref().map_or(ptr::null(), |cwd| cwd.as_ptr());
+            spawn.sc.flags = SPAWN_RESPAWN as c_int;
+            if respawn.kill {
+                spawn.sc.flags |= SPAWN_KILL as c_int;
+            }
+            spawn.pane()?;
+
+            (*wp).flags |= PANE_REDRAW as c_int;
+            tmux_bindings::server_status_window(w);
+        }
+        Ok(())
+    }
+
+    /// Kills the pane, the same way the `kill-pane` command does.
+    ///
+    /// If this is the last pane in its window, the window is killed too.
+    pub fn kill(self) {
+        unsafe { tmux_bindings::server_kill_pane(self.as_ptr()) }
+    }
+
+    /// Resizes the pane, the same way `resize-pane -x width -y height` does.
+    ///
+    /// The other panes in the window are resized to make room, so the pane may not end up
+    /// at exactly the requested size if the window is too small.
+    pub fn resize(&self, width: u32, height: u32) {
+        let wp = self.as_ptr();
+        unsafe {
+            let w = (*wp).window;
+            tmux_bindings::server_unzoom_window(w);
+            tmux_bindings::layout_resize_pane_to(wp, layout_type_LAYOUT_LEFTRIGHT, width);
+            tmux_bindings::layout_resize_pane_to(wp, layout_type_LAYOUT_TOPBOTTOM, height);
+            tmux_bindings::server_redraw_window(w);
+        }
+    }
+}
+
+/// Options for [respawning](./struct.Pane.html#method.respawn) a pane.
+#[derive(Debug, Clone, Default)]
+pub struct Respawn {
+    command: Option<String>,
+    environment: Vec<(String, String)>,
+    start_directory: Option<PathBuf>,
+    kill: bool,
+}
+
+impl Respawn {
+    /// Creates options which respawn a pane with the command it was originally started with.
+    pub fn new() -> Respawn {
+        Respawn::default()
+    }
+
+    /// Sets the shell command to run in the pane.
+    pub fn command(mut self, command: &str) -> Respawn {
+        self.command = Some(command.to_owned());
+        self
+    }
+
+    /// Sets an environment variable for the new process, on top of the session's environment.
+    pub fn env(mut self, name: &str, value: &str) -> Respawn {
+        self.environment.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sets the working directory of the new process.
+    pub fn start_directory<P: AsRef<Path>>(mut self, directory: P) -> Respawn {
+        self.start_directory = Some(directory.as_ref().to_owned());
+        self
+    }
+
+    /// Kills the pane's process first if it is still running, like `respawn-pane -k`.
+    pub fn kill(mut self, kill: bool) -> Respawn {
+        self.kill = kill;
+        self
+    }
+}
+
+fn path_from(path: &CStr) -> PathBuf {
+    PathBuf::from(OsStr::from_bytes(path.to_bytes()))
+}
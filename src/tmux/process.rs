@@ -0,0 +1,190 @@
+//! Inspecting the processes running in panes.
+//!
+//! On Linux, processes are read from `/proc`. Elsewhere, `ps` is run instead.
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::Pane;
+//! use std::ffi::CString;
+//!
+//! // The program in the foreground of the pane, such as "vim" or "zsh".
+//! format_plugin!(b"pane_program\0", |format_tree| {
+//!     let pane = unsafe { Pane::from_raw((*format_tree).wp) };
+//!     let name = pane
+//!         .and_then(|pane| pane.foreground_process())
+//!         .map(|process| process.name)
+//!         .unwrap_or_default();
+//!     CString::new(name).unwrap_or_default()
+//! });
+//! # fn main() {}
+//! ```
+//!
+//! Or, walking the tree of processes started in a pane:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::{process, Pane};
+//! use std::ffi::CString;
+//!
+//! // Whether ssh is running anywhere in the pane, even under another program.
+//! format_plugin!(b"pane_ssh\0", |format_tree| {
+//!     let pane = unsafe { Pane::from_raw((*format_tree).wp) };
+//!     let ssh = pane.is_some_and(|pane| {
+//!         process::descendants_of(pane.pid())
+//!             .into_iter()
+//!             .filter_map(process::info)
+//!             .any(|process| process.name == "ssh")
+//!     });
+//!     CString::new(if ssh { "1" } else { "0" }).unwrap()
+//! });
+//! # fn main() {}
+//! ```
+
+use super::Pane;
+use crate::tmux_bindings::pid_t;
+#[cfg(target_os = "linux")]
+use std::fs;
+#[cfg(not(target_os = "linux"))]
+use std::process::Command;
+
+/// A running process.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessInfo {
+    /// The process ID.
+    pub pid: pid_t,
+    /// The name of the program, such as `vim`.
+    pub name: String,
+    /// The command line the program was started with, including the program itself.
+    pub args: Vec<String>,
+}
+
+/// Looks up a running process, returning `None` if there is no such process.
+pub fn info(pid: pid_t) -> Option<ProcessInfo> {
+    let (name, args) = read_info(pid)?;
+    Some(ProcessInfo { pid, name, args })
+}
+
+#[cfg(target_os = "linux")]
+fn read_info(pid: pid_t) -> Option<(String, Vec<String>)> {
+    let name = fs::read_to_string(format!("/proc/{}/comm", pid)).ok()?;
+    let cmdline = fs::read(format!("/proc/{}/cmdline", pid)).unwrap_or_default();
+    let args = cmdline
+        .split(|&b| b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| String::from_utf8_lossy(arg).into_owned())
+        .collect();
+    Some((name.trim_end_matches('\n').to_owned(), args))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_info(pid: pid_t) -> Option<(String, Vec<String>)> {
+    let name = ps(&["-o", "comm=", "-p", &pid.to_string()])?;
+    let name = name.trim();
+    if name.is_empty() {
+        return None;
+    }
+    let name = name.rsplit('/').next().unwrap_or(name).to_owned();
+    let args = ps(&["-o", "args=", "-p", &pid.to_string()])
+        .map(|args| args.split_whitespace().map(str::to_owned).collect())
+        .unwrap_or_default();
+    Some((name, args))
+}
+
+/// The processes whose parent is `pid`.
+pub fn children_of(pid: pid_t) -> Vec<pid_t> {
+    parents()
+        .into_iter()
+        .filter(|&(_, parent)| parent == pid)
+        .map(|(child, _)| child)
+        .collect()
+}
+
+/// The processes started by `pid`, their children, and so on, parents before their children.
+pub fn descendants_of(pid: pid_t) -> Vec<pid_t> {
+    let parents = parents();
+    let mut descendants = vec![pid];
+    let mut next = 0;
+    while next < descendants.len() {
+        let parent = descendants[next];
+        descendants.extend(
+            parents
+                .iter()
+                .filter(|&&(child, ppid)| ppid == parent && child != parent)
+                .map(|&(child, _)| child),
+        );
+        next += 1;
+    }
+    descendants.remove(0);
+    descendants
+}
+
+/// Every process, with the ID of its parent.
+#[cfg(target_os = "linux")]
+fn parents() -> Vec<(pid_t, pid_t)> {
+    let entries = match fs::read_dir("/proc") {
+        Ok(entries) => entries,
+        Err(_) => return Vec::new(),
+    };
+    entries
+        .filter_map(|entry| {
+            let pid = entry.ok()?.file_name().to_str()?.parse().ok()?;
+            let stat = fs::read_to_string(format!("/proc/{}/stat", pid)).ok()?;
+            // The name in brackets can contain anything, so the fields after it are found
+            // from the last bracket.
+            let after_name = &stat[stat.rfind(')')? + 1..];
+            let ppid = after_name.split_whitespace().nth(1)?.parse().ok()?;
+            Some((pid, ppid))
+        })
+        .collect()
+}
+
+/// Every process, with the ID of its parent.
+#[cfg(not(target_os = "linux"))]
+fn parents() -> Vec<(pid_t, pid_t)> {
+    ps(&["-A", "-o", "pid=", "-o", "ppid="])
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let pid = fields.next()?.parse().ok()?;
+            let ppid = fields.next()?.parse().ok()?;
+            Some((pid, ppid))
+        })
+        .collect()
+}
+
+/// Runs `ps`, returning its output if it succeeds.
+#[cfg(not(target_os = "linux"))]
+fn ps(args: &[&str]) -> Option<String> {
+    let output = Command::new("ps").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+impl Pane {
+    /// The process ID of the pane's first process, usually a shell.
+    pub fn pid(&self) -> pid_t {
+        unsafe { (*self.as_ptr()).pid }
+    }
+
+    /// The process in the foreground of the pane's terminal, such as the program the pane's
+    /// shell is running, or the shell itself when it is waiting for a command.
+    ///
+    /// This is the leader of the terminal's foreground process group. Returns `None` once the
+    /// pane is dead.
+    pub fn foreground_process(&self) -> Option<ProcessInfo> {
+        let fd = unsafe { (*self.as_ptr()).fd };
+        if fd == -1 {
+            return None;
+        }
+        let pgid = unsafe { libc::tcgetpgrp(fd) };
+        if pgid == -1 {
+            return None;
+        }
+        info(pgid)
+    }
+}
@@ -0,0 +1,197 @@
+//! Prompting the user for input on the status line, as tmux's `command-prompt` does.
+//!
+//! Build a [`Prompt`](./struct.Prompt.html) and [show](./struct.Prompt.html#method.show) it on
+//! a client, with a closure which is called with what the user enters.
+
+use super::{boxed, Client};
+use crate::tmux_bindings::{
+    self, client, PROMPT_INCREMENTAL, PROMPT_NOFORMAT, PROMPT_NUMERIC, PROMPT_SINGLE,
+};
+use bitflags::bitflags;
+use std::cell::{Cell, RefCell};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int, c_void};
+use std::rc::Rc;
+
+bitflags! {
+    /// Options changing how a [`Prompt`](./struct.Prompt.html) takes its input.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Flags: u32 {
+        /// Finish after a single key, as with `command-prompt -1`.
+        const SINGLE = PROMPT_SINGLE;
+        /// Only accept digits, as with `command-prompt -N`.
+        const NUMERIC = PROMPT_NUMERIC;
+        /// Report the input as it changes, as well as when it is entered, as with
+        /// `command-prompt -i`.
+        const INCREMENTAL = PROMPT_INCREMENTAL;
+        /// Show the message as it is, rather than expanding it as a format.
+        const NOFORMAT = PROMPT_NOFORMAT;
+    }
+}
+
+/// What the user did with a prompt, passed to its closure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Input<'a> {
+    /// The user pressed enter with this text.
+    Entered(&'a CStr),
+    /// The text has changed. Only given to prompts with the
+    /// [`INCREMENTAL`](./struct.Flags.html#associatedconstant.INCREMENTAL) flag.
+    Changed(&'a CStr),
+    /// The user pressed escape, or the prompt was otherwise closed without being entered.
+    Cancelled,
+}
+
+/// A prompt on a client's status line.
+///
+/// For example, to ask for a name, starting with the current window's:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, cstr, tmux};
+/// use tmux_plugin::tmux::prompt::{Input, Prompt};
+///
+/// cmd_plugin!("rename-prompt", "", "", 0, 0, |_cmd, _args, item| {
+///     let message = cstr!("(rename) ");
+///     let initial = cstr!("#W");
+///     if let Some(client) = item.target_client() {
+///         Prompt::new(message).initial(initial).show(client, |client, input| {
+///             if let Input::Entered(name) = input {
+///                 tmux::display_message(client, name);
+///             }
+///         });
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone)]
+pub struct Prompt<'a> {
+    message: &'a CStr,
+    initial: Option<&'a CStr>,
+    flags: Flags,
+}
+
+impl<'a> Prompt<'a> {
+    /// Creates a prompt showing the given message before the input.
+    ///
+    /// Unless the prompt has the [`NOFORMAT`](./struct.Flags.html#associatedconstant.NOFORMAT)
+    /// flag, the message is expanded as a format.
+    pub fn new(message: &'a CStr) -> Prompt<'a> {
+        Prompt {
+            message,
+            initial: None,
+            flags: Flags::empty(),
+        }
+    }
+
+    /// Sets the text the input starts with, which is also expanded as a format.
+    pub fn initial(mut self, initial: &'a CStr) -> Prompt<'a> {
+        self.initial = Some(initial);
+        self
+    }
+
+    /// Sets the prompt's [flags](./struct.Flags.html).
+    pub fn flags(mut self, flags: Flags) -> Prompt<'a> {
+        self.flags = flags;
+        self
+    }
+
+    /// Shows the prompt on a client, replacing any prompt it is already showing.
+    ///
+    /// The closure is called with the client and the user's [input](./enum.Input.html). The
+    /// prompt closes once the input is entered or cancelled, unless the closure shows another
+    /// prompt, and the closure is dropped when it closes.
+    pub fn show<F>(&self, client: Client, callback: F)
+    where
+        F: FnMut(Client, Input<'_>) + 'static,
+    {
+        let callback: Callback = Rc::new(RefCell::new(Box::new(callback)));
+        SHOWN.with(|shown| shown.set(shown.get().wrapping_add(1)));
+        unsafe {
+            tmux_bindings::status_prompt_set(
+                client.as_ptr(),
+                self.message.as_ptr(),
+                self.initial.map_or(crate::cstr!("").as_ptr(), CStr::as_ptr),
+                Some(input),
+                Some(boxed::drop_raw::<Callback>),
+                boxed::into_raw(callback),
+                self.flags.bits() as c_int,
+            );
+        }
+    }
+}
+
+/// Asks the user a yes or no question, as tmux's `confirm-before` does, and runs the closure
+/// if they answer yes.
+///
+/// The question is shown followed by `(y/n)`, and a single key answers it. Any key other than
+/// `y`, or cancelling the prompt, answers no.
+///
+/// For example, to ask before killing every other pane:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, cstr, tmux};
+///
+/// cmd_plugin!("kill-others", "", "", 0, 0, |_cmd, _args, item| {
+///     let question = cstr!("Kill every other pane?");
+///     if let Some(client) = item.target_client() {
+///         tmux::prompt::confirm(client, question, move || {
+///             // Kill the panes here.
+///         });
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+pub fn confirm<F>(client: Client, question: &CStr, confirmed: F)
+where
+    F: FnOnce() + 'static,
+{
+    let mut message = question.to_bytes().to_vec();
+    message.extend_from_slice(b" (y/n) ");
+    let message = CString::new(message).expect("A CStr has no null bytes");
+    let mut confirmed = Some(confirmed);
+    Prompt::new(&message)
+        .flags(Flags::SINGLE | Flags::NOFORMAT)
+        .show(client, move |_client, input| {
+            if matches!(input, Input::Entered(answer) if answer.to_bytes() == b"y") {
+                if let Some(confirmed) = confirmed.take() {
+                    confirmed();
+                }
+            }
+        });
+}
+
+/// Closes the prompt a client is showing, if any, cancelling it.
+pub fn clear(client: Client) {
+    unsafe { tmux_bindings::status_prompt_clear(client.as_ptr()) }
+}
+
+type Callback = Rc<RefCell<Box<dyn FnMut(Client, Input<'_>)>>>;
+
+thread_local! {
+    /// How many prompts have been shown, to tell when a prompt's closure shows another.
+    static SHOWN: Cell<u64> = const { Cell::new(0) };
+}
+
+unsafe extern "C" fn input(
+    c: *mut client,
+    data: *mut c_void,
+    s: *const c_char,
+    done: c_int,
+) -> c_int {
+    // Showing another prompt frees this one's data, so hold on to the closure while it runs.
+    let callback = Rc::clone(boxed::as_ref::<Callback>(data));
+    let input = if s.is_null() {
+        Input::Cancelled
+    } else if done == 0 {
+        Input::Changed(CStr::from_ptr(s))
+    } else {
+        Input::Entered(CStr::from_ptr(s))
+    };
+    let shown = SHOWN.with(Cell::get);
+    if let (Some(client), Ok(mut callback)) = (Client::from_raw(c), callback.try_borrow_mut()) {
+        callback(client, input);
+    }
+    // tmux clears the client's prompt when this returns zero, which would be the new one.
+    (SHOWN.with(Cell::get) != shown) as c_int
+}
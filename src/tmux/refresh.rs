@@ -0,0 +1,58 @@
+//! Making changes visible immediately.
+//!
+//! tmux redraws status lines every `status-interval` seconds, and panes when their contents
+//! change. A plugin which learns about new data in between, such as from a background thread or
+//! a [control socket](../control/index.html), can use these to have tmux redraw what shows it
+//! the next time it returns to its event loop.
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::{cmd_plugin, tmux};
+//! use tmux_plugin::tmux::refresh;
+//!
+//! // Redraws the status line of the invoking client's session, to pick up fresh values.
+//! cmd_plugin!(b"refresh-status\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+//!     if let Some(session) = item.client().and_then(|client| client.session()) {
+//!         refresh::status(session);
+//!     }
+//!     tmux::CmdResult::Normal
+//! });
+//! # fn main() {}
+//! ```
+
+use super::{clients, Client, Session, Window};
+use crate::tmux_bindings;
+
+/// Redraws the status lines of the clients attached to a session.
+pub fn status(session: Session) {
+    unsafe { tmux_bindings::server_status_session(session.as_ptr()) }
+}
+
+/// Redraws the status line of every client.
+pub fn status_all() {
+    for client in clients::all() {
+        client_status(client);
+    }
+}
+
+/// Redraws the status line of one client.
+pub fn client_status(client: Client) {
+    unsafe { tmux_bindings::server_status_client(client.as_ptr()) }
+}
+
+/// Redraws the status lines of the clients whose current window is this window.
+pub fn window_status(window: Window) {
+    unsafe { tmux_bindings::server_status_window(window.as_ptr()) }
+}
+
+/// Redraws everything shown by the clients whose current window is this window, including
+/// its panes, borders and the status line.
+pub fn window(window: Window) {
+    unsafe { tmux_bindings::server_redraw_window(window.as_ptr()) }
+}
+
+/// Redraws everything shown by the clients attached to a session.
+pub fn session(session: Session) {
+    unsafe { tmux_bindings::server_redraw_session(session.as_ptr()) }
+}
@@ -0,0 +1,156 @@
+use super::grid;
+use super::Pane;
+use std::vec;
+
+/// Something to search pane contents for.
+///
+/// This is implemented for `&str`, which matches literally, and with the `regex` feature
+/// enabled, for [`regex::Regex`](https://docs.rs/regex/1/regex/struct.Regex.html).
+pub trait Pattern {
+    /// Returns the byte ranges of every non-overlapping match in a line of text.
+    fn find_in(&self, line: &str) -> Vec<(usize, usize)>;
+}
+
+impl Pattern for &str {
+    fn find_in(&self, line: &str) -> Vec<(usize, usize)> {
+        if self.is_empty() {
+            return Vec::new();
+        }
+        line.match_indices(*self)
+            .map(|(start, found)| (start, start + found.len()))
+            .collect()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Pattern for regex::Regex {
+    fn find_in(&self, line: &str) -> Vec<(usize, usize)> {
+        self.find_iter(line).map(|m| (m.start(), m.end())).collect()
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Pattern for &regex::Regex {
+    fn find_in(&self, line: &str) -> Vec<(usize, usize)> {
+        (*self).find_in(line)
+    }
+}
+
+/// Which way to search through a pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDirection {
+    /// From the oldest line of history to the bottom of the screen.
+    Forward,
+    /// From the bottom of the screen back to the oldest line of history.
+    Backward,
+}
+
+/// Where a search pattern was found in a pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The line the match is on, counting from the oldest line of history.
+    pub line: u32,
+    /// The column the match starts at.
+    pub start: u32,
+    /// The column just after the end of the match.
+    pub end: u32,
+}
+
+impl Pane {
+    /// Searches the pane's history and visible contents.
+    ///
+    /// Lines are read one at a time as the returned iterator is advanced, so finding the
+    /// most recent match in a pane with a long history doesn't read the whole history.
+    /// Lines which wrapped are searched separately, so matches can't span them.
+    ///
+    /// Line numbers count from the oldest line of history, the same way as
+    /// [`CopyMode::move_cursor_to`](./struct.CopyMode.html#method.move_cursor_to), so a match
+    /// can be selected in copy mode directly.
+    ///
+    /// For example:
+    ///
+    /// ```rust
+    /// use tmux_plugin::{cmd_plugin, tmux};
+    /// use tmux_plugin::tmux::SearchDirection;
+    ///
+    /// // Jumps to the last line that mentions an error.
+    /// cmd_plugin!(b"jump-to-error\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+    ///     let pane = item
+    ///         .target_client()
+    ///         .and_then(|client| client.session())
+    ///         .and_then(|session| session.current_window())
+    ///         .and_then(|window| window.active_pane());
+    ///     if let Some(pane) = pane {
+    ///         if let Some(found) = pane.search("error", SearchDirection::Backward).next() {
+    ///             pane.enter_copy_mode()
+    ///                 .set_selection((found.start, found.line), (found.end - 1, found.line));
+    ///         }
+    ///     }
+    ///     tmux::CmdResult::Normal
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn search<P: Pattern>(&self, pattern: P, direction: SearchDirection) -> Matches<P> {
+        let lines = unsafe { grid::line_count((*self.as_ptr()).base.grid) };
+        let next_line = match direction {
+            SearchDirection::Forward => 0,
+            SearchDirection::Backward => lines,
+        };
+        Matches {
+            pane: *self,
+            pattern,
+            direction,
+            next_line,
+            lines,
+            pending: Vec::new().into_iter(),
+        }
+    }
+}
+
+/// An iterator over the matches of a [search](./struct.Pane.html#method.search) in a pane.
+pub struct Matches<P> {
+    pane: Pane,
+    pattern: P,
+    direction: SearchDirection,
+    next_line: u32,
+    lines: u32,
+    pending: vec::IntoIter<Match>,
+}
+
+impl<P: Pattern> Iterator for Matches<P> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            if let Some(found) = self.pending.next() {
+                return Some(found);
+            }
+            let y = match self.direction {
+                SearchDirection::Forward if self.next_line < self.lines => {
+                    self.next_line += 1;
+                    self.next_line - 1
+                }
+                SearchDirection::Backward if self.next_line > 0 => {
+                    self.next_line -= 1;
+                    self.next_line
+                }
+                _ => return None,
+            };
+            let line = unsafe { grid::read_line((*self.pane.as_ptr()).base.grid, y) };
+            let mut found = self
+                .pattern
+                .find_in(&line.text)
+                .into_iter()
+                .map(|(start, end)| Match {
+                    line: y,
+                    start: line.column(start),
+                    end: line.column(end),
+                })
+                .collect::<Vec<_>>();
+            if self.direction == SearchDirection::Backward {
+                found.reverse();
+            }
+            self.pending = found.into_iter();
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! The state of the tmux server as a whole.
+//!
+//! These read tmux's global variables, for things that don't belong to any one session,
+//! window or client.
+
+use super::{time, Options, Pane};
+use crate::tmux_bindings::{self, sessions_RB_MINMAX};
+use std::ffi::{CStr, CString, OsStr};
+use std::os::unix::ffi::OsStrExt;
+use std::path::Path;
+use std::ptr;
+use std::time::SystemTime;
+
+/// The path of the socket the server is listening on, as passed to `tmux -S`.
+pub fn socket_path() -> &'static Path {
+    let path = unsafe { CStr::from_ptr(tmux_bindings::socket_path) };
+    Path::new(OsStr::from_bytes(path.to_bytes()))
+}
+
+/// When the server was started.
+pub fn start_time() -> SystemTime {
+    time::system_time(unsafe { tmux_bindings::start_time })
+}
+
+/// The server options, as set with `set-option -s`.
+pub fn global_options() -> Options {
+    unsafe { Options::from_raw(tmux_bindings::global_options) }
+        .expect("The global options are created at startup")
+}
+
+/// The global session options, which sessions inherit unless they override them.
+pub fn global_session_options() -> Options {
+    unsafe { Options::from_raw(tmux_bindings::global_s_options) }
+        .expect("The global session options are created at startup")
+}
+
+/// The global window options, which windows inherit unless they override them.
+pub fn global_window_options() -> Options {
+    unsafe { Options::from_raw(tmux_bindings::global_w_options) }
+        .expect("The global window options are created at startup")
+}
+
+/// A variable in the server's global environment, which new sessions inherit, as shown by
+/// `show-environment -g`.
+///
+/// This starts out as the environment of the client which started the server, and changes
+/// with `set-environment -g`. Returns `None` if the variable isn't set, or is marked to be
+/// removed from new sessions' environments.
+pub fn environment(name: &str) -> Option<String> {
+    let name = CString::new(name).ok()?;
+    unsafe {
+        let entry = tmux_bindings::environ_find(tmux_bindings::global_environ, name.as_ptr());
+        if entry.is_null() || (*entry).value.is_null() {
+            return None;
+        }
+        Some(
+            CStr::from_ptr((*entry).value)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+/// The marked pane, as set by `select-pane -m`, if there is one.
+pub fn marked_pane() -> Option<Pane> {
+    unsafe {
+        if tmux_bindings::server_check_marked() == 0 {
+            return None;
+        }
+        Pane::from_raw(tmux_bindings::marked_pane.wp)
+    }
+}
+
+/// Whether the server is on its way to exiting.
+///
+/// tmux doesn't expose its own exit flag, so this is worked out the same way tmux decides
+/// to exit: once the last session is gone, the server exits as soon as its remaining clients
+/// have, unless the `exit-empty` option is off. `kill-server` destroys every session first,
+/// so it is covered too, as long as `exit-empty` is on.
+pub fn is_exiting() -> bool {
+    let tree = ptr::addr_of_mut!(tmux_bindings::sessions);
+    let empty = unsafe { sessions_RB_MINMAX(tree, -1) }.is_null();
+    empty && global_options().get_number("exit-empty") != Some(0)
+}
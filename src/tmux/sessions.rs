@@ -0,0 +1,250 @@
+use super::spawn::{c_string, Spawn, SpawnError};
+use super::{SessionId, Window};
+use crate::tmux_bindings::{self, session};
+use std::error;
+use std::ffi::{CStr, CString, NulError};
+use std::fmt;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+use std::ptr::{self, NonNull};
+
+/// A tmux session.
+///
+/// This is a thin wrapper around a [`*mut session`](./struct.session.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Session(NonNull<session>);
+
+impl Session {
+    /// Wraps a raw session pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `session`.
+    pub unsafe fn from_raw(ptr: *mut session) -> Option<Session> {
+        NonNull::new(ptr).map(Session)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut session {
+        self.0.as_ptr()
+    }
+
+    /// Finds a session by its unique ID.
+    pub fn find_by_id(id: SessionId) -> Option<Session> {
+        unsafe { Session::from_raw(tmux_bindings::session_find_by_id(id.0)) }
+    }
+
+    /// The session's unique ID, as used in `$` targets.
+    pub fn id(&self) -> SessionId {
+        SessionId(unsafe { (*self.as_ptr()).id })
+    }
+
+    /// The session's name.
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr((*self.as_ptr()).name) }
+    }
+
+    /// The session's current window.
+    pub fn current_window(&self) -> Option<Window> {
+        unsafe {
+            let wl = (*self.as_ptr()).curw;
+            if wl.is_null() {
+                None
+            } else {
+                Window::from_raw((*wl).window)
+            }
+        }
+    }
+
+    /// Makes the window at the given index the session's current window, the same way the
+    /// `select-window` command does. Returns `false` if there is no window at that index.
+    pub fn select_window(&self, index: i32) -> bool {
+        unsafe {
+            if tmux_bindings::session_select(self.as_ptr(), index) != 0 {
+                return false;
+            }
+            tmux_bindings::server_redraw_session(self.as_ptr());
+        }
+        true
+    }
+
+    /// Renames the session, the same way the `rename-session` command does.
+    ///
+    /// This fires the `session-renamed` hook, unless the session already has this name.
+    pub fn rename(&self, name: &str) -> Result<(), RenameError> {
+        let name = CString::new(name).map_err(RenameError::Nul)?;
+        if name.as_c_str() == self.name() {
+            return Ok(());
+        }
+        let s = self.as_ptr();
+        unsafe {
+            if tmux_bindings::session_check_name(name.as_ptr()) == 0 {
+                return Err(RenameError::Invalid);
+            }
+            if !tmux_bindings::session_find(name.as_ptr()).is_null() {
+                return Err(RenameError::Duplicate);
+            }
+            let tree = ptr::addr_of_mut!(tmux_bindings::sessions);
+            tmux_bindings::sessions_RB_REMOVE(tree, s);
+            super::strings::free((*s).name);
+            (*s).name = tmux_bindings::xstrdup(name.as_ptr());
+            tmux_bindings::sessions_RB_INSERT(tree, s);
+
+            tmux_bindings::server_status_session(s);
+            tmux_bindings::notify_session(crate::cstr!("session-renamed").as_ptr(), s);
+        }
+        Ok(())
+    }
+
+    /// Destroys the session, the same way the `kill-session` command does.
+    ///
+    /// Any clients attached to the session are moved to another session, or detached.
+    pub fn destroy(self) {
+        unsafe {
+            tmux_bindings::server_destroy_session(self.as_ptr());
+            tmux_bindings::session_destroy(
+                self.as_ptr(),
+                1,
+                crate::cstr!("Session::destroy").as_ptr(),
+            );
+        }
+    }
+}
+
+/// The error returned when a session cannot be renamed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameError {
+    /// The new name contains a null byte.
+    Nul(NulError),
+    /// The new name is not a valid session name, for instance because it contains a colon.
+    Invalid,
+    /// Another session already has the new name.
+    Duplicate,
+}
+
+impl fmt::Display for RenameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RenameError::Nul(err) => err.fmt(f),
+            RenameError::Invalid => f.write_str("bad session name"),
+            RenameError::Duplicate => f.write_str("duplicate session"),
+        }
+    }
+}
+
+impl error::Error for RenameError {}
+
+/// Creates a new session, the same way `new-session -d` does.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use tmux_plugin::tmux::SessionBuilder;
+///
+/// // Opens a session for a project, with an editor already running.
+/// cmd_plugin!(b"open-project\0", b"\0", b"directory\0", 1, 1, |_cmd, args, _item| {
+///     let directory = args.last().unwrap().to_string_lossy().into_owned();
+///     let session = SessionBuilder::new()
+///         .name(directory.rsplit('/').next().unwrap())
+///         .start_directory(&directory)
+///         .command("vim .")
+///         .create();
+///     match session {
+///         Ok(_) => tmux::CmdResult::Normal,
+///         Err(err) => tmux::CmdResult::Error(err.to_string()),
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SessionBuilder {
+    name: Option<String>,
+    start_directory: Option<PathBuf>,
+    command: Option<String>,
+}
+
+impl SessionBuilder {
+    /// Creates a builder for a session with the default name, start directory and command.
+    pub fn new() -> SessionBuilder {
+        SessionBuilder::default()
+    }
+
+    /// Sets the session's name. Without a name, tmux numbers the session.
+    pub fn name(mut self, name: &str) -> SessionBuilder {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the session's working directory, which new windows and panes start in.
+    pub fn start_directory<P: AsRef<Path>>(mut self, directory: P) -> SessionBuilder {
+        self.start_directory = Some(directory.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the shell command run in the session's first window, instead of the default shell.
+    pub fn command(mut self, command: &str) -> SessionBuilder {
+        self.command = Some(command.to_owned());
+        self
+    }
+
+    /// Creates the session, without attaching any client to it.
+    pub fn create(&self) -> Result<Session, SpawnError> {
+        let name = self.name.as_deref().map(c_string).transpose()?;
+        let cwd = match &self.start_directory {
+            Some(directory) => Some(c_string(&directory.to_string_lossy())?),
+            None => None,
+        };
+        let command = self.command.as_deref().map(c_string).transpose()?;
+
+        unsafe {
+            if let Some(name) = &name {
+                if tmux_bindings::session_check_name(name.as_ptr()) == 0 {
+                    return Err(SpawnError(format!(
+                        "bad session name: {}",
+                        self.name.as_ref().unwrap()
+                    )));
+                }
+                if !tmux_bindings::session_find(name.as_ptr()).is_null() {
+                    return Err(SpawnError(format!(
+                        "duplicate session: {}",
+                        self.name.as_ref().unwrap()
+                    )));
+                }
+            }
+            let cwd = match &cwd {
+                Some(cwd) => cwd.as_ptr(),
+                None => tmux_bindings::server_client_get_cwd(ptr::null_mut(), ptr::null_mut()),
+            };
+
+            let s = tmux_bindings::session_create(
+                ptr::null(),
+                name.as_ref().map_or(ptr::null(), |name| name.as_ptr()),
+                cwd,
+                tmux_bindings::environ_create(),
+                tmux_bindings::options_create(tmux_bindings::global_s_options),
+                ptr::null_mut(),
+            );
+
+            let mut argv = command
+                .iter()
+                .map(|command| command.as_ptr() as *mut c_char)
+                .collect::<Vec<_>>();
+            let mut spawn = Spawn::new(ptr::null_mut());
+            spawn.sc.s = s;
+            spawn.sc.argc = argv.len() as _;
+            spawn.sc.argv = argv.as_mut_ptr();
+            if let Err(err) = spawn.window() {
+                tmux_bindings::session_destroy(
+                    s,
+                    0,
+                    crate::cstr!("SessionBuilder::create").as_ptr(),
+                );
+                return Err(err);
+            }
+
+            tmux_bindings::notify_session(crate::cstr!("session-created").as_ptr(), s);
+            Ok(Session::from_raw(s).expect("session_create never returns null"))
+        }
+    }
+}
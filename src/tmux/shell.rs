@@ -0,0 +1,112 @@
+//! Running shell commands from format plugins.
+//!
+//! Many status line plugins are shell scripts run with `#(...)`. [`cached`](./fn.cached.html)
+//! covers what most of them need when ported to Rust: running a command every so often, and
+//! showing its last output in between.
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::shell;
+//! use std::ffi::CString;
+//! use std::time::Duration;
+//!
+//! // The current git branch of the server's directory, checked at most once a minute.
+//! format_plugin!(b"git_branch\0", |_format_tree| {
+//!     let branch = shell::cached("git rev-parse --abbrev-ref HEAD", Duration::from_secs(60));
+//!     CString::new(branch).unwrap_or_default()
+//! });
+//! # fn main() {}
+//! ```
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::io::Read;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long a command may run before it is killed.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often a running command is checked for having finished.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+thread_local! {
+    /// The last output of each command, and when it was run.
+    static CACHE: RefCell<HashMap<String, (Instant, String)>> = RefCell::new(HashMap::new());
+}
+
+/// Runs a command with `sh -c`, unless it was already run within `ttl`, and returns its output
+/// with any trailing newlines removed.
+///
+/// The output is cached by the command, so the same command used from several places is only
+/// run once per `ttl`. tmux waits while the command runs, so commands should be quick. One
+/// which takes longer than five seconds is killed.
+pub fn cached(command: &str, ttl: Duration) -> String {
+    let cached = CACHE.with(|cache| {
+        cache
+            .borrow()
+            .get(command)
+            .filter(|(ran, _)| ran.elapsed() < ttl)
+            .map(|(_, output)| output.clone())
+    });
+    if let Some(output) = cached {
+        return output;
+    }
+    let output = run(command);
+    CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .insert(command.to_owned(), (Instant::now(), output.clone()))
+    });
+    output
+}
+
+/// Forgets the cached output of a command, so that it is run again the next time it is used.
+pub fn invalidate(command: &str) {
+    CACHE.with(|cache| cache.borrow_mut().remove(command));
+}
+
+/// Runs a command and returns its output, giving up after the timeout.
+pub(crate) fn run(command: &str) -> String {
+    let mut child = match Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    {
+        Ok(child) => child,
+        Err(_) => return String::new(),
+    };
+    // The output is read on another thread, so that a command with a lot of output can't
+    // fill the pipe and stall before it finishes.
+    let mut stdout = child.stdout.take().expect("The output was piped");
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        let _ = stdout.read_to_end(&mut output);
+        let _ = sender.send(output);
+    });
+    let started = Instant::now();
+    while let Ok(None) = child.try_wait() {
+        if started.elapsed() >= TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            break;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+    // Something the command started in the background may still have the output open, so
+    // don't wait for it to be closed for long.
+    let output = receiver
+        .recv_timeout(TIMEOUT.saturating_sub(started.elapsed()).max(POLL_INTERVAL))
+        .unwrap_or_default();
+    String::from_utf8_lossy(&output)
+        .trim_end_matches('\n')
+        .to_owned()
+}
@@ -0,0 +1,87 @@
+use crate::tmux_bindings::{self, cmdq_item, spawn_context, window_pane, winlink};
+use std::error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::mem;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// The error returned when tmux fails to create a pane, window or session.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpawnError(pub String);
+
+impl fmt::Display for SpawnError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl error::Error for SpawnError {}
+
+/// A `spawn_context` for calling tmux's `spawn_pane` and `spawn_window`.
+///
+/// tmux looks up the client a pane is spawned for through the context's queue item,
+/// so when spawning from outside of a command this points at an empty item instead.
+pub(crate) struct Spawn {
+    pub(crate) sc: spawn_context,
+    _item: Option<Box<cmdq_item>>,
+}
+
+impl Spawn {
+    pub(crate) fn new(item: *mut cmdq_item) -> Spawn {
+        let mut sc: spawn_context = unsafe { mem::zeroed() };
+        let placeholder = if item.is_null() {
+            let mut placeholder: Box<cmdq_item> = Box::new(unsafe { mem::zeroed() });
+            sc.item = &mut *placeholder;
+            Some(placeholder)
+        } else {
+            sc.item = item;
+            None
+        };
+        sc.environ = unsafe { tmux_bindings::environ_create() };
+        sc.idx = -1;
+        Spawn {
+            sc,
+            _item: placeholder,
+        }
+    }
+
+    pub(crate) unsafe fn pane(&mut self) -> Result<*mut window_pane, SpawnError> {
+        let mut cause = ptr::null_mut();
+        let wp = tmux_bindings::spawn_pane(&mut self.sc, &mut cause);
+        if wp.is_null() {
+            Err(take_cause(cause))
+        } else {
+            Ok(wp)
+        }
+    }
+
+    pub(crate) unsafe fn window(&mut self) -> Result<*mut winlink, SpawnError> {
+        let mut cause = ptr::null_mut();
+        let wl = tmux_bindings::spawn_window(&mut self.sc, &mut cause);
+        if wl.is_null() {
+            Err(take_cause(cause))
+        } else {
+            Ok(wl)
+        }
+    }
+}
+
+impl Drop for Spawn {
+    fn drop(&mut self) {
+        unsafe { tmux_bindings::environ_free(self.sc.environ) }
+    }
+}
+
+unsafe fn take_cause(cause: *mut c_char) -> SpawnError {
+    if cause.is_null() {
+        return SpawnError("unknown error".to_owned());
+    }
+    let message = CStr::from_ptr(cause).to_string_lossy().into_owned();
+    super::strings::free(cause);
+    SpawnError(message)
+}
+
+pub(crate) fn c_string(value: &str) -> Result<CString, SpawnError> {
+    CString::new(value).map_err(|err| SpawnError(err.to_string()))
+}
@@ -0,0 +1,275 @@
+//! Status line messages.
+//!
+//! [`display_message`](./fn.display_message.html) covers the common case of showing some text
+//! the same way tmux's `display-message` does. For more control over how long a message is shown,
+//! how it's styled, and which clients see it, build a [`Message`](./struct.Message.html) instead.
+
+use super::{clients, timer, Client};
+use crate::tmux_bindings::{self, client, session, CLIENT_DEAD};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::c_char;
+use std::time::Duration;
+
+const MESSAGE_STYLE: &CStr = crate::cstr!("message-style");
+const DISPLAY_TIME: &CStr = crate::cstr!("display-time");
+
+/// How often an indefinitely shown, styled message is checked for having been dismissed.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Displays a message in the client's status line.
+///
+/// The message is shown for the duration of the `display-time` option, or until
+/// the next key press, just like messages from tmux's own `display-message` command.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, cstr, tmux};
+///
+/// cmd_plugin!("say-hello", "", "", 0, 0, |_cmd, _args, item| {
+///     if let Some(client) = item.target_client() {
+///         let text = cstr!("Hello!");
+///         tmux::display_message(client, text);
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+pub fn display_message(client: Client, text: &CStr) {
+    Message::new(text)
+        .show(client)
+        .expect("An unstyled message cannot have an invalid style")
+}
+
+/// A status line message with an optional duration and style.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, cstr, tmux};
+/// use tmux_plugin::tmux::status::Message;
+/// use std::time::Duration;
+///
+/// cmd_plugin!("announce", "", "", 0, 0, |_cmd, _args, item| {
+///     let text = cstr!("Build finished");
+///     let style = cstr!("fg=black,bg=green");
+///     let result = Message::new(text)
+///         .duration(Duration::from_secs(5))
+///         .style(style)
+///         .broadcast();
+///     match result {
+///         Ok(()) => tmux::CmdResult::Normal,
+///         Err(err) => tmux::CmdResult::Error(err.to_string()),
+///     }
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone)]
+pub struct Message<'a> {
+    text: &'a CStr,
+    duration: Option<Duration>,
+    style: Option<&'a CStr>,
+}
+
+impl<'a> Message<'a> {
+    /// Creates a message that will display the given text.
+    pub fn new(text: &'a CStr) -> Message<'a> {
+        Message {
+            text,
+            duration: None,
+            style: None,
+        }
+    }
+
+    /// Sets how long the message is shown for, instead of the `display-time` option.
+    ///
+    /// A zero duration shows the message until the next key press.
+    pub fn duration(mut self, duration: Duration) -> Message<'a> {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets the style of the message, instead of the `message-style` option.
+    ///
+    /// The style is given in the same syntax as tmux's style options, such as `fg=red,bold`.
+    /// tmux draws messages using the session's `message-style`, so this overrides that option
+    /// for the client's session while the message is shown, and restores it afterwards.
+    pub fn style(mut self, style: &'a CStr) -> Message<'a> {
+        self.style = Some(style);
+        self
+    }
+
+    /// Shows the message on a client.
+    ///
+    /// Clients which are not attached to a session have no status line, and are skipped.
+    pub fn show(&self, client: Client) -> Result<(), InvalidStyle> {
+        let c = client.as_ptr();
+        let s = unsafe { (*c).session };
+        if s.is_null() {
+            return Ok(());
+        }
+
+        if let Some(style) = self.style {
+            unsafe { push_style(s, style)? };
+        }
+        let restyled = self.style.is_some();
+
+        unsafe {
+            tmux_bindings::status_message_set(c, crate::cstr!("%s").as_ptr(), self.text.as_ptr());
+        }
+        if self.duration.is_none() && !restyled {
+            return Ok(());
+        }
+
+        let duration = self.duration.unwrap_or_else(|| unsafe {
+            let ms = tmux_bindings::options_get_number((*s).options, DISPLAY_TIME.as_ptr());
+            Duration::from_millis(ms as u64)
+        });
+        unsafe {
+            // Take over expiring the message from tmux's own timer.
+            tmux_bindings::event_del(&mut (*c).message_timer);
+        }
+        if duration == Duration::from_secs(0) && !restyled {
+            return Ok(());
+        }
+
+        unsafe {
+            (*c).references += 1;
+            if restyled {
+                tmux_bindings::session_add_ref(s, crate::cstr!("status::Message").as_ptr());
+            }
+        }
+        let pending = Pending {
+            client: c,
+            message: unsafe { (*c).message_string },
+            session: if restyled { Some(s) } else { None },
+        };
+        if duration == Duration::from_secs(0) {
+            timer::after(POLL_INTERVAL, move || pending.poll());
+        } else {
+            timer::after(duration, move || pending.expire());
+        }
+        Ok(())
+    }
+
+    /// Shows the message on every client attached to a session.
+    pub fn broadcast(&self) -> Result<(), InvalidStyle> {
+        for client in clients::all() {
+            self.show(client)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when a message's style could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidStyle(pub CString);
+
+impl fmt::Display for InvalidStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid style: {}", self.0.to_string_lossy())
+    }
+}
+
+impl error::Error for InvalidStyle {}
+
+/// A message which the crate, rather than tmux, is responsible for expiring.
+struct Pending {
+    client: *mut client,
+    message: *mut c_char,
+    /// The session whose `message-style` was overridden for this message, if any.
+    session: Option<*mut session>,
+}
+
+impl Pending {
+    fn is_showing(&self) -> bool {
+        unsafe {
+            (*self.client).flags & CLIENT_DEAD as i32 == 0
+                && (*self.client).message_string == self.message
+        }
+    }
+
+    fn expire(self) {
+        if self.is_showing() {
+            unsafe { tmux_bindings::status_message_clear(self.client) };
+        }
+        self.finish();
+    }
+
+    fn poll(self) {
+        if self.is_showing() {
+            timer::after(POLL_INTERVAL, move || self.poll());
+        } else {
+            self.finish();
+        }
+    }
+
+    fn finish(self) {
+        unsafe {
+            if let Some(s) = self.session {
+                pop_style(s);
+                tmux_bindings::session_remove_ref(s, crate::cstr!("status::Message").as_ptr());
+            }
+            tmux_bindings::server_client_unref(self.client);
+        }
+    }
+}
+
+thread_local! {
+    /// Sessions whose `message-style` is currently overridden, with the session's own
+    /// value of the option and the number of styled messages still being shown.
+    static OVERRIDDEN: RefCell<HashMap<*mut session, (Option<CString>, usize)>> =
+        RefCell::new(HashMap::new());
+}
+
+unsafe fn push_style(s: *mut session, style: &CStr) -> Result<(), InvalidStyle> {
+    let oo = (*s).options;
+    let previous = {
+        let o = tmux_bindings::options_get_only(oo, MESSAGE_STYLE.as_ptr());
+        if o.is_null() {
+            None
+        } else {
+            let value = tmux_bindings::options_tostring(o, -1, 0);
+            let owned = CStr::from_ptr(value).to_owned();
+            super::strings::free(value);
+            Some(owned)
+        }
+    };
+    if tmux_bindings::options_set_style(oo, MESSAGE_STYLE.as_ptr(), 0, style.as_ptr()).is_null() {
+        return Err(InvalidStyle(style.to_owned()));
+    }
+    OVERRIDDEN.with(|overridden| {
+        overridden.borrow_mut().entry(s).or_insert((previous, 0)).1 += 1;
+    });
+    Ok(())
+}
+
+unsafe fn pop_style(s: *mut session) {
+    let restore = OVERRIDDEN.with(|overridden| {
+        let mut overridden = overridden.borrow_mut();
+        let entry = overridden.get_mut(&s)?;
+        entry.1 -= 1;
+        if entry.1 == 0 {
+            overridden.remove(&s).map(|(previous, _)| previous)
+        } else {
+            None
+        }
+    });
+    let oo = (*s).options;
+    match restore {
+        Some(Some(previous)) => {
+            tmux_bindings::options_set_style(oo, MESSAGE_STYLE.as_ptr(), 0, previous.as_ptr());
+        }
+        Some(None) => {
+            let o = tmux_bindings::options_get_only(oo, MESSAGE_STYLE.as_ptr());
+            if !o.is_null() {
+                tmux_bindings::options_remove(o);
+            }
+        }
+        None => {}
+    }
+}
@@ -0,0 +1,471 @@
+//! Converting strings to and from tmux without panicking.
+//!
+//! Much of what plugins show comes from outside, such as process names, file paths and the
+//! output of commands, and may contain null bytes or invalid UTF-8. `CString::new` and
+//! `CStr::to_str` fail on those, and unwrapping them panics inside tmux. The functions here
+//! leave the offending bytes out instead.
+//!
+//! [`display_width`](./fn.display_width.html) and [`markup_width`](./fn.markup_width.html)
+//! measure strings the way tmux does when it draws them, so segments line up with what is
+//! shown, wide characters and all. [`truncate_to_width`](./fn.truncate_to_width.html) and
+//! [`pad_to_width`](./fn.pad_to_width.html) fit strings to a number of columns using them.
+//!
+//! [`sanitize_value`](./fn.sanitize_value.html) makes outside data safe to return from a
+//! format variable, so that it is shown as it is rather than being taken for markup.
+//!
+//! [`OwnedTmuxString`](./struct.OwnedTmuxString.html) is a string allocated for tmux to take
+//! ownership of, such as the value of a format variable, which tmux frees once it is done.
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::strings::{to_tmux_string_lossy, CStrExt};
+//! use tmux_plugin::tmux::Window;
+//!
+//! // The window's name in brackets, with anything odd in it left out.
+//! format_plugin!(b"window_name_bracketed\0", |format_tree| {
+//!     let name = unsafe { Window::from_raw((*format_tree).w) }
+//!         .map(|window| window.name().to_string_sanitized())
+//!         .unwrap_or_default();
+//!     to_tmux_string_lossy(format!("[{}]", name))
+//! });
+//! # fn main() {}
+//! ```
+
+use crate::tmux_bindings;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::os::raw::c_char;
+use std::ptr::NonNull;
+
+/// A string allocated the way tmux allocates its own, so that tmux can free it.
+///
+/// Some values are handed over to tmux, which frees them itself once it is done with them,
+/// such as the value of a format variable, which tmux frees along with the rest of the
+/// variables. These have to be allocated the way tmux allocates, with its `xmalloc` family,
+/// not as a Rust `CString`.
+///
+/// Until it is handed over, with [`hand_off`](#method.hand_off) or
+/// [`into_raw`](#method.into_raw), the string is owned by this value and freed when it is
+/// dropped, so nothing leaks if it is never handed over. Once it is handed over, tmux owns it,
+/// and nothing in Rust can free it again.
+///
+/// For example, in a format callback written by hand, rather than with
+/// [`format_plugin!`](../../macro.format_plugin.html):
+///
+/// ```rust
+/// use tmux_plugin::cstr;
+/// use tmux_plugin::tmux::format_entry;
+/// use tmux_plugin::tmux::strings::OwnedTmuxString;
+///
+/// unsafe extern "C" fn greeting(_ft: *mut tmux_plugin::tmux::format_tree, fe: *mut format_entry) {
+///     let value = OwnedTmuxString::new(cstr!("hello"));
+///     value.hand_off(&mut (*fe).value);
+/// }
+/// # fn main() {}
+/// ```
+pub struct OwnedTmuxString(NonNull<c_char>);
+
+impl OwnedTmuxString {
+    /// Copies a string into memory which tmux can free.
+    ///
+    /// The copy is made with tmux's own `xstrdup`, so it comes from the same allocator tmux
+    /// frees it with, whichever that is. Like the rest of tmux, this aborts if there is no
+    /// memory left.
+    pub fn new(value: &CStr) -> OwnedTmuxString {
+        let copy = unsafe { tmux_bindings::xstrdup(value.as_ptr()) };
+        #[cfg(feature = "leak-debug")]
+        super::leaks::string_copied();
+        OwnedTmuxString(NonNull::new(copy).expect("xstrdup never returns null"))
+    }
+
+    /// Copies a string into memory which tmux can free, leaving out any null bytes and invalid
+    /// UTF-8, as [`to_tmux_string_lossy`](./fn.to_tmux_string_lossy.html) does.
+    pub fn from_lossy<S: AsRef<[u8]>>(value: S) -> OwnedTmuxString {
+        OwnedTmuxString::new(&to_tmux_string_lossy(value))
+    }
+
+    /// The string.
+    pub fn as_c_str(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.0.as_ptr()) }
+    }
+
+    /// Hands the string over to tmux, by storing it where tmux expects to find a string it
+    /// will free, such as a `format_entry`'s `value`.
+    ///
+    /// In debug builds, this panics if there is already a string there, which handing over
+    /// another would leak.
+    ///
+    /// # Safety
+    ///
+    /// tmux must free whatever is stored in `slot` once it is done with it.
+    pub unsafe fn hand_off(self, slot: &mut *mut c_char) {
+        debug_assert!(
+            slot.is_null(),
+            "A string was handed to tmux where it already had one"
+        );
+        *slot = self.into_raw();
+    }
+
+    /// Gives up ownership of the string, returning the pointer for tmux to free.
+    pub fn into_raw(self) -> *mut c_char {
+        #[cfg(feature = "leak-debug")]
+        super::leaks::string_handed_off();
+        let ptr = self.0.as_ptr();
+        std::mem::forget(self);
+        ptr
+    }
+}
+
+impl Drop for OwnedTmuxString {
+    fn drop(&mut self) {
+        #[cfg(feature = "leak-debug")]
+        super::leaks::string_dropped();
+        unsafe { free(self.0.as_ptr()) }
+    }
+}
+
+/// Frees a string which tmux allocated, such as one returned by `format_single`, or one which
+/// was handed over to it.
+///
+/// This is `free()`, except with the `tracked-alloc` feature, described in the crate's
+/// documentation under "Testing", which checks that the string was allocated and hasn't
+/// been freed already. A test can use it to free strings its plugin handed over, as tmux would.
+/// Null is ignored.
+///
+/// For example, a test which frees a string handed over to tmux, as tmux would:
+///
+/// ```rust
+/// # #[cfg(feature = "tracked-alloc")]
+/// # {
+/// use tmux_plugin::cstr;
+/// use tmux_plugin::tmux::strings::{self, OwnedTmuxString};
+/// use std::ffi::CStr;
+/// use std::ptr;
+///
+/// let mut value = ptr::null_mut();
+/// let greeting = OwnedTmuxString::new(cstr!("hello"));
+/// unsafe {
+///     greeting.hand_off(&mut value);
+///     assert_eq!(CStr::from_ptr(value).to_bytes(), b"hello");
+///     strings::free(value);
+/// }
+/// # }
+/// ```
+///
+/// # Safety
+///
+/// The string must be null, or have been allocated by tmux, or with
+/// [`OwnedTmuxString`](./struct.OwnedTmuxString.html), and not have been freed. Nothing may
+/// use it afterwards.
+pub unsafe fn free(s: *mut c_char) {
+    #[cfg(feature = "tracked-alloc")]
+    crate::stub::free(s);
+    #[cfg(not(feature = "tracked-alloc"))]
+    libc::free(s as *mut _);
+}
+
+impl fmt::Debug for OwnedTmuxString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OwnedTmuxString")
+            .field(&self.as_c_str())
+            .finish()
+    }
+}
+
+impl AsRef<CStr> for OwnedTmuxString {
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+/// Converts a string to one which can be passed to tmux, leaving out any null bytes and
+/// invalid UTF-8.
+pub fn to_tmux_string_lossy<S: AsRef<[u8]>>(string: S) -> CString {
+    let string = sanitize(string.as_ref());
+    CString::new(string.replace('\0', "")).expect("Null bytes were left out")
+}
+
+/// Extra conversions for strings from tmux.
+pub trait CStrExt {
+    /// Converts the string to a `String`, leaving out any invalid UTF-8.
+    ///
+    /// Unlike `to_string_lossy`, this doesn't add replacement characters in place of what it
+    /// leaves out, so nothing unexpected ends up on the status line.
+    fn to_string_sanitized(&self) -> String;
+}
+
+impl CStrExt for CStr {
+    fn to_string_sanitized(&self) -> String {
+        sanitize(self.to_bytes())
+    }
+}
+
+/// The number of columns tmux takes to draw a string.
+///
+/// This uses tmux's own idea of how wide each character is, the one it uses to lay out the
+/// status line, so wide characters such as CJK and most emoji count for two columns, and
+/// combining characters and control characters for none. Null bytes are left out.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::strings::{display_width, to_tmux_string_lossy};
+/// use tmux_plugin::tmux::Window;
+///
+/// // As many dashes as the window's name is wide, to underline it with.
+/// format_plugin!("window_name_rule", |format_tree| {
+///     let name = unsafe { Window::from_raw((*format_tree).w) }
+///         .map(|window| window.name().to_string_lossy().into_owned())
+///         .unwrap_or_default();
+///     to_tmux_string_lossy("-".repeat(display_width(&name)))
+/// });
+/// # fn main() {}
+/// ```
+pub fn display_width(string: &str) -> usize {
+    let string = to_tmux_string_lossy(string);
+    unsafe { tmux_bindings::utf8_cstrwidth(string.as_ptr()) as usize }
+}
+
+/// The number of columns tmux takes to draw a string with style markup in it, such as
+/// `#[fg=red]warning#[default]`, as the value of a format variable is drawn.
+///
+/// The markup takes no columns, and `##` takes one, as it is drawn as a single `#`.
+pub fn markup_width(string: &str) -> usize {
+    let string = to_tmux_string_lossy(string);
+    unsafe { tmux_bindings::format_width(string.as_ptr()) as usize }
+}
+
+/// Makes a string safe to show as the value of a format variable, when it comes from
+/// somewhere outside the plugin's control, such as a command's output or a file.
+///
+/// tmux draws the value of a format variable as it is, apart from style markup such as
+/// `#[fg=red]`, which outside data could use to restyle or hide the rest of the status line,
+/// and control characters, which can move the cursor or garble the terminal. So each `#` is
+/// escaped as `##`, newlines and tabs become spaces, and other control characters are left
+/// out. Formats which expand a value again, such as with `#{E:...}`, can't run `#{...}` or
+/// `#(...)` from it either, as the `#` before them is escaped.
+///
+/// [`format_plugin!`](../../macro.format_plugin.html) applies this to a variable's value when
+/// it is marked `sanitized`.
+pub fn sanitize_value(value: &str) -> String {
+    let mut sanitized = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '#' => sanitized.push_str("##"),
+            '\n' | '\r' | '\t' => sanitized.push(' '),
+            c if c.is_control() => {}
+            c => sanitized.push(c),
+        }
+    }
+    sanitized
+}
+
+/// Where [`pad_to_width`](./fn.pad_to_width.html) puts a string in the space it pads it to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// At the start, padded on the right.
+    Left,
+    /// At the end, padded on the left.
+    Right,
+    /// In the middle, with any odd column of padding on the right.
+    Centre,
+}
+
+/// Cuts a string down to at most `width` columns, ending it with `ellipsis` if anything was
+/// cut off.
+///
+/// Characters are never cut in half: if a wide character would straddle the last column, it
+/// is left out, so the result can be a column narrower than `width`. If even the ellipsis is
+/// wider than `width`, the string is cut without one.
+///
+/// For example, with `truncate_to_width("日本語のパス", 7, "…")`, each of the Japanese
+/// characters takes two columns, so three of them fit alongside the ellipsis, in 7 columns.
+pub fn truncate_to_width(string: &str, width: usize, ellipsis: &str) -> String {
+    if display_width(string) <= width {
+        return string.to_owned();
+    }
+    let ellipsis_width = display_width(ellipsis);
+    let (ellipsis, room) = if ellipsis_width <= width {
+        (ellipsis, width - ellipsis_width)
+    } else {
+        ("", width)
+    };
+
+    let mut truncated = String::new();
+    let mut used = 0;
+    for c in string.chars() {
+        let char_width = display_width(c.encode_utf8(&mut [0; 4]));
+        if used + char_width > room {
+            break;
+        }
+        used += char_width;
+        truncated.push(c);
+    }
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+/// Pads a string with spaces to `width` columns, putting it where `align` says.
+///
+/// Strings which are already `width` columns or wider are left as they are; use
+/// [`truncate_to_width`](./fn.truncate_to_width.html) first to cut them down.
+pub fn pad_to_width(string: &str, width: usize, align: Align) -> String {
+    let padding = width.saturating_sub(display_width(string));
+    let (left, right) = match align {
+        Align::Left => (0, padding),
+        Align::Right => (padding, 0),
+        Align::Centre => (padding / 2, padding - padding / 2),
+    };
+    format!("{}{}{}", " ".repeat(left), string, " ".repeat(right))
+}
+
+/// The valid UTF-8 in some bytes.
+fn sanitize(bytes: &[u8]) -> String {
+    bytes.utf8_chunks().map(|chunk| chunk.valid()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether every `#` in a sanitized value is escaped, so that none of them can start
+    /// `#{`, `#(` or `#[`, wherever the value is put.
+    fn all_escaped(sanitized: &str) -> bool {
+        sanitized.split(|c| c != '#').all(|run| run.len() % 2 == 0)
+    }
+
+    #[test]
+    fn sanitize_escapes_formats_and_commands() {
+        assert_eq!(sanitize_value("#{pane_id}"), "##{pane_id}");
+        assert_eq!(sanitize_value("#(rm -rf ~)"), "##(rm -rf ~)");
+        assert_eq!(sanitize_value("#[fg=red]"), "##[fg=red]");
+        assert_eq!(sanitize_value("100#"), "100##");
+    }
+
+    #[test]
+    fn sanitize_escapes_escapes() {
+        assert_eq!(sanitize_value("##"), "####");
+        assert_eq!(sanitize_value("##{pane_id}"), "####{pane_id}");
+        assert_eq!(sanitize_value("###(id)"), "######(id)");
+    }
+
+    #[test]
+    fn sanitize_replaces_line_breaks_and_tabs() {
+        assert_eq!(
+            sanitize_value("one\ntwo\r\nthree\tfour"),
+            "one two  three four"
+        );
+    }
+
+    #[test]
+    fn sanitize_drops_control_characters() {
+        assert_eq!(sanitize_value("a\x1b[31mb\x07c\0d\x7fe"), "a[31mbcde");
+        assert_eq!(sanitize_value("a\u{9b}2Jb"), "a2Jb");
+        assert_eq!(sanitize_value("日本\u{301}"), "日本\u{301}");
+    }
+
+    #[test]
+    fn sanitized_escapes_cannot_be_undone() {
+        // Dropping a character or joining values can't leave a `#` unescaped, since each one
+        // is doubled whatever comes before or after it.
+        let values = [
+            "#",
+            "##",
+            "#\x1b{x}",
+            "#\x07(id)",
+            "#\n{x}",
+            "a#",
+            "#a#b##c###",
+            "\x1b#\x1b#{",
+        ];
+        for value in &values {
+            let sanitized = sanitize_value(value);
+            assert!(
+                all_escaped(&sanitized),
+                "{:?} became {:?}",
+                value,
+                sanitized
+            );
+            for other in &values {
+                let joined = sanitized.clone() + &sanitize_value(other);
+                assert!(all_escaped(&joined), "{:?}", joined);
+            }
+        }
+        assert_eq!(sanitize_value("#\x1b{x}"), "##{x}");
+    }
+}
+
+#[cfg(all(test, feature = "stub"))]
+mod width_tests {
+    use super::*;
+
+    #[test]
+    fn truncate_leaves_short_strings() {
+        assert_eq!(truncate_to_width("abc", 5, "…"), "abc");
+        assert_eq!(truncate_to_width("abc", 3, "…"), "abc");
+        assert_eq!(truncate_to_width("日本", 4, "…"), "日本");
+        assert_eq!(truncate_to_width("", 0, "…"), "");
+    }
+
+    #[test]
+    fn truncate_adds_the_ellipsis() {
+        assert_eq!(truncate_to_width("abcdef", 4, "…"), "abc…");
+        assert_eq!(truncate_to_width("abcdef", 5, "..."), "ab...");
+        assert_eq!(truncate_to_width("abcdef", 3, ""), "abc");
+    }
+
+    #[test]
+    fn truncate_never_splits_wide_characters() {
+        assert_eq!(truncate_to_width("日本語のパス", 7, "…"), "日本語…");
+        // The third character would straddle the last column, so it is left out.
+        assert_eq!(truncate_to_width("日本語のパス", 6, "…"), "日本…");
+        assert_eq!(truncate_to_width("日本語のパス", 6, ""), "日本語");
+        assert_eq!(truncate_to_width("日本語のパス", 5, ""), "日本");
+        assert_eq!(truncate_to_width("a日本", 2, ""), "a");
+        assert_eq!(truncate_to_width("日本", 1, ""), "");
+    }
+
+    #[test]
+    fn truncate_to_zero_width() {
+        assert_eq!(truncate_to_width("abc", 0, "…"), "");
+        assert_eq!(truncate_to_width("日本", 0, ""), "");
+    }
+
+    #[test]
+    fn truncate_without_room_for_the_ellipsis() {
+        assert_eq!(truncate_to_width("abcdef", 2, "..."), "ab");
+        assert_eq!(truncate_to_width("abc", 1, "日"), "a");
+    }
+
+    #[test]
+    fn truncate_keeps_zero_width_characters_with_their_base() {
+        assert_eq!(truncate_to_width("e\u{301}xyz", 2, ""), "e\u{301}x");
+        assert_eq!(truncate_to_width("ab\u{301}c", 2, ""), "ab\u{301}");
+        assert_eq!(truncate_to_width("ab\u{301}", 2, "…"), "ab\u{301}");
+    }
+
+    #[test]
+    fn pad_aligns() {
+        assert_eq!(pad_to_width("ab", 5, Align::Left), "ab   ");
+        assert_eq!(pad_to_width("ab", 5, Align::Right), "   ab");
+        assert_eq!(pad_to_width("ab", 5, Align::Centre), " ab  ");
+        assert_eq!(pad_to_width("", 2, Align::Centre), "  ");
+    }
+
+    #[test]
+    fn pad_counts_columns() {
+        assert_eq!(pad_to_width("日本", 5, Align::Left), "日本 ");
+        assert_eq!(pad_to_width("日本", 6, Align::Centre), " 日本 ");
+        assert_eq!(pad_to_width("e\u{301}", 3, Align::Right), "  e\u{301}");
+    }
+
+    #[test]
+    fn pad_leaves_wide_enough_strings() {
+        assert_eq!(pad_to_width("abc", 3, Align::Left), "abc");
+        assert_eq!(pad_to_width("日本語", 4, Align::Centre), "日本語");
+        assert_eq!(pad_to_width("abc", 0, Align::Right), "abc");
+    }
+}
@@ -0,0 +1,522 @@
+use super::Colour;
+use crate::tmux_bindings::{
+    COLOUR_FLAG_256, COLOUR_FLAG_RGB, GRID_ATTR_BLINK, GRID_ATTR_BRIGHT, GRID_ATTR_DIM,
+    GRID_ATTR_HIDDEN, GRID_ATTR_ITALICS, GRID_ATTR_REVERSE, GRID_ATTR_STRIKETHROUGH,
+    GRID_ATTR_UNDERSCORE,
+};
+use bitflags::bitflags;
+use std::ffi::CString;
+use std::fmt;
+
+bitflags! {
+    /// The attributes of a [`Style`](./struct.Style.html), such as bold or italics.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct Attributes: u16 {
+        const BOLD = GRID_ATTR_BRIGHT as u16;
+        const DIM = GRID_ATTR_DIM as u16;
+        const UNDERSCORE = GRID_ATTR_UNDERSCORE as u16;
+        const BLINK = GRID_ATTR_BLINK as u16;
+        const REVERSE = GRID_ATTR_REVERSE as u16;
+        const HIDDEN = GRID_ATTR_HIDDEN as u16;
+        const ITALICS = GRID_ATTR_ITALICS as u16;
+        const STRIKETHROUGH = GRID_ATTR_STRIKETHROUGH as u16;
+    }
+}
+
+/// The names tmux gives attributes in styles.
+const ATTRIBUTE_NAMES: &[(Attributes, &str)] = &[
+    (Attributes::BOLD, "bold"),
+    (Attributes::DIM, "dim"),
+    (Attributes::UNDERSCORE, "underscore"),
+    (Attributes::BLINK, "blink"),
+    (Attributes::REVERSE, "reverse"),
+    (Attributes::HIDDEN, "hidden"),
+    (Attributes::ITALICS, "italics"),
+    (Attributes::STRIKETHROUGH, "strikethrough"),
+];
+
+/// A style for text in the status line, or anywhere else tmux draws formats.
+///
+/// Styles are written the way tmux writes them in options, such as `fg=red,bg=default,bold`.
+/// A style with no colours or attributes is written as `default`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    fg: Option<Colour>,
+    bg: Option<Colour>,
+    attributes: Attributes,
+}
+
+impl Style {
+    /// A style which leaves the colours and attributes as they are.
+    pub fn new() -> Style {
+        Style::default()
+    }
+
+    /// Sets the foreground colour.
+    pub fn fg(mut self, colour: Colour) -> Style {
+        self.fg = Some(colour);
+        self
+    }
+
+    /// Sets the background colour.
+    pub fn bg(mut self, colour: Colour) -> Style {
+        self.bg = Some(colour);
+        self
+    }
+
+    /// Adds attributes.
+    pub fn attributes(mut self, attributes: Attributes) -> Style {
+        self.attributes |= attributes;
+        self
+    }
+
+    /// Adds the bold attribute.
+    pub fn bold(self) -> Style {
+        self.attributes(Attributes::BOLD)
+    }
+
+    /// Adds the dim attribute.
+    pub fn dim(self) -> Style {
+        self.attributes(Attributes::DIM)
+    }
+
+    /// Adds the italics attribute.
+    pub fn italics(self) -> Style {
+        self.attributes(Attributes::ITALICS)
+    }
+
+    /// Adds the underscore attribute.
+    pub fn underscore(self) -> Style {
+        self.attributes(Attributes::UNDERSCORE)
+    }
+
+    /// Adds the reverse attribute, swapping the foreground and background colours.
+    pub fn reverse(self) -> Style {
+        self.attributes(Attributes::REVERSE)
+    }
+
+    /// Whether the style changes nothing.
+    pub fn is_default(&self) -> bool {
+        *self == Style::default()
+    }
+
+    /// Text in this style.
+    pub fn span(self, text: &str) -> StyledSpan {
+        StyledSpan::new(self, text)
+    }
+}
+
+impl Style {
+    /// The style after the parameters of an SGR escape sequence, such as `1;31`.
+    fn apply_sgr(mut self, params: &str) -> Style {
+        let mut params = params
+            .split([';', ':'])
+            .map(|param| param.parse::<u32>().unwrap_or(0));
+        while let Some(param) = params.next() {
+            match param {
+                0 => self = Style::default(),
+                1..=9 => self.attributes |= sgr_attribute(param),
+                21 | 22 => self.attributes -= Attributes::BOLD | Attributes::DIM,
+                23..=29 => self.attributes -= sgr_attribute(param - 20),
+                30..=37 => self.fg = Some(Colour(param as i32 - 30)),
+                38 => self.fg = sgr_colour(&mut params),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(Colour(param as i32 - 40)),
+                48 => self.bg = sgr_colour(&mut params),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(Colour(param as i32)),
+                100..=107 => self.bg = Some(Colour(param as i32)),
+                _ => {}
+            }
+        }
+        self
+    }
+}
+
+/// The attribute an SGR parameter from 1 to 9 turns on.
+fn sgr_attribute(param: u32) -> Attributes {
+    match param {
+        1 => Attributes::BOLD,
+        2 => Attributes::DIM,
+        3 => Attributes::ITALICS,
+        4 => Attributes::UNDERSCORE,
+        5 | 6 => Attributes::BLINK,
+        7 => Attributes::REVERSE,
+        8 => Attributes::HIDDEN,
+        9 => Attributes::STRIKETHROUGH,
+        _ => Attributes::empty(),
+    }
+}
+
+/// The colour after an SGR `38` or `48`, either `5;n` from the 256 colour palette, or
+/// `2;r;g;b`.
+fn sgr_colour<I: Iterator<Item = u32>>(params: &mut I) -> Option<Colour> {
+    let component = |param: Option<u32>| param.unwrap_or(0).min(255) as i32;
+    match params.next() {
+        Some(5) => Some(Colour(component(params.next()) | COLOUR_FLAG_256 as i32)),
+        Some(2) => {
+            let (r, g, b) = (params.next(), params.next(), params.next());
+            let rgb = component(r) << 16 | component(g) << 8 | component(b);
+            Some(Colour(rgb | COLOUR_FLAG_RGB as i32))
+        }
+        _ => None,
+    }
+}
+
+impl fmt::Display for Style {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(fg) = self.fg {
+            parts.push(format!("fg={}", fg));
+        }
+        if let Some(bg) = self.bg {
+            parts.push(format!("bg={}", bg));
+        }
+        for (attribute, name) in ATTRIBUTE_NAMES {
+            if self.attributes.contains(*attribute) {
+                parts.push((*name).to_owned());
+            }
+        }
+        if parts.is_empty() {
+            f.write_str("default")
+        } else {
+            f.write_str(&parts.join(","))
+        }
+    }
+}
+
+/// Text shown in a [`Style`](./struct.Style.html).
+///
+/// Written out, a span is the text with `#` escaped as `##`, after the style's markup, such as
+/// `#[fg=red,bold]`, and followed by `#[default]`, so that the style doesn't carry on into
+/// whatever comes after it. This is markup for the value of a format variable, which tmux
+/// draws as it is. Formats for options, in which `,` and `}` have to be escaped too, are built
+/// with [`Format`](./format/struct.Format.html) instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StyledSpan {
+    style: Style,
+    text: String,
+}
+
+impl StyledSpan {
+    /// Text in a style.
+    pub fn new(style: Style, text: &str) -> StyledSpan {
+        StyledSpan {
+            style,
+            text: text.to_owned(),
+        }
+    }
+
+    /// Text in the default style, written out without any markup.
+    pub fn plain(text: &str) -> StyledSpan {
+        StyledSpan::new(Style::default(), text)
+    }
+
+    /// The span's style.
+    pub fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The span's text, before it is escaped.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for StyledSpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.style.is_default() {
+            write!(f, "#[{}]", self.style)?;
+        }
+        for c in self.text.chars().filter(|&c| c != '\0') {
+            if c == '#' {
+                f.write_str("#")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        if !self.style.is_default() {
+            f.write_str("#[default]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Spans of styled text, one after the other, such as a status line segment.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::{Colour, Style, StyledText};
+///
+/// // Shows the number of unread mails, in red when there are any.
+/// format_plugin!("mail_unread", |_format_tree| {
+///     let unread = 3;
+///     let red = Colour::parse("red").expect("red is a colour");
+///     StyledText::new()
+///         .text("mail: ")
+///         .span(Style::new().fg(red).bold().span(&format!("#{}", unread)))
+///         .into_c_string()
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StyledText(Vec<StyledSpan>);
+
+impl StyledText {
+    /// No text.
+    pub fn new() -> StyledText {
+        StyledText::default()
+    }
+
+    /// Adds a span.
+    pub fn span(mut self, span: StyledSpan) -> StyledText {
+        self.0.push(span);
+        self
+    }
+
+    /// Adds text in the default style.
+    pub fn text(self, text: &str) -> StyledText {
+        self.span(StyledSpan::plain(text))
+    }
+
+    /// Converts text coloured with ANSI escape sequences, as many commands print when writing
+    /// to a terminal, such as `ls --color=always` or `git log --color`.
+    ///
+    /// The colours and attributes set by SGR sequences (`ESC [ ... m`) are kept, including
+    /// those from the 256 colour palette and 24-bit colours. Any other escape sequences, such
+    /// as ones moving the cursor or setting the terminal's title, are dropped.
+    ///
+    /// For example:
+    ///
+    /// ```rust
+    /// use tmux_plugin::format_plugin;
+    /// use tmux_plugin::tmux::StyledText;
+    /// use std::process::Command;
+    ///
+    /// // The status of the current directory's git repository, as git colours it.
+    /// format_plugin!("git_status", |_format_tree| {
+    ///     let output = Command::new("git")
+    ///         .args(&["-c", "color.status=always", "status", "--short", "--branch"])
+    ///         .output();
+    ///     let output = output.map(|output| output.stdout).unwrap_or_default();
+    ///     let first_line = String::from_utf8_lossy(&output).lines().next().map(str::to_owned);
+    ///     StyledText::from_ansi(&first_line.unwrap_or_default()).into_c_string()
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn from_ansi(text: &str) -> StyledText {
+        let mut styled = StyledText::new();
+        let mut style = Style::default();
+        let mut run = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                run.push(c);
+                continue;
+            }
+            match chars.next() {
+                Some('[') => {
+                    let mut params = String::new();
+                    let mut end = None;
+                    for c in chars.by_ref() {
+                        if ('\x40'..='\x7e').contains(&c) {
+                            end = Some(c);
+                            break;
+                        }
+                        params.push(c);
+                    }
+                    if end == Some('m') {
+                        let next = style.apply_sgr(&params);
+                        if next != style && !run.is_empty() {
+                            styled = styled.span(StyledSpan::new(style, &run));
+                            run.clear();
+                        }
+                        style = next;
+                    }
+                }
+                // Operating system commands, such as setting the title, end with BEL or ST.
+                Some(']') => {
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                // Sequences with intermediate bytes, such as `ESC ( B` selecting a character set,
+                // end with the first byte after them.
+                Some(' '..='/') => {
+                    for c in chars.by_ref() {
+                        if !(' '..='/').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if !run.is_empty() {
+            styled = styled.span(StyledSpan::new(style, &run));
+        }
+        styled
+    }
+
+    /// The spans, in order.
+    pub fn spans(&self) -> &[StyledSpan] {
+        &self.0
+    }
+
+    /// The markup, for returning from a plugin.
+    pub fn into_c_string(self) -> CString {
+        CString::new(self.to_string()).expect("Spans leave out null bytes")
+    }
+}
+
+impl fmt::Display for StyledText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|span| span.fmt(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The spans of converted text, as pairs of style and text.
+    fn spans(text: &str) -> Vec<(Style, String)> {
+        StyledText::from_ansi(text)
+            .spans()
+            .iter()
+            .map(|span| (span.style(), span.text().to_owned()))
+            .collect()
+    }
+
+    fn plain(text: &str) -> (Style, String) {
+        (Style::new(), text.to_owned())
+    }
+
+    #[test]
+    fn plain_text_is_one_unstyled_span() {
+        assert_eq!(spans("hello"), vec![plain("hello")]);
+        assert_eq!(spans(""), vec![]);
+    }
+
+    #[test]
+    fn basic_colours_and_attributes() {
+        assert_eq!(
+            spans("\x1b[1;31merror\x1b[0m: \x1b[4;42mok\x1b[m"),
+            vec![
+                (Style::new().fg(Colour(1)).bold(), "error".to_owned()),
+                plain(": "),
+                (Style::new().bg(Colour(2)).underscore(), "ok".to_owned()),
+            ]
+        );
+        assert_eq!(
+            spans("\x1b[95mbright\x1b[104m"),
+            vec![(Style::new().fg(Colour(95)), "bright".to_owned())]
+        );
+    }
+
+    #[test]
+    fn palette_colours() {
+        let orange = Colour(208 | COLOUR_FLAG_256 as i32);
+        assert_eq!(
+            spans("\x1b[38;5;208mA\x1b[48;5;208mB"),
+            vec![
+                (Style::new().fg(orange), "A".to_owned()),
+                (Style::new().fg(orange).bg(orange), "B".to_owned()),
+            ]
+        );
+        assert_eq!(
+            spans("\x1b[38:5:208mA"),
+            vec![(Style::new().fg(orange), "A".to_owned())]
+        );
+        // Out of range indexes are clamped to the palette.
+        let last = Colour(255 | COLOUR_FLAG_256 as i32);
+        assert_eq!(
+            spans("\x1b[38;5;99999mA"),
+            vec![(Style::new().fg(last), "A".to_owned())]
+        );
+    }
+
+    #[test]
+    fn rgb_colours() {
+        let rgb = Colour(0x0a141e | COLOUR_FLAG_RGB as i32);
+        assert_eq!(
+            spans("\x1b[38;2;10;20;30mA\x1b[39;48;2;10;20;30mB"),
+            vec![
+                (Style::new().fg(rgb), "A".to_owned()),
+                (Style::new().bg(rgb), "B".to_owned()),
+            ]
+        );
+        // Missing components are zero, and large ones are clamped.
+        let red = Colour(0xff0000 | COLOUR_FLAG_RGB as i32);
+        assert_eq!(
+            spans("\x1b[38;2;300mA"),
+            vec![(Style::new().fg(red), "A".to_owned())]
+        );
+    }
+
+    #[test]
+    fn resets() {
+        assert_eq!(
+            spans("\x1b[1;2;3mA\x1b[22mB\x1b[23mC\x1b[0;7mD\x1b[27mE"),
+            vec![
+                (Style::new().bold().dim().italics(), "A".to_owned()),
+                (Style::new().italics(), "B".to_owned()),
+                plain("C"),
+                (Style::new().reverse(), "D".to_owned()),
+                plain("E"),
+            ]
+        );
+        assert_eq!(
+            spans("\x1b[31;41mA\x1b[39mB\x1b[49mC"),
+            vec![
+                (Style::new().fg(Colour(1)).bg(Colour(1)), "A".to_owned()),
+                (Style::new().bg(Colour(1)), "B".to_owned()),
+                plain("C"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_codes_are_ignored() {
+        assert_eq!(spans("\x1b[999;53mA"), vec![plain("A")]);
+        // A parameter too large for a number is read as a reset.
+        assert_eq!(spans("\x1b[1mA\x1b[4294967296mB"), {
+            vec![(Style::new().bold(), "A".to_owned()), plain("B")]
+        });
+        // A colour with an unknown form leaves it unset.
+        assert_eq!(spans("\x1b[31mA\x1b[38;7mB"), {
+            vec![(Style::new().fg(Colour(1)), "A".to_owned()), plain("B")]
+        });
+    }
+
+    #[test]
+    fn other_sequences_are_dropped() {
+        assert_eq!(spans("a\x1b[2Jb\x1b[10;5Hc"), vec![plain("abc")]);
+        assert_eq!(spans("\x1b]0;title\x07a\x1b]2;t\x1b\\b"), vec![plain("ab")]);
+        assert_eq!(spans("a\x1b(Bb\x1b(B\x1b[mc"), vec![plain("abc")]);
+    }
+
+    #[test]
+    fn truncated_sequences() {
+        assert_eq!(spans("abc\x1b"), vec![plain("abc")]);
+        assert_eq!(spans("abc\x1b["), vec![plain("abc")]);
+        assert_eq!(spans("abc\x1b[38;5"), vec![plain("abc")]);
+        assert_eq!(spans("abc\x1b]0;unterminated title"), vec![plain("abc")]);
+        assert_eq!(
+            spans("\x1b[38;5mA"),
+            vec![(
+                Style::new().fg(Colour(COLOUR_FLAG_256 as i32)),
+                "A".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn styles_without_text_make_no_spans() {
+        assert_eq!(spans("\x1b[31m\x1b[32m\x1b[0m"), vec![]);
+        assert_eq!(spans("\x1b[31m\x1b[0mA"), vec![plain("A")]);
+    }
+}
@@ -0,0 +1,149 @@
+//! Commands made up of several subcommands, such as `todo add|remove|list`.
+//!
+//! See [`subcommands!`](../../macro.subcommands.html).
+
+use super::{cmd_retval, CmdqItem};
+use crate::tmux_bindings;
+use std::ffi::{CStr, CString};
+
+/// A subcommand's name, usage, and how many arguments it takes.
+#[doc(hidden)]
+pub struct Spec {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub min: usize,
+    /// The most arguments the subcommand takes, or -1 for no limit, as in `cmd_plugin!`.
+    pub max: i32,
+}
+
+impl Spec {
+    fn usage(&self) -> String {
+        if self.usage.is_empty() {
+            self.name.to_owned()
+        } else {
+            format!("{} {}", self.name, self.usage)
+        }
+    }
+}
+
+/// Checks that a subcommand was given the right number of arguments, reporting its usage
+/// otherwise.
+#[doc(hidden)]
+pub fn check(item: CmdqItem, spec: &Spec, count: usize) -> Result<(), cmd_retval> {
+    let too_many = spec.max >= 0 && count > spec.max as usize;
+    if count < spec.min || too_many {
+        return Err(error(item, &format!("usage: {}", spec.usage())));
+    }
+    Ok(())
+}
+
+/// Reports a missing or unknown subcommand, along with the usage of each subcommand.
+#[doc(hidden)]
+pub fn unknown(item: CmdqItem, subcommand: Option<&CStr>, specs: &[Spec]) -> cmd_retval {
+    let usages = specs.iter().map(Spec::usage).collect::<Vec<_>>();
+    let message = match subcommand {
+        Some(subcommand) => format!(
+            "unknown subcommand: {} (usage: {})",
+            subcommand.to_string_lossy(),
+            usages.join(" | ")
+        ),
+        None => format!("missing subcommand (usage: {})", usages.join(" | ")),
+    };
+    error(item, &message)
+}
+
+fn error(item: CmdqItem, message: &str) -> cmd_retval {
+    item.error(&CString::new(message.replace('\0', "")).expect("Null bytes were removed"));
+    tmux_bindings::cmd_retval_CMD_RETURN_ERROR
+}
+
+/// Runs one of several subcommands, picked by a command's first argument.
+///
+/// This takes a command's arguments and [`CmdqItem`](./tmux/struct.CmdqItem.html), as passed
+/// to a [`cmd_plugin!`](./macro.cmd_plugin.html) body, and a list of subcommands. Each has a
+/// name, then in brackets the usage of its own arguments and the least and most arguments it
+/// takes (or -1 for no limit), then a handler. The handler is passed the arguments after the
+/// subcommand's name, and returns anything a command body can, such as a `cmd_retval` or a
+/// [`Result`](./type.Result.html), declared after its arguments as in `cmd_plugin!`.
+///
+/// When the subcommand is missing, unknown, or given the wrong number of arguments, the
+/// command fails with a message showing the usage of the subcommands.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, subcommands};
+/// use std::cell::RefCell;
+/// use std::ffi::CString;
+///
+/// thread_local! {
+///     static TODOS: RefCell<Vec<String>> = RefCell::new(Vec::new());
+/// }
+///
+/// cmd_plugin!("todo", "", "add|remove|list ...", 1, -1, |_cmd, args, item| {
+///     subcommands!(args, item, {
+///         "add" ("text ...", 1, -1) => |args| {
+///             let text = args.map(|arg| arg.to_string_lossy()).collect::<Vec<_>>().join(" ");
+///             TODOS.with(|todos| todos.borrow_mut().push(text));
+///         },
+///         "remove" ("index", 1, 1) => |args| -> tmux_plugin::Result<()> {
+///             let index: usize = args.last().unwrap().to_str()?.parse().unwrap_or(usize::MAX);
+///             TODOS.with(|todos| {
+///                 let mut todos = todos.borrow_mut();
+///                 if index < todos.len() {
+///                     todos.remove(index);
+///                 }
+///             });
+///             Ok(())
+///         },
+///         "list" ("", 0, 0) => |_args| {
+///             TODOS.with(|todos| {
+///                 for (i, todo) in todos.borrow().iter().enumerate() {
+///                     item.print(&CString::new(format!("{}: {}", i, todo)).unwrap_or_default());
+///                 }
+///             });
+///         },
+///     })
+/// });
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! subcommands {
+    ($args:expr, $item:expr, {
+        $($name:literal ($usage:expr, $min:expr, $max:expr) => |$handler_args:ident| $(-> $ret:ty)? $body:block),+ $(,)?
+    }) => {{
+        let item: $crate::tmux::CmdqItem = $item;
+        let args = $args.collect::<::std::vec::Vec<&::std::ffi::CStr>>();
+        let specs = [$(
+            $crate::tmux::subcommands::Spec {
+                name: $name,
+                usage: $usage,
+                min: $min,
+                max: $max,
+            }
+        ),+];
+        let mut index = 0;
+        let mut retval = None;
+        $(
+            if retval.is_none() && args.first().map(|arg| arg.to_bytes()) == Some($name.as_bytes()) {
+                retval = Some(match $crate::tmux::subcommands::check(item, &specs[index], args.len() - 1) {
+                    Ok(()) => {
+                        #[allow(unused_mut)]
+                        let mut handler = |$handler_args: ::std::iter::Copied<::std::slice::Iter<&::std::ffi::CStr>>| $(-> $ret)? { $body };
+                        $crate::tmux::IntoCmdRetval::into_cmd_retval(
+                            handler(args[1..].iter().copied()),
+                            item,
+                        )
+                    }
+                    Err(retval) => retval,
+                });
+            }
+            index += 1;
+        )+
+        let _ = index;
+        match retval {
+            Some(retval) => retval,
+            None => $crate::tmux::subcommands::unknown(item, args.first().copied(), &specs),
+        }
+    }};
+}
@@ -0,0 +1,127 @@
+use super::maybe::Unsupported;
+use std::ffi::CString;
+use std::marker::PhantomData;
+use std::mem;
+use std::os::raw::c_void;
+use std::sync::OnceLock;
+
+/// Whether the tmux binary the plugin was loaded into has a function or variable by this
+/// name.
+///
+/// Builds of the fork differ in what they have, so a plugin can check for a newer facility
+/// before using it, rather than failing to load wherever it's missing. Anything the plugin
+/// links against directly has to be there for the plugin to load at all, so a function which
+/// might not be has to be called through a [`Symbol`](./struct.Symbol.html) instead.
+///
+/// ```rust
+/// use tmux_plugin::tmux;
+///
+/// assert!(!tmux::has_symbol("no_such_tmux_function"));
+/// ```
+pub fn has_symbol(name: &str) -> bool {
+    lookup(name).is_some()
+}
+
+/// A function tmux might not have, looked up by name the first time it is needed.
+///
+/// For example, a variable with the word under the cursor, from `format_grid_word`, which
+/// builds of the fork from before tmux 3.1 don't have:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::{strings, Symbol};
+/// use std::ffi::{CStr, CString};
+/// use std::os::raw::{c_char, c_void};
+///
+/// type FormatGridWord = unsafe extern "C" fn(gd: *mut c_void, x: u32, y: u32) -> *mut c_char;
+///
+/// static FORMAT_GRID_WORD: Symbol<FormatGridWord> =
+///     unsafe { Symbol::new("format_grid_word") };
+///
+/// format_plugin!("cursor_word", |format_tree| {
+///     let wp = unsafe { (*format_tree).wp };
+///     match FORMAT_GRID_WORD.get() {
+///         Some(format_grid_word) if !wp.is_null() => unsafe {
+///             let screen = &(*wp).base;
+///             let word = format_grid_word(screen.grid as *mut c_void, screen.cx, screen.cy);
+///             if word.is_null() {
+///                 return CString::default();
+///             }
+///             let copy = CStr::from_ptr(word).to_owned();
+///             strings::free(word);
+///             copy
+///         },
+///         _ => CString::default(),
+///     }
+/// });
+/// # fn main() {}
+/// ```
+pub struct Symbol<F> {
+    name: &'static str,
+    address: OnceLock<usize>,
+    function: PhantomData<F>,
+}
+
+impl<F: Copy> Symbol<F> {
+    /// A function to look up by name.
+    ///
+    /// # Safety
+    ///
+    /// `F` must be a function pointer type matching the function's signature in every build of
+    /// tmux which has it, since calling it through any other type is undefined behaviour.
+    pub const unsafe fn new(name: &'static str) -> Symbol<F> {
+        Symbol {
+            name,
+            address: OnceLock::new(),
+            function: PhantomData,
+        }
+    }
+
+    /// The name looked up.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The function, or `None` if tmux doesn't have it. The lookup is only done the first time.
+    pub fn get(&self) -> Option<F> {
+        assert_eq!(
+            mem::size_of::<F>(),
+            mem::size_of::<*mut c_void>(),
+            "A Symbol's type must be a function pointer"
+        );
+        let address = *self
+            .address
+            .get_or_init(|| lookup(self.name).map_or(0, |address| address as usize));
+        if address == 0 {
+            None
+        } else {
+            Some(unsafe { mem::transmute_copy::<usize, F>(&address) })
+        }
+    }
+
+    /// The function, or an [`Unsupported`](./maybe/struct.Unsupported.html) error naming it
+    /// if tmux doesn't have it.
+    ///
+    /// ```rust
+    /// use tmux_plugin::tmux::Symbol;
+    ///
+    /// static MISSING: Symbol<unsafe extern "C" fn()> = unsafe { Symbol::new("no_such_function") };
+    ///
+    /// let err = MISSING.require().unwrap_err();
+    /// assert_eq!(err.to_string(), "this version of tmux has no no_such_function");
+    /// ```
+    pub fn require(&self) -> Result<F, Unsupported> {
+        self.get().ok_or_else(|| Unsupported::new(self.name))
+    }
+}
+
+/// The address of a symbol in tmux, or any library loaded into it.
+fn lookup(name: &str) -> Option<*mut c_void> {
+    let name = CString::new(name).ok()?;
+    let address = unsafe { libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr()) };
+    if address.is_null() {
+        None
+    } else {
+        Some(address)
+    }
+}
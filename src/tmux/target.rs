@@ -0,0 +1,777 @@
+//! Targets, in the syntax tmux commands take with `-t`, such as `mysession:2.1`.
+//!
+//! A [`Target`](./enum.Target.html) is parsed into its session, window and pane, each of which
+//! can be given by ID, index, name or a token such as `{last}`, and can be
+//! [resolved](./enum.Target.html#method.resolve) the same way tmux resolves the targets of its
+//! own commands:
+//!
+//! ```rust
+//! use tmux_plugin::cmd_plugin;
+//! use tmux_plugin::tmux::target::Target;
+//!
+//! // Selects a pane, given as a target.
+//! cmd_plugin!("focus", "", "target", 1, 1, |_cmd, args, item| -> tmux_plugin::Result<()> {
+//!     let target: Target = args.last().unwrap().to_str()?.parse()?;
+//!     target.resolve(item)?.pane.select();
+//!     Ok(())
+//! });
+//! # fn main() {}
+//! ```
+//!
+//! Plugins which already know what they want to act on, such as a pane by its ID, can have
+//! tmux fill in the rest with [`find_pane`](./fn.find_pane.html),
+//! [`find_window_by_name`](./fn.find_window_by_name.html) and
+//! [`current_for_client`](./fn.current_for_client.html), which pick the session and window the
+//! same way builtin commands do.
+
+use super::{Client, CmdqItem, Pane, PaneId, Session, SessionId, Window, WindowId};
+use crate::tmux_bindings::{
+    self, cmd_find_state, cmd_find_type_CMD_FIND_PANE, winlink, CMD_FIND_QUIET,
+};
+use crate::{Error, Result};
+use std::error;
+use std::ffi::{CStr, CString};
+use std::fmt;
+use std::mem;
+use std::os::raw::c_int;
+use std::ptr;
+use std::str::FromStr;
+
+/// Where a command should act, as given with `-t`.
+///
+/// ```rust
+/// use tmux_plugin::tmux::target::{PaneTarget, SessionTarget, Target, WindowTarget};
+///
+/// let target = Target::parse("mysession:2.1").unwrap();
+/// assert_eq!(
+///     target,
+///     Target::Parts {
+///         session: Some(SessionTarget::Name("mysession".to_owned())),
+///         window: Some(WindowTarget::Index(2)),
+///         pane: Some(PaneTarget::Index(1)),
+///     }
+/// );
+/// assert_eq!(target.to_string(), "mysession:2.1");
+///
+/// assert_eq!(Target::parse("{marked}").unwrap(), Target::Marked);
+/// assert!(Target::parse("work:@x").is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Target {
+    /// A session, window and pane, as `session:window.pane`, with any of them left out to
+    /// use the current one. An empty target is the current pane.
+    Parts {
+        session: Option<SessionTarget>,
+        window: Option<WindowTarget>,
+        pane: Option<PaneTarget>,
+    },
+    /// A target with no `:` or `.`, such as `2` or `editor`, which tmux tries as a pane, then
+    /// a window, then a session.
+    Bare(String),
+    /// The pane the mouse is over, `{mouse}` or `=`.
+    Mouse,
+    /// The marked pane, `{marked}` or `~`.
+    Marked,
+}
+
+/// The session part of a [`Target`](./enum.Target.html).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SessionTarget {
+    /// A session by its ID, `$1`.
+    Id(SessionId),
+    /// A session by name: the one with exactly that name, or else the only one whose name
+    /// starts with it or matches it as a pattern, such as `work*`.
+    Name(String),
+    /// The session with exactly this name, `=work`.
+    ExactName(String),
+}
+
+/// The window part of a [`Target`](./enum.Target.html).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowTarget {
+    /// A window by its ID, `@1`.
+    Id(WindowId),
+    /// The window at an index in the session.
+    Index(u32),
+    /// A window by name: the one with exactly that name, or else the only one whose name
+    /// starts with it or matches it as a pattern.
+    Name(String),
+    /// The window with exactly this name, `=editor`.
+    ExactName(String),
+    /// The lowest-numbered window, `{start}` or `^`.
+    Start,
+    /// The highest-numbered window, `{end}` or `$`.
+    End,
+    /// The previously current window, `{last}` or `!`.
+    Last,
+    /// A number of windows after the current one, `{next}` or `+` for one, or `+2`.
+    Next(u32),
+    /// A number of windows before the current one, `{previous}` or `-` for one, or `-2`.
+    Previous(u32),
+}
+
+/// The pane part of a [`Target`](./enum.Target.html).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PaneTarget {
+    /// A pane by its ID, `%1`.
+    Id(PaneId),
+    /// The pane at an index in the window.
+    Index(u32),
+    /// The previously active pane, `{last}` or `!`.
+    Last,
+    /// A number of panes after the active one, `{next}` or `+` for one, or `+2`.
+    Next(u32),
+    /// A number of panes before the active one, `{previous}` or `-` for one, or `-2`.
+    Previous(u32),
+    /// The top pane, `{top}`.
+    Top,
+    /// The bottom pane, `{bottom}`.
+    Bottom,
+    /// The leftmost pane, `{left}`.
+    Left,
+    /// The rightmost pane, `{right}`.
+    Right,
+    /// The top left pane, `{top-left}`.
+    TopLeft,
+    /// The top right pane, `{top-right}`.
+    TopRight,
+    /// The bottom left pane, `{bottom-left}`.
+    BottomLeft,
+    /// The bottom right pane, `{bottom-right}`.
+    BottomRight,
+    /// The pane above the active one, `{up-of}`.
+    UpOf,
+    /// The pane below the active one, `{down-of}`.
+    DownOf,
+    /// The pane to the left of the active one, `{left-of}`.
+    LeftOf,
+    /// The pane to the right of the active one, `{right-of}`.
+    RightOf,
+}
+
+/// The pane tokens, with what they're written as and the other name tmux accepts for them, if
+/// it has one.
+const PANE_TOKENS: &[(PaneTarget, &str, Option<&str>)] = &[
+    (PaneTarget::Top, "{top}", Some("top")),
+    (PaneTarget::Bottom, "{bottom}", Some("bottom")),
+    (PaneTarget::Left, "{left}", Some("left")),
+    (PaneTarget::Right, "{right}", Some("right")),
+    (PaneTarget::TopLeft, "{top-left}", Some("top-left")),
+    (PaneTarget::TopRight, "{top-right}", Some("top-right")),
+    (PaneTarget::BottomLeft, "{bottom-left}", Some("bottom-left")),
+    (
+        PaneTarget::BottomRight,
+        "{bottom-right}",
+        Some("bottom-right"),
+    ),
+    (PaneTarget::UpOf, "{up-of}", None),
+    (PaneTarget::DownOf, "{down-of}", None),
+    (PaneTarget::LeftOf, "{left-of}", None),
+    (PaneTarget::RightOf, "{right-of}", None),
+];
+
+impl Target {
+    /// Parses a target, splitting it into its parts the same way tmux does.
+    pub fn parse(target: &str) -> std::result::Result<Target, InvalidTarget> {
+        let invalid = |reason| InvalidTarget {
+            target: target.to_owned(),
+            reason,
+        };
+        match target {
+            "{mouse}" | "=" => return Ok(Target::Mouse),
+            "{marked}" | "~" => return Ok(Target::Marked),
+            _ => {}
+        }
+        let (session, rest) = match target.find(':') {
+            Some(colon) => (Some(&target[..colon]), &target[colon + 1..]),
+            None => (None, target),
+        };
+        let (window, pane) = match rest.find('.') {
+            Some(period) => (Some(&rest[..period]), Some(&rest[period + 1..])),
+            None if session.is_some() => (Some(rest), None),
+            None => (None, None),
+        };
+        if session.is_none() && window.is_none() {
+            // Without a `:` or `.`, only an ID says which part the target is.
+            return match target.chars().next() {
+                Some('$') => Ok(Target::Parts {
+                    session: parse_session(target).map_err(invalid)?,
+                    window: None,
+                    pane: None,
+                }),
+                Some('@') => Ok(Target::Parts {
+                    session: None,
+                    window: parse_window(target).map_err(invalid)?,
+                    pane: None,
+                }),
+                Some('%') => Ok(Target::Parts {
+                    session: None,
+                    window: None,
+                    pane: parse_pane(target).map_err(invalid)?,
+                }),
+                Some(_) => Ok(Target::Bare(target.to_owned())),
+                None => Ok(Target::Parts {
+                    session: None,
+                    window: None,
+                    pane: None,
+                }),
+            };
+        }
+        Ok(Target::Parts {
+            session: match session {
+                Some(session) => parse_session(session).map_err(invalid)?,
+                None => None,
+            },
+            window: match window {
+                Some(window) => parse_window(window).map_err(invalid)?,
+                None => None,
+            },
+            pane: match pane {
+                Some(pane) => parse_pane(pane).map_err(invalid)?,
+                None => None,
+            },
+        })
+    }
+
+    /// Finds the session, window and pane the target refers to, as tmux does for a command's
+    /// `-t`, relative to the client and current pane of the item.
+    ///
+    /// A target which doesn't refer to anything is an
+    /// [`Error::MissingTarget`](../../enum.Error.html#variant.MissingTarget).
+    pub fn resolve(&self, item: CmdqItem) -> Result<Found> {
+        let target = CString::new(self.to_string())?;
+        unsafe {
+            let mut state: cmd_find_state = mem::zeroed();
+            let failed = tmux_bindings::cmd_find_target(
+                &mut state,
+                item.as_ptr(),
+                target.as_ptr(),
+                cmd_find_type_CMD_FIND_PANE,
+                CMD_FIND_QUIET as c_int,
+            );
+            if failed != 0 {
+                return Err(Error::MissingTarget(format!("target {}", self)));
+            }
+            Found::from_state(&state)
+                .ok_or_else(|| Error::MissingTarget(format!("target {}", self)))
+        }
+    }
+}
+
+impl FromStr for Target {
+    type Err = InvalidTarget;
+
+    fn from_str(target: &str) -> std::result::Result<Target, InvalidTarget> {
+        Target::parse(target)
+    }
+}
+
+fn parse_session(session: &str) -> std::result::Result<Option<SessionTarget>, &'static str> {
+    Ok(Some(if let Some(name) = session.strip_prefix('=') {
+        if name.is_empty() {
+            return Ok(None);
+        }
+        SessionTarget::ExactName(name.to_owned())
+    } else if let Some(id) = session.strip_prefix('$') {
+        SessionTarget::Id(SessionId(id.parse().map_err(|_| "invalid session ID")?))
+    } else if session.is_empty() {
+        return Ok(None);
+    } else {
+        SessionTarget::Name(session.to_owned())
+    }))
+}
+
+fn parse_window(window: &str) -> std::result::Result<Option<WindowTarget>, &'static str> {
+    Ok(Some(match window {
+        "" | "=" => return Ok(None),
+        "{start}" | "^" => WindowTarget::Start,
+        "{end}" | "$" => WindowTarget::End,
+        "{last}" | "!" => WindowTarget::Last,
+        "{next}" | "+" => WindowTarget::Next(1),
+        "{previous}" | "-" => WindowTarget::Previous(1),
+        _ => {
+            if let Some(name) = window.strip_prefix('=') {
+                WindowTarget::ExactName(name.to_owned())
+            } else if let Some(id) = window.strip_prefix('@') {
+                WindowTarget::Id(WindowId(id.parse().map_err(|_| "invalid window ID")?))
+            } else if let Some(offset) = window.strip_prefix('+') {
+                WindowTarget::Next(parse_offset(offset).ok_or("invalid window offset")?)
+            } else if let Some(offset) = window.strip_prefix('-') {
+                WindowTarget::Previous(parse_offset(offset).ok_or("invalid window offset")?)
+            } else if let Ok(index) = window.parse() {
+                WindowTarget::Index(index)
+            } else {
+                WindowTarget::Name(window.to_owned())
+            }
+        }
+    }))
+}
+
+fn parse_pane(pane: &str) -> std::result::Result<Option<PaneTarget>, &'static str> {
+    Ok(Some(match pane {
+        "" => return Ok(None),
+        "{last}" | "!" => PaneTarget::Last,
+        "{next}" | "+" => PaneTarget::Next(1),
+        "{previous}" | "-" => PaneTarget::Previous(1),
+        _ => {
+            let token = PANE_TOKENS
+                .iter()
+                .find(|(_, token, other)| pane == *token || Some(pane) == *other);
+            if let Some((target, _, _)) = token {
+                target.clone()
+            } else if let Some(id) = pane.strip_prefix('%') {
+                PaneTarget::Id(PaneId(id.parse().map_err(|_| "invalid pane ID")?))
+            } else if let Some(offset) = pane.strip_prefix('+') {
+                PaneTarget::Next(parse_offset(offset).ok_or("invalid pane offset")?)
+            } else if let Some(offset) = pane.strip_prefix('-') {
+                PaneTarget::Previous(parse_offset(offset).ok_or("invalid pane offset")?)
+            } else {
+                PaneTarget::Index(pane.parse().map_err(|_| "invalid pane")?)
+            }
+        }
+    }))
+}
+
+/// An offset after a `+` or `-`, which tmux requires to be at least one.
+fn parse_offset(offset: &str) -> Option<u32> {
+    offset.parse().ok().filter(|&offset| offset > 0)
+}
+
+impl fmt::Display for Target {
+    /// Writes the target as tmux takes it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Parts {
+                session: None,
+                window: None,
+                pane: None,
+            } => Ok(()),
+            Target::Parts {
+                session,
+                window,
+                pane,
+            } => {
+                if let Some(session) = session {
+                    session.fmt(f)?;
+                }
+                f.write_str(":")?;
+                if let Some(window) = window {
+                    window.fmt(f)?;
+                }
+                if let Some(pane) = pane {
+                    write!(f, ".{}", pane)?;
+                }
+                Ok(())
+            }
+            Target::Bare(target) => f.write_str(target),
+            Target::Mouse => f.write_str("{mouse}"),
+            Target::Marked => f.write_str("{marked}"),
+        }
+    }
+}
+
+impl fmt::Display for SessionTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionTarget::Id(id) => id.fmt(f),
+            SessionTarget::Name(name) => f.write_str(name),
+            SessionTarget::ExactName(name) => write!(f, "={}", name),
+        }
+    }
+}
+
+impl fmt::Display for WindowTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowTarget::Id(id) => id.fmt(f),
+            WindowTarget::Index(index) => write!(f, "{}", index),
+            WindowTarget::Name(name) => f.write_str(name),
+            WindowTarget::ExactName(name) => write!(f, "={}", name),
+            WindowTarget::Start => f.write_str("{start}"),
+            WindowTarget::End => f.write_str("{end}"),
+            WindowTarget::Last => f.write_str("{last}"),
+            WindowTarget::Next(offset) => write!(f, "+{}", offset),
+            WindowTarget::Previous(offset) => write!(f, "-{}", offset),
+        }
+    }
+}
+
+impl fmt::Display for PaneTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaneTarget::Id(id) => id.fmt(f),
+            PaneTarget::Index(index) => write!(f, "{}", index),
+            PaneTarget::Last => f.write_str("{last}"),
+            PaneTarget::Next(offset) => write!(f, "+{}", offset),
+            PaneTarget::Previous(offset) => write!(f, "-{}", offset),
+            token => {
+                let (_, written, _) = PANE_TOKENS
+                    .iter()
+                    .find(|(target, _, _)| target == token)
+                    .expect("Every other pane target is a token");
+                f.write_str(written)
+            }
+        }
+    }
+}
+
+/// The error returned when a [`Target`](./enum.Target.html) can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidTarget {
+    /// The target which couldn't be parsed.
+    pub target: String,
+    /// What is wrong with it, such as `invalid pane ID`.
+    pub reason: &'static str,
+}
+
+impl fmt::Display for InvalidTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} in target {}", self.reason, self.target)
+    }
+}
+
+impl error::Error for InvalidTarget {}
+
+/// The session, window and pane a target was resolved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Found {
+    pub session: Session,
+    pub window: Window,
+    /// The index of the window in the session.
+    pub index: i32,
+    pub pane: Pane,
+}
+
+impl Found {
+    /// The session, window and pane tmux found, or `None` if it didn't find all of them.
+    pub(crate) unsafe fn from_state(state: &cmd_find_state) -> Option<Found> {
+        Some(Found {
+            session: Session::from_raw(state.s)?,
+            window: Window::from_raw(state.w)?,
+            index: state.idx,
+            pane: Pane::from_raw(state.wp)?,
+        })
+    }
+}
+
+/// The pane with an ID, in the session tmux would pick for it: the most recently used one
+/// its window is in. Returns `None` if there is no such pane, or its window isn't in a
+/// session.
+pub fn find_pane(id: PaneId) -> Option<Found> {
+    let pane = Pane::find_by_id(id)?;
+    unsafe {
+        let mut state: cmd_find_state = mem::zeroed();
+        if tmux_bindings::cmd_find_from_pane(&mut state, pane.as_ptr(), 0) != 0 {
+            return None;
+        }
+        Found::from_state(&state)
+    }
+}
+
+/// The window in a session with a name, and its active pane, matched the same way tmux
+/// matches a window name in a target: the window with exactly that name, or else the only
+/// one whose name starts with it, or else the only one whose name matches it as a pattern.
+///
+/// Returns `None` if no window matches, or more than one matches at the same step.
+pub fn find_window_by_name(session: Session, name: &str) -> Option<Found> {
+    let name = CString::new(name).ok()?;
+    let length = name.as_bytes().len();
+    let matches: [&dyn Fn(&CStr) -> bool; 3] = [
+        &|window| window == name.as_c_str(),
+        &|window| length > 0 && window.to_bytes().starts_with(name.as_bytes()),
+        &|window| unsafe { libc::fnmatch(name.as_ptr(), window.as_ptr(), 0) == 0 },
+    ];
+    for matches in &matches {
+        match unique_winlink(session, matches) {
+            Ok(Some(wl)) => return unsafe { from_winlink(wl) },
+            Ok(None) => {}
+            Err(()) => return None,
+        }
+    }
+    None
+}
+
+/// The only winlink in the session whose window's name matches, or `Err` if there is more
+/// than one.
+fn unique_winlink(
+    session: Session,
+    matches: &dyn Fn(&CStr) -> bool,
+) -> std::result::Result<Option<*mut winlink>, ()> {
+    let mut found = None;
+    unsafe {
+        let windows = ptr::addr_of_mut!((*session.as_ptr()).windows);
+        let mut wl = tmux_bindings::winlinks_RB_MINMAX(windows, -1);
+        while !wl.is_null() {
+            if matches(CStr::from_ptr((*(*wl).window).name)) {
+                if found.is_some() {
+                    return Err(());
+                }
+                found = Some(wl);
+            }
+            wl = tmux_bindings::winlinks_RB_NEXT(wl);
+        }
+    }
+    Ok(found)
+}
+
+/// Where a command run by a client acts when it is given no target: the client's session,
+/// its current window and that window's active pane.
+///
+/// For a client without a session, such as a command run from the shell inside a pane, this
+/// is that pane if tmux can tell which one it is, or else the most recently used session,
+/// as tmux picks for its own commands.
+pub fn current_for_client(client: Client) -> Option<Found> {
+    unsafe {
+        let mut state: cmd_find_state = mem::zeroed();
+        if tmux_bindings::cmd_find_from_client(&mut state, client.as_ptr(), 0) != 0 {
+            return None;
+        }
+        Found::from_state(&state)
+    }
+}
+
+/// The marked pane, as set by `select-pane -m`, with the session and window it was marked
+/// in, which is what the `{marked}` target and commands such as `join-pane` with no source
+/// use. Returns `None` if no pane is marked, or the marked pane has gone.
+pub fn marked() -> Option<Found> {
+    unsafe {
+        if tmux_bindings::server_check_marked() == 0 {
+            return None;
+        }
+        Found::from_state(&*ptr::addr_of!(tmux_bindings::marked_pane))
+    }
+}
+
+/// The session's previously current window, and its active pane, which is what `{last}` and
+/// `last-window` use. Returns `None` if the session has no previous window.
+pub fn last_window(session: Session) -> Option<Found> {
+    unsafe { from_winlink((*session.as_ptr()).lastw.tqh_first) }
+}
+
+/// The window of a winlink in its session, with its active pane.
+unsafe fn from_winlink(wl: *mut winlink) -> Option<Found> {
+    if wl.is_null() {
+        return None;
+    }
+    let mut state: cmd_find_state = mem::zeroed();
+    tmux_bindings::cmd_find_from_winlink(&mut state, wl, 0);
+    Found::from_state(&state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(
+        session: Option<SessionTarget>,
+        window: Option<WindowTarget>,
+        pane: Option<PaneTarget>,
+    ) -> Target {
+        Target::Parts {
+            session,
+            window,
+            pane,
+        }
+    }
+
+    fn window(window: WindowTarget) -> Target {
+        parts(None, Some(window), None)
+    }
+
+    fn pane(pane: PaneTarget) -> Target {
+        parts(None, None, Some(pane))
+    }
+
+    fn invalid(target: &str, reason: &'static str) -> std::result::Result<Target, InvalidTarget> {
+        Err(InvalidTarget {
+            target: target.to_owned(),
+            reason,
+        })
+    }
+
+    #[test]
+    fn mouse_and_marked() {
+        assert_eq!(Target::parse("{mouse}"), Ok(Target::Mouse));
+        assert_eq!(Target::parse("="), Ok(Target::Mouse));
+        assert_eq!(Target::parse("{marked}"), Ok(Target::Marked));
+        assert_eq!(Target::parse("~"), Ok(Target::Marked));
+        assert_eq!(Target::Mouse.to_string(), "{mouse}");
+        assert_eq!(Target::Marked.to_string(), "{marked}");
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(Target::parse(":+"), Ok(window(WindowTarget::Next(1))));
+        assert_eq!(Target::parse(":-"), Ok(window(WindowTarget::Previous(1))));
+        assert_eq!(Target::parse(":+3"), Ok(window(WindowTarget::Next(3))));
+        assert_eq!(Target::parse(":-2"), Ok(window(WindowTarget::Previous(2))));
+        assert_eq!(Target::parse(".+"), Ok(pane(PaneTarget::Next(1))));
+        assert_eq!(Target::parse(".-"), Ok(pane(PaneTarget::Previous(1))));
+        assert_eq!(Target::parse(".+4"), Ok(pane(PaneTarget::Next(4))));
+        assert_eq!(Target::parse(".-5"), Ok(pane(PaneTarget::Previous(5))));
+
+        assert_eq!(
+            Target::parse(":+0"),
+            invalid(":+0", "invalid window offset")
+        );
+        assert_eq!(
+            Target::parse(":-0"),
+            invalid(":-0", "invalid window offset")
+        );
+        assert_eq!(
+            Target::parse(":+x"),
+            invalid(":+x", "invalid window offset")
+        );
+        assert_eq!(Target::parse(".+0"), invalid(".+0", "invalid pane offset"));
+        assert_eq!(
+            Target::parse(".--1"),
+            invalid(".--1", "invalid pane offset")
+        );
+
+        // Without a `:` or `.`, tmux decides what an offset refers to.
+        assert_eq!(Target::parse("+"), Ok(Target::Bare("+".to_owned())));
+        assert_eq!(Target::parse("+0"), Ok(Target::Bare("+0".to_owned())));
+    }
+
+    #[test]
+    fn exact_names() {
+        assert_eq!(
+            Target::parse("=work:=editor"),
+            Ok(parts(
+                Some(SessionTarget::ExactName("work".to_owned())),
+                Some(WindowTarget::ExactName("editor".to_owned())),
+                None,
+            ))
+        );
+        assert_eq!(
+            Target::parse("work:editor"),
+            Ok(parts(
+                Some(SessionTarget::Name("work".to_owned())),
+                Some(WindowTarget::Name("editor".to_owned())),
+                None,
+            ))
+        );
+        // An `=` with no name after it is the current session or window.
+        assert_eq!(Target::parse("=:="), Ok(parts(None, None, None)));
+        assert_eq!(
+            Target::parse("=work:=editor").unwrap().to_string(),
+            "=work:=editor"
+        );
+    }
+
+    #[test]
+    fn malformed_ids() {
+        assert_eq!(Target::parse("$"), invalid("$", "invalid session ID"));
+        assert_eq!(Target::parse("$x"), invalid("$x", "invalid session ID"));
+        assert_eq!(Target::parse("$-1:"), invalid("$-1:", "invalid session ID"));
+        assert_eq!(
+            Target::parse("$4294967296"),
+            invalid("$4294967296", "invalid session ID")
+        );
+        assert_eq!(Target::parse("@"), invalid("@", "invalid window ID"));
+        assert_eq!(
+            Target::parse("work:@1x"),
+            invalid("work:@1x", "invalid window ID")
+        );
+        assert_eq!(Target::parse("%"), invalid("%", "invalid pane ID"));
+        assert_eq!(Target::parse(":.% 1"), invalid(":.% 1", "invalid pane ID"));
+        assert_eq!(Target::parse(":.x"), invalid(":.x", "invalid pane"));
+
+        assert_eq!(
+            Target::parse("$1"),
+            Ok(parts(Some(SessionTarget::Id(SessionId(1))), None, None))
+        );
+        assert_eq!(
+            Target::parse("@2"),
+            Ok(window(WindowTarget::Id(WindowId(2))))
+        );
+        assert_eq!(Target::parse("%3"), Ok(pane(PaneTarget::Id(PaneId(3)))));
+    }
+
+    #[test]
+    fn empty_parts() {
+        assert_eq!(Target::parse(""), Ok(parts(None, None, None)));
+        assert_eq!(Target::parse(":"), Ok(parts(None, None, None)));
+        assert_eq!(Target::parse(":."), Ok(parts(None, None, None)));
+        assert_eq!(
+            Target::parse("work:"),
+            Ok(parts(
+                Some(SessionTarget::Name("work".to_owned())),
+                None,
+                None
+            ))
+        );
+        assert_eq!(
+            Target::parse(":editor"),
+            Ok(window(WindowTarget::Name("editor".to_owned())))
+        );
+        assert_eq!(Target::parse(":2."), Ok(window(WindowTarget::Index(2))));
+        assert_eq!(Target::parse(".1"), Ok(pane(PaneTarget::Index(1))));
+        assert_eq!(parts(None, None, None).to_string(), "");
+    }
+
+    #[test]
+    fn pane_tokens_round_trip() {
+        let others = [
+            PaneTarget::Id(PaneId(7)),
+            PaneTarget::Index(2),
+            PaneTarget::Last,
+            PaneTarget::Next(1),
+            PaneTarget::Next(3),
+            PaneTarget::Previous(1),
+            PaneTarget::Previous(2),
+        ];
+        let tokens = PANE_TOKENS.iter().map(|(target, _, _)| target);
+        for target in tokens.chain(&others) {
+            let target = pane(target.clone());
+            assert_eq!(Target::parse(&target.to_string()), Ok(target.clone()));
+        }
+        for (target, written, other) in PANE_TOKENS {
+            assert_eq!(parse_pane(written), Ok(Some(target.clone())));
+            if let Some(other) = other {
+                assert_eq!(parse_pane(other), Ok(Some(target.clone())));
+            }
+        }
+        assert_eq!(parse_pane("!"), Ok(Some(PaneTarget::Last)));
+        assert_eq!(parse_pane("{next}"), Ok(Some(PaneTarget::Next(1))));
+        assert_eq!(parse_pane("{previous}"), Ok(Some(PaneTarget::Previous(1))));
+    }
+
+    #[test]
+    fn window_tokens_round_trip() {
+        let targets = [
+            WindowTarget::Id(WindowId(4)),
+            WindowTarget::Index(0),
+            WindowTarget::Name("editor".to_owned()),
+            WindowTarget::ExactName("editor".to_owned()),
+            WindowTarget::Start,
+            WindowTarget::End,
+            WindowTarget::Last,
+            WindowTarget::Next(1),
+            WindowTarget::Next(2),
+            WindowTarget::Previous(1),
+            WindowTarget::Previous(3),
+        ];
+        for target in &targets {
+            let target = parts(
+                Some(SessionTarget::Id(SessionId(1))),
+                Some(target.clone()),
+                Some(PaneTarget::Top),
+            );
+            assert_eq!(Target::parse(&target.to_string()), Ok(target.clone()));
+        }
+        let tokens = [
+            ("^", WindowTarget::Start),
+            ("{start}", WindowTarget::Start),
+            ("$", WindowTarget::End),
+            ("{end}", WindowTarget::End),
+            ("!", WindowTarget::Last),
+            ("{last}", WindowTarget::Last),
+            ("{next}", WindowTarget::Next(1)),
+            ("{previous}", WindowTarget::Previous(1)),
+        ];
+        for (written, target) in &tokens {
+            assert_eq!(parse_window(written), Ok(Some(target.clone())));
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Formatting the times tmux keeps.
+//!
+//! tmux records times, such as when a session was created or a window last had activity, as
+//! [`timeval`](../struct.timeval.html)s. These convert them to [`SystemTime`]s and format them,
+//! either as a date and time or relative to now. Sessions, windows and panes also have methods
+//! here for how long ago things happened to them, such as
+//! [`Session::created_ago`](../struct.Session.html#method.created_ago).
+//!
+//! For example:
+//!
+//! ```rust
+//! use tmux_plugin::format_plugin;
+//! use tmux_plugin::tmux::time;
+//! use std::ffi::CString;
+//!
+//! // Shows how long ago the session was created, such as "3h ago".
+//! format_plugin!(b"session_age\0", |format_tree| {
+//!     let session = unsafe { (*format_tree).s };
+//!     if session.is_null() {
+//!         return CString::default();
+//!     }
+//!     CString::new(time::relative(unsafe { (*session).creation_time })).unwrap_or_default()
+//! });
+//! # fn main() {}
+//! ```
+//!
+//! [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+
+use super::{Pane, Session, Window};
+use crate::tmux_bindings::timeval;
+use std::ffi::{CStr, CString, OsStr};
+use std::fs;
+use std::mem;
+use std::os::raw::c_char;
+use std::os::unix::ffi::OsStrExt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Converts a `timeval` to a `SystemTime`.
+pub fn system_time(tv: timeval) -> SystemTime {
+    let micros = Duration::from_micros(tv.tv_usec.max(0) as u64);
+    if tv.tv_sec >= 0 {
+        UNIX_EPOCH + Duration::from_secs(tv.tv_sec as u64) + micros
+    } else {
+        UNIX_EPOCH - Duration::from_secs(tv.tv_sec.unsigned_abs()) + micros
+    }
+}
+
+/// Formats a time in the local time zone, with a `strftime` specification such as
+/// `%Y-%m-%d %H:%M`, the same way tmux formats times in `status-left` and `status-right`.
+///
+/// Returns an empty string if the specification contains a null byte.
+pub fn format(tv: timeval, spec: &str) -> String {
+    let spec = match CString::new(spec) {
+        Ok(spec) => spec,
+        Err(_) => return String::new(),
+    };
+    let seconds = tv.tv_sec as libc::time_t;
+    let mut tm: libc::tm = unsafe { mem::zeroed() };
+    if unsafe { libc::localtime_r(&seconds, &mut tm) }.is_null() {
+        return String::new();
+    }
+    // strftime returns 0 both when the buffer is too small and when the result is empty, so
+    // give up once the buffer is much larger than any reasonable result.
+    let mut buffer = vec![0 as c_char; 64];
+    while buffer.len() <= 4096 {
+        let length =
+            unsafe { libc::strftime(buffer.as_mut_ptr(), buffer.len(), spec.as_ptr(), &tm) };
+        if length > 0 {
+            let bytes = buffer[..length]
+                .iter()
+                .map(|&c| c as u8)
+                .collect::<Vec<_>>();
+            return String::from_utf8_lossy(&bytes).into_owned();
+        }
+        buffer.resize(buffer.len() * 4, 0);
+    }
+    String::new()
+}
+
+/// Describes how long ago a time was, such as `3m ago`, or how far off it is if it is in the
+/// future, such as `in 2h`.
+pub fn relative(tv: timeval) -> String {
+    relative_to(tv, SystemTime::now())
+}
+
+/// Describes a time relative to another time, in the largest whole unit: seconds, minutes,
+/// hours or days. Anything within a second is `now`.
+///
+/// ```rust
+/// use tmux_plugin::tmux::{time, timeval};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let tv = timeval { tv_sec: 1_000_000, tv_usec: 0 };
+/// let now = UNIX_EPOCH + Duration::from_secs(1_000_200);
+/// assert_eq!(time::relative_to(tv, now), "3m ago");
+/// assert_eq!(time::relative_to(tv, now - Duration::from_secs(7300)), "in 1h");
+/// ```
+pub fn relative_to(tv: timeval, now: SystemTime) -> String {
+    let then = system_time(tv);
+    let (elapsed, past) = match now.duration_since(then) {
+        Ok(elapsed) => (elapsed, true),
+        Err(error) => (error.duration(), false),
+    };
+    let seconds = elapsed.as_secs();
+    let amount = match seconds {
+        0 => return "now".to_owned(),
+        1..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m", seconds / 60),
+        3600..=86399 => format!("{}h", seconds / 3600),
+        _ => format!("{}d", seconds / 86400),
+    };
+    if past {
+        format!("{} ago", amount)
+    } else {
+        format!("in {}", amount)
+    }
+}
+
+/// How long ago a time was, or zero if it is in the future.
+fn since(time: SystemTime) -> Duration {
+    SystemTime::now().duration_since(time).unwrap_or_default()
+}
+
+impl Session {
+    /// When the session was created.
+    pub fn created(&self) -> SystemTime {
+        system_time(unsafe { (*self.as_ptr()).creation_time })
+    }
+
+    /// How long ago the session was created, as shown in an uptime segment.
+    pub fn created_ago(&self) -> Duration {
+        since(self.created())
+    }
+}
+
+impl Window {
+    /// When there was last output in any of the window's panes.
+    pub fn last_activity(&self) -> SystemTime {
+        system_time(unsafe { (*self.as_ptr()).activity_time })
+    }
+
+    /// How long ago there was last output in any of the window's panes.
+    pub fn last_activity_ago(&self) -> Duration {
+        since(self.last_activity())
+    }
+}
+
+impl Pane {
+    /// How long it has been since the program in the pane last wrote to its terminal.
+    ///
+    /// tmux only tracks activity for whole windows, so this goes by the modification time of
+    /// the pane's pseudo-terminal instead. Some systems, such as Linux, only update it every
+    /// few seconds, so short idle times aren't accurate. Returns `None` if the pane has no
+    /// terminal, as after its program has exited, or its time can't be read.
+    pub fn idle_time(&self) -> Option<Duration> {
+        let tty = unsafe { CStr::from_ptr((*self.as_ptr()).tty.as_ptr()) };
+        if self.is_dead() || tty.to_bytes().is_empty() {
+            return None;
+        }
+        let modified = fs::metadata(OsStr::from_bytes(tty.to_bytes()))
+            .ok()?
+            .modified()
+            .ok()?;
+        Some(since(modified))
+    }
+}
@@ -0,0 +1,32 @@
+use crate::tmux_bindings::{self, timeval, EV_TIMEOUT};
+use std::os::raw::{c_int, c_short, c_void};
+use std::time::Duration;
+
+type Callback = Box<dyn FnOnce()>;
+
+/// Runs `f` on tmux's event loop once `delay` has passed.
+pub(crate) fn after<F: FnOnce() + 'static>(delay: Duration, f: F) {
+    let callback: Box<Callback> = Box::new(Box::new(f));
+    let callback = Box::into_raw(callback);
+    let tv = timeval {
+        tv_sec: delay.as_secs() as _,
+        tv_usec: delay.subsec_micros() as _,
+    };
+    unsafe {
+        if tmux_bindings::event_once(
+            -1,
+            EV_TIMEOUT as c_short,
+            Some(fire),
+            callback as *mut c_void,
+            &tv,
+        ) != 0
+        {
+            drop(Box::from_raw(callback));
+        }
+    }
+}
+
+unsafe extern "C" fn fire(_fd: c_int, _events: c_short, arg: *mut c_void) {
+    let callback = Box::from_raw(arg as *mut Callback);
+    callback()
+}
@@ -0,0 +1,121 @@
+use super::{clients, timer, Client};
+use crate::tmux_bindings::{self, client};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::time::Duration;
+
+/// How often titles are checked for having been replaced by tmux.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The suffix set for a client's title, and the title tmux had set when it was last added.
+struct Suffix {
+    suffix: String,
+    base: Option<CString>,
+}
+
+thread_local! {
+    static SUFFIXES: RefCell<HashMap<*mut client, Suffix>> = RefCell::new(HashMap::new());
+    static POLLING: Cell<bool> = const { Cell::new(false) };
+}
+
+impl Client {
+    /// The terminal title tmux last set for the client, from the `set-titles-string` option.
+    ///
+    /// This is `None` until tmux first sets a title, which it only does while the `set-titles`
+    /// option is on.
+    pub fn title(&self) -> Option<&CStr> {
+        unsafe {
+            let title = (*self.as_ptr()).title;
+            if title.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(title))
+            }
+        }
+    }
+
+    /// Adds text to the end of the client's terminal title, after the title tmux sets from the
+    /// `set-titles-string` option. An empty suffix removes it again.
+    ///
+    /// tmux rewrites the title whenever `set-titles-string` expands to something new, so the
+    /// suffix is added back each time tmux does, within a second. Nothing is shown while the
+    /// `set-titles` option is off, since tmux leaves the title alone then.
+    ///
+    /// For example:
+    ///
+    /// ```rust
+    /// use tmux_plugin::{cmd_plugin, tmux};
+    ///
+    /// // Marks the invoking client's title while it is recording.
+    /// cmd_plugin!(b"mark-recording\0", b"\0", b"[on|off]\0", 1, 1, |_cmd, args, item| {
+    ///     if let Some(client) = item.client() {
+    ///         let on = args.last().is_some_and(|arg| arg.to_bytes() == b"on");
+    ///         client.set_title_suffix(if on { " [REC]" } else { "" });
+    ///     }
+    ///     tmux::CmdResult::Normal
+    /// });
+    /// # fn main() {}
+    /// ```
+    pub fn set_title_suffix(&self, suffix: &str) {
+        let c = self.as_ptr();
+        SUFFIXES.with(|suffixes| {
+            let mut suffixes = suffixes.borrow_mut();
+            let base = self.title().map(CStr::to_owned);
+            if suffix.is_empty() {
+                // Put tmux's own title back.
+                if suffixes.remove(&c).is_some() {
+                    if let Some(base) = &base {
+                        unsafe { tmux_bindings::tty_set_title(&mut (*c).tty, base.as_ptr()) };
+                    }
+                }
+                return;
+            }
+            suffixes.insert(
+                c,
+                Suffix {
+                    suffix: suffix.to_owned(),
+                    base: None,
+                },
+            );
+        });
+        apply_all();
+    }
+}
+
+/// Adds each client's suffix back to its title, if tmux has set the title since it was last
+/// added.
+fn apply_all() {
+    SUFFIXES.with(|suffixes| {
+        let mut suffixes = suffixes.borrow_mut();
+        let live = clients::all().map(Client::as_ptr).collect::<Vec<_>>();
+        suffixes.retain(|c, _| live.contains(c));
+        for (&c, suffix) in suffixes.iter_mut() {
+            let client = unsafe { Client::from_raw(c) }.expect("Live clients are not null");
+            let base = match client.title() {
+                Some(base) => base,
+                None => continue,
+            };
+            if suffix.base.as_deref() == Some(base) {
+                continue;
+            }
+            let mut title = base.to_bytes().to_vec();
+            title.extend(suffix.suffix.bytes().take_while(|&b| b != 0));
+            let title = CString::new(title).expect("Null bytes were left out");
+            unsafe { tmux_bindings::tty_set_title(&mut (*c).tty, title.as_ptr()) };
+            suffix.base = Some(base.to_owned());
+        }
+    });
+    poll();
+}
+
+/// Keeps checking titles while any client has a suffix.
+fn poll() {
+    let wanted = SUFFIXES.with(|suffixes| !suffixes.borrow().is_empty());
+    if wanted && !POLLING.with(|polling| polling.replace(true)) {
+        timer::after(POLL_INTERVAL, || {
+            POLLING.with(|polling| polling.set(false));
+            apply_all();
+        });
+    }
+}
@@ -0,0 +1,329 @@
+use super::Client;
+use crate::tmux_bindings::{
+    self, termios, tty, tty_code_code, tty_code_code_TTYC_MS, tty_code_code_TTYC_RGB,
+    tty_code_code_TTYC_SITM, tty_code_code_TTYC_SMXX, tty_code_code_TTYC_SS, tty_code_code_TTYC_TC,
+    tty_code_code_TTYC_TSL, tty_term, TERM_256COLOURS, TTY_UTF8,
+};
+use std::ffi::CStr;
+use std::mem;
+use std::ptr::NonNull;
+
+/// A client's terminal, as tmux sees it.
+///
+/// This is a read-only wrapper around a [`*mut tty`](./struct.tty.html), for working out
+/// what a client's terminal can display. Capabilities come from the terminal's terminfo
+/// entry, as amended by the `terminal-overrides` option. This version of tmux has no notion
+/// of synchronized output, so there is no way to ask about it.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use std::ffi::CString;
+///
+/// // Prints a colour bar, using as many colours as the terminal supports.
+/// cmd_plugin!(b"colour-bar\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+///     let bar = match item.client().map(|client| client.tty()) {
+///         Some(tty) if tty.has_rgb() => "\x1b[38;2;255;128;0m#####\x1b[0m",
+///         Some(tty) if tty.has_256_colours() => "\x1b[38;5;208m#####\x1b[0m",
+///         _ => "\x1b[33m#####\x1b[0m",
+///     };
+///     item.print(&CString::new(bar).unwrap());
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tty(NonNull<tty>);
+
+impl Client {
+    /// The client's terminal.
+    pub fn tty(&self) -> Tty {
+        Tty(unsafe { NonNull::new_unchecked(&mut (*self.as_ptr()).tty) })
+    }
+}
+
+impl Tty {
+    /// Wraps a raw tty pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `tty`.
+    pub unsafe fn from_raw(ptr: *mut tty) -> Option<Tty> {
+        NonNull::new(ptr).map(Tty)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut tty {
+        self.0.as_ptr()
+    }
+
+    /// The client this terminal belongs to.
+    pub fn client(&self) -> Option<Client> {
+        unsafe { Client::from_raw((*self.as_ptr()).client) }
+    }
+
+    /// The value of `TERM` in the client's environment, such as `xterm-256color`.
+    pub fn term_name(&self) -> Option<&CStr> {
+        unsafe {
+            let name = (*self.as_ptr()).term_name;
+            if name.is_null() {
+                None
+            } else {
+                Some(CStr::from_ptr(name))
+            }
+        }
+    }
+
+    /// The terminal description tmux is using, if the terminal has been opened.
+    pub fn term(&self) -> Option<TtyTerm> {
+        unsafe { TtyTerm::from_raw((*self.as_ptr()).term) }
+    }
+
+    /// The terminal's width and height, in cells.
+    pub fn size(&self) -> (u32, u32) {
+        unsafe { ((*self.as_ptr()).sx, (*self.as_ptr()).sy) }
+    }
+
+    /// The terminal's width and height in pixels, if the terminal reports them.
+    ///
+    /// Many terminals report zero, in which case this is `None`.
+    pub fn pixel_size(&self) -> Option<(u32, u32)> {
+        unsafe {
+            let fd = (*self.as_ptr()).fd;
+            if fd == -1 {
+                return None;
+            }
+            let mut ws: libc::winsize = mem::zeroed();
+            if libc::ioctl(fd, libc::TIOCGWINSZ, &mut ws) == -1
+                || ws.ws_xpixel == 0
+                || ws.ws_ypixel == 0
+            {
+                return None;
+            }
+            Some((ws.ws_xpixel.into(), ws.ws_ypixel.into()))
+        }
+    }
+
+    /// Whether the client is treating the terminal as UTF-8.
+    pub fn has_utf8(&self) -> bool {
+        unsafe { (*self.as_ptr()).flags as u32 & TTY_UTF8 != 0 }
+    }
+
+    /// Whether the terminal supports 256 colours, either because its terminfo entry says so
+    /// or because the client was started with `tmux -2`.
+    pub fn has_256_colours(&self) -> bool {
+        let forced = unsafe { (*self.as_ptr()).term_flags as u32 & TERM_256COLOURS != 0 };
+        forced || self.term().is_some_and(|term| term.has_256_colours())
+    }
+
+    /// Whether the terminal supports 24-bit colour.
+    pub fn has_rgb(&self) -> bool {
+        self.term().is_some_and(|term| term.has_rgb())
+    }
+}
+
+/// A terminal description, loaded from terminfo.
+///
+/// This is a read-only wrapper around a [`*mut tty_term`](./struct.tty_term.html). tmux shares
+/// descriptions between clients whose terminals have the same `TERM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TtyTerm(NonNull<tty_term>);
+
+impl TtyTerm {
+    /// Wraps a raw terminal description pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `tty_term`.
+    pub unsafe fn from_raw(ptr: *mut tty_term) -> Option<TtyTerm> {
+        NonNull::new(ptr).map(TtyTerm)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut tty_term {
+        self.0.as_ptr()
+    }
+
+    /// The terminfo name of the description.
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr((*self.as_ptr()).name) }
+    }
+
+    /// Whether the terminal supports 256 colours.
+    pub fn has_256_colours(&self) -> bool {
+        unsafe { (*self.as_ptr()).flags as u32 & TERM_256COLOURS != 0 }
+    }
+
+    /// Whether the terminal supports 24-bit colour, through the `Tc` or `RGB` capabilities.
+    pub fn has_rgb(&self) -> bool {
+        self.has(tty_code_code_TTYC_TC) || self.has(tty_code_code_TTYC_RGB)
+    }
+
+    /// Whether the terminal can set the clipboard, through the `Ms` capability.
+    pub fn has_clipboard(&self) -> bool {
+        self.has(tty_code_code_TTYC_MS)
+    }
+
+    /// Whether the terminal can set its title, through the `tsl` capability.
+    pub fn has_title(&self) -> bool {
+        self.has(tty_code_code_TTYC_TSL)
+    }
+
+    /// Whether the terminal supports italics.
+    pub fn has_italics(&self) -> bool {
+        self.has(tty_code_code_TTYC_SITM)
+    }
+
+    /// Whether the terminal supports strikethrough.
+    pub fn has_strikethrough(&self) -> bool {
+        self.has(tty_code_code_TTYC_SMXX)
+    }
+
+    /// Whether the terminal can change the cursor style.
+    pub fn has_cursor_style(&self) -> bool {
+        self.has(tty_code_code_TTYC_SS)
+    }
+
+    fn has(&self, code: tty_code_code) -> bool {
+        unsafe { tmux_bindings::tty_term_has(self.as_ptr(), code) != 0 }
+    }
+}
+
+/// A read-only view of a client terminal's `termios` settings.
+///
+/// These are the settings the terminal had when the client attached, before tmux put it
+/// into raw mode, so they show how the user's terminal is set up outside of tmux.
+///
+/// For example:
+///
+/// ```rust
+/// use tmux_plugin::{cmd_plugin, tmux};
+/// use tmux_plugin::tmux::SpecialChar;
+/// use std::ffi::CString;
+///
+/// // Warns if ^S would freeze the terminal outside of tmux.
+/// cmd_plugin!(b"check-flow-control\0", b"\0", b"\0", 0, 0, |_cmd, _args, item| {
+///     if let Some(termios) = item.client().map(|client| client.tty().termios()) {
+///         if termios.flow_control() && termios.special_char(SpecialChar::Stop) == Some(0x13) {
+///             item.print(&CString::new("^S stops output; try `stty -ixon`").unwrap());
+///         }
+///     }
+///     tmux::CmdResult::Normal
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Termios(termios);
+
+/// A character with a special meaning to the terminal driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpecialChar {
+    /// Sends `SIGINT`, usually `^C`.
+    Interrupt,
+    /// Sends `SIGQUIT`, usually `^\`.
+    Quit,
+    /// Erases the previous character, usually `^?` or `^H`.
+    Erase,
+    /// Erases the line, usually `^U`.
+    Kill,
+    /// Ends input, usually `^D`.
+    EndOfFile,
+    /// Sends `SIGTSTP`, usually `^Z`.
+    Suspend,
+    /// Resumes output after a stop, usually `^Q`.
+    Start,
+    /// Stops output, usually `^S`.
+    Stop,
+    /// Erases the previous word, usually `^W`.
+    WordErase,
+    /// Reprints the line, usually `^R`.
+    Reprint,
+    /// Quotes the next character, usually `^V`.
+    LiteralNext,
+}
+
+impl SpecialChar {
+    fn index(self) -> usize {
+        match self {
+            SpecialChar::Interrupt => libc::VINTR,
+            SpecialChar::Quit => libc::VQUIT,
+            SpecialChar::Erase => libc::VERASE,
+            SpecialChar::Kill => libc::VKILL,
+            SpecialChar::EndOfFile => libc::VEOF,
+            SpecialChar::Suspend => libc::VSUSP,
+            SpecialChar::Start => libc::VSTART,
+            SpecialChar::Stop => libc::VSTOP,
+            SpecialChar::WordErase => libc::VWERASE,
+            SpecialChar::Reprint => libc::VREPRINT,
+            SpecialChar::LiteralNext => libc::VLNEXT,
+        }
+    }
+}
+
+impl Tty {
+    /// The terminal's `termios` settings from before tmux changed them.
+    pub fn termios(&self) -> Termios {
+        Termios(unsafe { (*self.as_ptr()).tio })
+    }
+}
+
+impl Termios {
+    /// The raw settings.
+    pub fn as_raw(&self) -> &termios {
+        &self.0
+    }
+
+    /// The input mode flags, `c_iflag`.
+    pub fn input_flags(&self) -> u32 {
+        self.0.c_iflag
+    }
+
+    /// The output mode flags, `c_oflag`.
+    pub fn output_flags(&self) -> u32 {
+        self.0.c_oflag
+    }
+
+    /// The control mode flags, `c_cflag`.
+    pub fn control_flags(&self) -> u32 {
+        self.0.c_cflag
+    }
+
+    /// The local mode flags, `c_lflag`.
+    pub fn local_flags(&self) -> u32 {
+        self.0.c_lflag
+    }
+
+    /// Whether `^S` and `^Q` stop and start output (`IXON`).
+    pub fn flow_control(&self) -> bool {
+        self.0.c_iflag & libc::IXON != 0
+    }
+
+    /// Whether the terminal sends `^S` and `^Q` when its input buffer fills (`IXOFF`).
+    pub fn input_flow_control(&self) -> bool {
+        self.0.c_iflag & libc::IXOFF != 0
+    }
+
+    /// Whether input is echoed (`ECHO`).
+    pub fn echo(&self) -> bool {
+        self.0.c_lflag & libc::ECHO != 0
+    }
+
+    /// Whether input is read a line at a time (`ICANON`).
+    pub fn canonical(&self) -> bool {
+        self.0.c_lflag & libc::ICANON != 0
+    }
+
+    /// Whether special characters send signals (`ISIG`).
+    pub fn signals(&self) -> bool {
+        self.0.c_lflag & libc::ISIG != 0
+    }
+
+    /// The character bound to a special function, or `None` if it is disabled.
+    pub fn special_char(&self, special: SpecialChar) -> Option<u8> {
+        match self.0.c_cc.get(special.index()) {
+            Some(&0) | None => None,
+            Some(&c) => Some(c),
+        }
+    }
+}
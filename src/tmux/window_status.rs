@@ -0,0 +1,125 @@
+//! What `window-status-format` and `window-status-current-format` are being expanded for.
+//!
+//! tmux expands one of those options for each window in the status line, picking between
+//! them by whether the window is the current one, and shows alerts with flags such as `#` and
+//! `!` in `#{window_flags}`. [`WindowStatus`](./struct.WindowStatus.html) gives plugins the
+//! same facts about the window, so a theme can pick its styles from a single variable.
+
+use super::Window;
+use crate::tmux_bindings::{
+    self, format_tree, winlink, FORMAT_STATUS, FORMAT_WINDOW, WINDOW_ZOOMED, WINLINK_ACTIVITY,
+    WINLINK_BELL, WINLINK_SILENCE,
+};
+use std::os::raw::c_int;
+use std::ptr::{self, NonNull};
+
+/// A window, as it is being shown in the status line of a session.
+///
+/// For example, a variable for `window-status-style` and friends which picks a style from
+/// the window's state:
+///
+/// ```rust
+/// use tmux_plugin::format_plugin;
+/// use tmux_plugin::tmux::window_status::WindowStatus;
+/// use std::ffi::CStr;
+///
+/// // Used as `set -g window-status-format '#[#{window_theme_style}] #I:#W '`.
+/// format_plugin!("window_theme_style", |format_tree| {
+///     let style: &[u8] = match unsafe { WindowStatus::from_format(format_tree) } {
+///         Some(status) if status.is_current() => b"fg=black,bg=green,bold\0",
+///         Some(status) if status.has_bell() => b"fg=white,bg=red\0",
+///         Some(status) if status.has_activity() || status.has_silence() => b"fg=yellow\0",
+///         _ => b"fg=default\0",
+///     };
+///     CStr::from_bytes_with_nul(style).unwrap()
+/// });
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowStatus {
+    wl: NonNull<winlink>,
+    for_status: bool,
+}
+
+impl WindowStatus {
+    /// The window a format is being expanded for, in the session it is being expanded for, or
+    /// `None` if it isn't being expanded for a window in a session.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to the `format_tree` passed to a format callback.
+    pub unsafe fn from_format(ft: *mut format_tree) -> Option<WindowStatus> {
+        let wl = NonNull::new((*ft).wl)?;
+        let w = (*wl.as_ptr()).window;
+        let for_status = (*ft).flags & FORMAT_STATUS as c_int != 0
+            && !w.is_null()
+            && (*ft).tag == FORMAT_WINDOW | (*w).id;
+        Some(WindowStatus { wl, for_status })
+    }
+
+    /// The window.
+    pub fn window(&self) -> Window {
+        unsafe { Window::from_raw((*self.wl.as_ptr()).window) }
+            .expect("A winlink always links a window")
+    }
+
+    /// The window's index in the session, as in `#{window_index}`.
+    pub fn index(&self) -> i32 {
+        unsafe { (*self.wl.as_ptr()).idx }
+    }
+
+    /// Whether the format is being expanded for the window's entry in the status line, rather
+    /// than for something else about the window, such as `display-message`.
+    pub fn is_status_line(&self) -> bool {
+        self.for_status
+    }
+
+    /// Whether the window is the session's current window, so that tmux uses
+    /// `window-status-current-format` for it.
+    pub fn is_current(&self) -> bool {
+        unsafe {
+            let s = (*self.wl.as_ptr()).session;
+            !s.is_null() && ptr::eq((*s).curw, self.wl.as_ptr())
+        }
+    }
+
+    /// Whether the window is the session's last window, the one `last-window` selects.
+    pub fn is_last(&self) -> bool {
+        unsafe {
+            let s = (*self.wl.as_ptr()).session;
+            !s.is_null() && ptr::eq((*s).lastw.tqh_first, self.wl.as_ptr())
+        }
+    }
+
+    /// Whether there has been a bell in the window since it was last current, shown as `!`.
+    pub fn has_bell(&self) -> bool {
+        self.has_flag(WINLINK_BELL)
+    }
+
+    /// Whether there has been activity in the window since it was last current, shown as `#`.
+    pub fn has_activity(&self) -> bool {
+        self.has_flag(WINLINK_ACTIVITY)
+    }
+
+    /// Whether the window has been silent for the `monitor-silence` interval, shown as `~`.
+    pub fn has_silence(&self) -> bool {
+        self.has_flag(WINLINK_SILENCE)
+    }
+
+    /// Whether the window's active pane is zoomed, shown as `Z`.
+    pub fn is_zoomed(&self) -> bool {
+        unsafe { (*(*self.wl.as_ptr()).window).flags & WINDOW_ZOOMED as c_int != 0 }
+    }
+
+    /// Whether the marked pane is in this window, shown as `M`.
+    pub fn is_marked(&self) -> bool {
+        unsafe {
+            tmux_bindings::server_check_marked() != 0
+                && ptr::eq(tmux_bindings::marked_pane.wl, self.wl.as_ptr())
+        }
+    }
+
+    fn has_flag(&self, flag: u32) -> bool {
+        unsafe { (*self.wl.as_ptr()).flags & flag as c_int != 0 }
+    }
+}
@@ -0,0 +1,102 @@
+use super::{Pane, WindowId};
+use crate::tmux_bindings::{self, window, WINDOW_MAXIMUM, WINDOW_MINIMUM, WINDOW_SIZE_MANUAL};
+use std::ffi::{CStr, CString, NulError};
+use std::ptr::NonNull;
+
+/// A tmux window.
+///
+/// This is a thin wrapper around a [`*mut window`](./struct.window.html). A window may be
+/// linked into several sessions at once, so it has no index of its own; that belongs to the
+/// [`winlink`](./struct.winlink.html) linking it into a particular session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window(NonNull<window>);
+
+impl Window {
+    /// Wraps a raw window pointer, returning `None` if it is null.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be null or point to a live tmux `window`.
+    pub unsafe fn from_raw(ptr: *mut window) -> Option<Window> {
+        NonNull::new(ptr).map(Window)
+    }
+
+    /// Returns the underlying raw pointer.
+    pub fn as_ptr(self) -> *mut window {
+        self.0.as_ptr()
+    }
+
+    /// Finds a window by its unique ID.
+    pub fn find_by_id(id: WindowId) -> Option<Window> {
+        unsafe { Window::from_raw(tmux_bindings::window_find_by_id(id.0)) }
+    }
+
+    /// The window's unique ID, as used in `@` targets.
+    pub fn id(&self) -> WindowId {
+        WindowId(unsafe { (*self.as_ptr()).id })
+    }
+
+    /// The window's name.
+    pub fn name(&self) -> &CStr {
+        unsafe { CStr::from_ptr((*self.as_ptr()).name) }
+    }
+
+    /// The window's active pane.
+    pub fn active_pane(&self) -> Option<Pane> {
+        unsafe { Pane::from_raw((*self.as_ptr()).active) }
+    }
+
+    /// The window's panes, in the order tmux numbers them.
+    pub fn panes(&self) -> Vec<Pane> {
+        let mut panes = Vec::new();
+        unsafe {
+            let mut wp = (*self.as_ptr()).panes.tqh_first;
+            while let Some(pane) = Pane::from_raw(wp) {
+                panes.push(pane);
+                wp = (*wp).entry.tqe_next;
+            }
+        }
+        panes
+    }
+
+    /// The window's width and height, in cells.
+    pub fn size(&self) -> (u32, u32) {
+        unsafe { ((*self.as_ptr()).sx, (*self.as_ptr()).sy) }
+    }
+
+    /// Renames the window.
+    ///
+    /// This takes care of freeing the old name, redrawing status lines, and firing the
+    /// `window-renamed` hook. Renaming a window to the name it already has does nothing,
+    /// so it is safe to call this from a `window-renamed` notification plugin.
+    ///
+    /// Unlike the `rename-window` command, this does not turn off the `automatic-rename`
+    /// option, so tmux may go on to rename the window again.
+    pub fn rename(&self, name: &str) -> Result<(), NulError> {
+        let name = CString::new(name)?;
+        if name.as_c_str() == self.name() {
+            return Ok(());
+        }
+        unsafe {
+            tmux_bindings::window_set_name(self.as_ptr(), name.as_ptr());
+            tmux_bindings::server_status_window(self.as_ptr());
+        }
+        Ok(())
+    }
+
+    /// Resizes the window, the same way the `resize-window` command does.
+    ///
+    /// This sets the window's `window-size` option to `manual`, so that tmux stops
+    /// resizing it to fit its clients. Sizes are clamped to the range tmux allows.
+    pub fn resize(&self, width: u32, height: u32) {
+        let clamp = |size: u32| size.clamp(WINDOW_MINIMUM, WINDOW_MAXIMUM);
+        unsafe {
+            tmux_bindings::options_set_number(
+                (*self.as_ptr()).options,
+                crate::cstr!("window-size").as_ptr(),
+                WINDOW_SIZE_MANUAL.into(),
+            );
+            tmux_bindings::resize_window(self.as_ptr(), clamp(width), clamp(height));
+        }
+    }
+}
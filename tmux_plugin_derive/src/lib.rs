@@ -0,0 +1,137 @@
+//! Derive macros for [`tmux_plugin`](https://docs.rs/tmux_plugin). Use them through
+//! `tmux_plugin`'s `derive` feature rather than depending on this crate directly.
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr};
+
+/// Derives `tmux_plugin::tmux::PluginOptions`. See the documentation there.
+#[proc_macro_derive(PluginOptions, attributes(plugin_options, option))]
+pub fn derive_plugin_options(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    plugin_options(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn plugin_options(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut prefix = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("plugin_options"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = Some(meta.value()?.parse::<LitStr>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `prefix`"))
+            }
+        })?;
+    }
+    let prefix = match prefix {
+        Some(prefix) => quote!(#prefix),
+        None => quote!(env!("CARGO_PKG_NAME")),
+    };
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "PluginOptions can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "PluginOptions can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut names = Vec::new();
+    let mut loads = Vec::new();
+    let mut refreshes = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("Named fields have names");
+        let mut option = ident.to_string().trim_start_matches("r#").replace('_', "-");
+        let mut default = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("option"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    option = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    default = Some(meta.value()?.parse::<LitStr>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `name` or `default`"))
+                }
+            })?;
+        }
+
+        let name = quote!(concat!("@", #prefix, "-", #option));
+        let ty = &field.ty;
+        let default = match default {
+            Some(default) => quote! {
+                <#ty as ::tmux_plugin::tmux::OptionValue>::parse(#default)
+                    .unwrap_or_else(<#ty as ::std::default::Default>::default)
+            },
+            None => quote!(<#ty as ::std::default::Default>::default()),
+        };
+        let value = quote! {
+            options
+                .get(#name)
+                .and_then(|value| <#ty as ::tmux_plugin::tmux::OptionValue>::parse(&value))
+                .unwrap_or_else(|| #default)
+        };
+
+        names.push(name);
+        loads.push(quote!(#ident: #value,));
+        refreshes.push(quote! {
+            let value: #ty = #value;
+            if value != self.#ident {
+                self.#ident = value;
+                changed = true;
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::tmux_plugin::tmux::PluginOptions for #ident #ty_generics
+        #where_clause
+        {
+            const NAMES: &'static [&'static str] = &[#(#names),*];
+
+            fn load() -> Self {
+                #[allow(unused_variables)]
+                let options = ::tmux_plugin::tmux::server::global_session_options();
+                #ident {
+                    #(#loads)*
+                }
+            }
+
+            fn refresh(&mut self) -> bool {
+                #[allow(unused_variables)]
+                let options = ::tmux_plugin::tmux::server::global_session_options();
+                #[allow(unused_mut)]
+                let mut changed = false;
+                #(#refreshes)*
+                changed
+            }
+        }
+    })
+}